@@ -1,4 +1,5 @@
-use solana_client::rpc_config::RpcSendTransactionConfig;
+use serde_json::json;
+use solana_client::rpc_config::{RpcSendTransactionConfig, RpcSimulateTransactionConfig};
 use solana_sdk::program_option::COption;
 use solana_sdk::program_pack::Pack;
 use spl_associated_token_account_interface::address::get_associated_token_address_with_program_id;
@@ -9,7 +10,11 @@ use spl_token_2022_interface::{
     state::{Account, AccountState},
 };
 use spl_token_metadata_interface::state::TokenMetadata;
-use token_acl_client::set_mint_tacl_metadata_ix;
+use token_acl_client::{
+    create_config_metadata_account_metas, get_mint_config_page,
+    set_gating_program_metadata_account_metas, GateExtraMetasStatus, GateMode, MintConfigFilter,
+    MintConfigPage, TokenAclClient,
+};
 use {
     clap::{crate_description, crate_name, crate_version, Arg, ArgGroup, Command},
     solana_clap_v3_utils::{
@@ -26,7 +31,7 @@ use {
     solana_sdk::{
         message::Message,
         pubkey::Pubkey,
-        signature::{Signature, Signer},
+        signature::{NullSigner, Signature, Signer},
         transaction::Transaction,
     },
     std::{error::Error, process::exit, rc::Rc, sync::Arc},
@@ -34,11 +39,20 @@ use {
 
 struct Config {
     commitment_config: CommitmentConfig,
-    payer: Arc<dyn Signer>,
+    /// `None` for read-only commands run without a keypair.
+    payer: Option<Arc<dyn Signer>>,
     json_rpc_url: String,
     verbose: bool,
 }
 
+impl Config {
+    fn payer(&self) -> Result<&Arc<dyn Signer>, Box<dyn Error>> {
+        self.payer
+            .as_ref()
+            .ok_or_else(|| "a payer keypair is required".into())
+    }
+}
+
 async fn process_create_config(
     rpc_client: &Arc<RpcClient>,
     payer: &Arc<dyn Signer>,
@@ -47,8 +61,13 @@ async fn process_create_config(
     gating_program: Option<&Pubkey>,
 ) -> Result<Signature, Box<dyn Error>> {
     let config = token_acl_client::accounts::MintConfig::find_pda(mint).0;
+    let mint_account = rpc_client
+        .get_account(mint)
+        .await
+        .map_err(|err| format!("unable to get mint: {}", err))?;
 
-    let ix = token_acl_client::instructions::CreateConfigBuilder::new()
+    let mut builder = token_acl_client::instructions::CreateConfigBuilder::new();
+    builder
         .authority(
             freeze_authority
                 .as_ref()
@@ -59,69 +78,41 @@ async fn process_create_config(
         .mint(*mint)
         .mint_config(config)
         .gating_program(gating_program.cloned().unwrap_or(Pubkey::default()))
-        .instruction();
-
-    let mut instructions = vec![ix];
-
-    if let Some(gating_program) = gating_program {
-        let mint_data = rpc_client
-            .get_account_data(&mint)
-            .await
-            .map_err(|err| format!("error: unable to get mint data: {}", err))?;
-        let mint_unpacked: PodStateWithExtensions<'_, PodMint> =
-            PodStateWithExtensions::<PodMint>::unpack(&mint_data)
-                .map_err(|err| format!("error: unable to unpack mint data: {}", err))?;
-        let mut metadata = mint_unpacked
-            .get_variable_len_extension::<TokenMetadata>()
-            .map_err(|err| format!("error: unable to get metadata: {}", err))?;
-
-        let initial_tlv_size = metadata.tlv_size_of()?;
-        metadata.set_key_value(
-            token_acl_client::TOKEN_ACL_METADATA_KEY.to_string(),
-            gating_program.to_string(),
-        );
-        let new_tlv_size = metadata.tlv_size_of()?;
-
-        if new_tlv_size > initial_tlv_size {
-            let diff = new_tlv_size - initial_tlv_size;
-            let rent = rpc_client
-                .get_minimum_balance_for_rent_exemption(diff)
-                .await
-                .map_err(|err| format!("error: unable to get rent: {}", err))?;
-            let transfer_ix =
-                solana_system_interface::instruction::transfer(&payer.pubkey(), &mint, rent);
-            instructions.push(transfer_ix);
-        }
-
-        let set_metadata_ix = set_mint_tacl_metadata_ix(mint, &payer.pubkey(), gating_program);
-        instructions.push(set_metadata_ix);
+        .token_program(mint_account.owner);
+
+    // SPL Token mints have no metadata extension and metadata kept in another account belongs
+    // to its own program, the gating program is only kept in the config then
+    if gating_program.is_some()
+        && token_acl_client::get_metadata_update_authority_from_mint_data(&mint_account.data)
+            .is_some()
+    {
+        builder.add_remaining_accounts(&create_config_metadata_account_metas(&payer.pubkey()));
     }
 
-    let mut transaction = Transaction::new_unsigned(Message::new(
-        &instructions.as_slice(),
-        Some(&payer.pubkey()),
-    ));
+    let instructions = vec![builder.instruction()];
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new(instructions.as_slice(), Some(&payer.pubkey())));
 
     let blockhash = rpc_client
         .get_latest_blockhash()
         .await
-        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
 
     if let Some((signer, _)) = freeze_authority {
-        let signer: Arc<dyn Signer> = Arc::new(signer);
         transaction
-            .try_sign(&[payer, &signer], blockhash)
-            .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+            .try_sign(&[payer.as_ref(), signer.as_ref()], blockhash)
+            .map_err(|err| format!("failed to sign transaction: {}", err))?;
     } else {
         transaction
             .try_sign(&[payer], blockhash)
-            .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+            .map_err(|err| format!("failed to sign transaction: {}", err))?;
     };
 
     let signature = rpc_client
         .send_and_confirm_transaction_with_spinner(&transaction)
         .await
-        .map_err(|err| format!("error: send transaction: {}", err))?;
+        .map_err(|err| format!("send transaction: {}", err))?;
 
     println!("config: {:?}", config);
 
@@ -133,33 +124,51 @@ async fn process_delete_config(
     payer: &Arc<dyn Signer>,
     mint: &Pubkey,
     receiver: Option<&Pubkey>,
+    new_freeze_authority: Option<&Pubkey>,
 ) -> Result<Signature, Box<dyn Error>> {
     let payer_pk = payer.pubkey();
     let receiver = receiver.unwrap_or(&payer_pk);
+    let new_freeze_authority = new_freeze_authority.unwrap_or(&payer_pk);
     let config = token_acl_client::accounts::MintConfig::find_pda(mint).0;
+    let token_program = rpc_client
+        .get_account(mint)
+        .await
+        .map_err(|err| format!("unable to get mint: {}", err))?
+        .owner;
 
-    let ix = token_acl_client::instructions::DeleteConfigBuilder::new()
+    let mut builder = token_acl_client::instructions::DeleteConfigBuilder::new();
+    builder
         .authority(payer.pubkey())
         .receiver(*receiver)
         .mint(*mint)
         .mint_config(config)
-        .instruction();
+        .token_program(token_program)
+        .pending_gating_program(token_acl_client::accounts::PendingGatingProgram::find_pda(mint).0)
+        .pending_payer(*receiver)
+        .new_freeze_authority(*new_freeze_authority);
+
+    // a pending gate change is closed with the config and refunded to its payer
+    if let Some(pending) = get_pending_gating_program(rpc_client, mint).await? {
+        builder.pending_payer(pending.payer);
+    }
+
+    let ix = builder.instruction();
 
     let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
 
     let blockhash = rpc_client
         .get_latest_blockhash()
         .await
-        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
 
     transaction
         .try_sign(&[payer], blockhash)
-        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
 
     let signature = rpc_client
         .send_and_confirm_transaction_with_spinner(&transaction)
         .await
-        .map_err(|err| format!("error: send transaction: {}", err))?;
+        .map_err(|err| format!("send transaction: {}", err))?;
 
     Ok(signature)
 }
@@ -168,13 +177,13 @@ async fn process_set_authority(
     rpc_client: &Arc<RpcClient>,
     payer: &Arc<dyn Signer>,
     mint: &Pubkey,
-    new_authority: &Pubkey,
+    new_authority: &dyn Signer,
 ) -> Result<Signature, Box<dyn Error>> {
     let config = token_acl_client::accounts::MintConfig::find_pda(mint).0;
 
     let ix = token_acl_client::instructions::SetAuthorityBuilder::new()
         .authority(payer.pubkey())
-        .new_authority(*new_authority)
+        .new_authority(new_authority.pubkey())
         .mint_config(config)
         .instruction();
 
@@ -183,99 +192,64 @@ async fn process_set_authority(
     let blockhash = rpc_client
         .get_latest_blockhash()
         .await
-        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
 
     transaction
-        .try_sign(&[payer], blockhash)
-        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+        .try_sign(&[payer.as_ref(), new_authority], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
 
     let signature = rpc_client
         .send_and_confirm_transaction_with_spinner(&transaction)
         .await
-        .map_err(|err| format!("error: send transaction: {}", err))?;
+        .map_err(|err| format!("send transaction: {}", err))?;
 
     Ok(signature)
 }
 
-async fn process_set_gating_program(
+async fn process_set_freeze_operator(
     rpc_client: &Arc<RpcClient>,
     payer: &Arc<dyn Signer>,
     mint: &Pubkey,
-    new_gating_program: &Pubkey,
+    new_operator: &Pubkey,
 ) -> Result<Signature, Box<dyn Error>> {
     let config = token_acl_client::accounts::MintConfig::find_pda(mint).0;
 
-    let ix = token_acl_client::instructions::SetGatingProgramBuilder::new()
+    let ix = token_acl_client::instructions::SetFreezeOperatorBuilder::new()
         .authority(payer.pubkey())
-        .new_gating_program(*new_gating_program)
+        .new_freeze_operator(*new_operator)
         .mint_config(config)
         .instruction();
 
-    let set_metadata_ix = set_mint_tacl_metadata_ix(mint, &payer.pubkey(), new_gating_program);
-
-    let mut instructions = vec![ix, set_metadata_ix];
-
-    let mint_data = rpc_client
-        .get_account_data(&mint)
-        .await
-        .map_err(|err| format!("error: unable to get mint data: {}", err))?;
-    let mint_unpacked = PodStateWithExtensions::<PodMint>::unpack(&mint_data)
-        .map_err(|err| format!("error: unable to unpack mint data: {}", err))?;
-    let mut metadata = mint_unpacked
-        .get_variable_len_extension::<TokenMetadata>()
-        .map_err(|err| format!("error: unable to get metadata: {}", err))?;
-
-    let initial_tlv_size = metadata.tlv_size_of()?;
-    metadata.set_key_value(
-        token_acl_client::TOKEN_ACL_METADATA_KEY.to_string(),
-        new_gating_program.to_string(),
-    );
-    let new_tlv_size = metadata.tlv_size_of()?;
-
-    if new_tlv_size > initial_tlv_size {
-        let diff = new_tlv_size - initial_tlv_size;
-        let rent = rpc_client
-            .get_minimum_balance_for_rent_exemption(diff)
-            .await
-            .map_err(|err| format!("error: unable to get rent: {}", err))?;
-        let transfer_ix =
-            solana_system_interface::instruction::transfer(&payer.pubkey(), &mint, rent);
-        instructions.push(transfer_ix);
-    }
-
-    let mut transaction =
-        Transaction::new_unsigned(Message::new(instructions.as_slice(), Some(&payer.pubkey())));
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
 
     let blockhash = rpc_client
         .get_latest_blockhash()
         .await
-        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
 
     transaction
         .try_sign(&[payer], blockhash)
-        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
 
     let signature = rpc_client
         .send_and_confirm_transaction_with_spinner(&transaction)
         .await
-        .map_err(|err| format!("error: send transaction: {}", err))?;
+        .map_err(|err| format!("send transaction: {}", err))?;
 
     Ok(signature)
 }
 
-async fn process_set_instructions(
+async fn process_set_thaw_operator(
     rpc_client: &Arc<RpcClient>,
     payer: &Arc<dyn Signer>,
     mint: &Pubkey,
-    enable_thaw: bool,
-    enable_freeze: bool,
+    new_operator: &Pubkey,
 ) -> Result<Signature, Box<dyn Error>> {
     let config = token_acl_client::accounts::MintConfig::find_pda(mint).0;
 
-    let ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
+    let ix = token_acl_client::instructions::SetThawOperatorBuilder::new()
         .authority(payer.pubkey())
-        .thaw_enabled(enable_thaw)
-        .freeze_enabled(enable_freeze)
+        .new_thaw_operator(*new_operator)
         .mint_config(config)
         .instruction();
 
@@ -284,36 +258,32 @@ async fn process_set_instructions(
     let blockhash = rpc_client
         .get_latest_blockhash()
         .await
-        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
 
     transaction
         .try_sign(&[payer], blockhash)
-        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
 
     let signature = rpc_client
         .send_and_confirm_transaction_with_spinner(&transaction)
         .await
-        .map_err(|err| format!("error: send transaction: {}", err))?;
+        .map_err(|err| format!("send transaction: {}", err))?;
 
     Ok(signature)
 }
 
-async fn process_freeze(
+async fn process_propose_authority(
     rpc_client: &Arc<RpcClient>,
     payer: &Arc<dyn Signer>,
-    token_account: Pubkey,
+    mint: &Pubkey,
+    pending_authority: &Pubkey,
 ) -> Result<Signature, Box<dyn Error>> {
-    let token_account_data = rpc_client.get_account(&token_account).await.unwrap();
-    let ta = StateWithExtensions::<Account>::unpack(token_account_data.data.as_ref()).unwrap();
-
-    let config = token_acl_client::accounts::MintConfig::find_pda(&ta.base.mint).0;
+    let config = token_acl_client::accounts::MintConfig::find_pda(mint).0;
 
-    let ix = token_acl_client::instructions::FreezeBuilder::new()
+    let ix = token_acl_client::instructions::ProposeAuthorityBuilder::new()
         .authority(payer.pubkey())
-        .mint(ta.base.mint)
-        .token_account(token_account)
+        .pending_authority(*pending_authority)
         .mint_config(config)
-        .token_program(spl_token_2022_interface::ID)
         .instruction();
 
     let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
@@ -321,148 +291,61 @@ async fn process_freeze(
     let blockhash = rpc_client
         .get_latest_blockhash()
         .await
-        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
 
     transaction
         .try_sign(&[payer], blockhash)
-        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
 
     let signature = rpc_client
         .send_and_confirm_transaction_with_spinner(&transaction)
         .await
-        .map_err(|err| format!("error: send transaction: {}", err))?;
+        .map_err(|err| format!("send transaction: {}", err))?;
 
     Ok(signature)
 }
 
-async fn process_freeze_permissionless(
+async fn process_accept_authority(
     rpc_client: &Arc<RpcClient>,
     payer: &Arc<dyn Signer>,
-    mint: Option<Pubkey>,
-    token_account_pk: Option<Pubkey>,
-    token_account_owner_pk: Option<Pubkey>,
+    mint: &Pubkey,
 ) -> Result<Signature, Box<dyn Error>> {
-    let mut instructions = Vec::new();
-
-    let (mint, token_account_pk, token_account_owner_pk, new_ata, ata_data) =
-        match (mint, token_account_pk, token_account_owner_pk) {
-            (None, Some(token_account_pk), None) => {
-                let token_account_data = rpc_client.get_account(&token_account_pk).await.unwrap();
-                let token_account =
-                    StateWithExtensions::<Account>::unpack(token_account_data.data.as_ref())
-                        .unwrap();
-                (
-                    token_account.base.mint,
-                    token_account_pk,
-                    token_account.base.owner,
-                    false,
-                    Vec::new(),
-                )
-            }
-            (Some(mint), None, Some(token_account_owner_pk)) => {
-                let token_account = get_associated_token_address_with_program_id(
-                    &token_account_owner_pk,
-                    &mint,
-                    &spl_token_2022_interface::ID,
-                );
-
-                let ix = create_associated_token_account(
-                    &payer.pubkey(),
-                    &token_account_owner_pk,
-                    &mint,
-                    &spl_token_2022_interface::ID,
-                );
-                instructions.push(ix);
-
-                let acc = Account {
-                    mint,
-                    owner: token_account_owner_pk,
-                    amount: 0,
-                    delegate: COption::None,
-                    state: AccountState::Frozen,
-                    is_native: COption::None,
-                    delegated_amount: 0,
-                    close_authority: COption::None,
-                };
-
-                let mut data = vec![0u8; Account::LEN];
-                Account::pack(acc, &mut data)?;
-
-                (mint, token_account, token_account_owner_pk, true, data)
-            }
-            _ => {
-                return Err(
-                    "error: token_account or token_account_owner and mint must be provided".into(),
-                )
-            }
-        };
-
-    let config = token_acl_client::accounts::MintConfig::find_pda(&mint).0;
-
-    println!("mint: {:?}", mint);
-    println!("token_account_pk: {:?}", token_account_pk);
-    println!("token_account_owner_pk: {:?}", token_account_owner_pk);
-
-    let ix = token_acl_client::create_freeze_permissionless_instruction_with_extra_metas(
-        &payer.pubkey(),
-        &token_account_pk,
-        &mint,
-        &config,
-        &spl_token_2022_interface::ID,
-        &token_account_owner_pk,
-        false,
-        |pubkey| {
-            let data = ata_data.clone();
-            async move {
-                if new_ata && pubkey == token_account_pk {
-                    return Ok(Some(data));
-                }
-                let data = rpc_client.get_account(&pubkey).await.map(|a| a.data).ok();
-                Ok(data)
-            }
-        },
-    )
-    .await
-    .unwrap();
+    let config = token_acl_client::accounts::MintConfig::find_pda(mint).0;
 
-    instructions.push(ix);
+    let ix = token_acl_client::instructions::AcceptAuthorityBuilder::new()
+        .pending_authority(payer.pubkey())
+        .mint_config(config)
+        .instruction();
 
-    let mut transaction =
-        Transaction::new_unsigned(Message::new(&instructions, Some(&payer.pubkey())));
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
 
     let blockhash = rpc_client
         .get_latest_blockhash()
         .await
-        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
 
     transaction
         .try_sign(&[payer], blockhash)
-        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
 
     let signature = rpc_client
         .send_and_confirm_transaction_with_spinner(&transaction)
         .await
-        .map_err(|err| format!("error: send transaction: {}", err))?;
+        .map_err(|err| format!("send transaction: {}", err))?;
 
     Ok(signature)
 }
 
-async fn process_thaw(
+async fn process_cancel_authority_transfer(
     rpc_client: &Arc<RpcClient>,
     payer: &Arc<dyn Signer>,
-    token_account: Pubkey,
+    mint: &Pubkey,
 ) -> Result<Signature, Box<dyn Error>> {
-    let token_account_data = rpc_client.get_account(&token_account).await.unwrap();
-    let ta = StateWithExtensions::<Account>::unpack(token_account_data.data.as_ref()).unwrap();
-
-    let config = token_acl_client::accounts::MintConfig::find_pda(&ta.base.mint).0;
+    let config = token_acl_client::accounts::MintConfig::find_pda(mint).0;
 
-    let ix = token_acl_client::instructions::ThawBuilder::new()
+    let ix = token_acl_client::instructions::CancelAuthorityTransferBuilder::new()
         .authority(payer.pubkey())
-        .mint(ta.base.mint)
-        .token_account(token_account)
         .mint_config(config)
-        .token_program(spl_token_2022_interface::ID)
         .instruction();
 
     let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
@@ -470,162 +353,1114 @@ async fn process_thaw(
     let blockhash = rpc_client
         .get_latest_blockhash()
         .await
-        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
 
     transaction
         .try_sign(&[payer], blockhash)
-        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
 
     let signature = rpc_client
         .send_and_confirm_transaction_with_spinner(&transaction)
         .await
-        .map_err(|err| format!("error: send transaction: {}", err))?;
+        .map_err(|err| format!("send transaction: {}", err))?;
 
     Ok(signature)
 }
 
-async fn process_thaw_permissionless(
+async fn process_migrate_config(
     rpc_client: &Arc<RpcClient>,
     payer: &Arc<dyn Signer>,
-    mint: Option<Pubkey>,
-    token_account_pk: Option<Pubkey>,
-    token_account_owner_pk: Option<Pubkey>,
+    mint: &Pubkey,
 ) -> Result<Signature, Box<dyn Error>> {
-    let mut instructions = Vec::new();
-
-    let (mint, token_account_pk, token_account_owner_pk, new_ata, ata_data) =
-        match (mint, token_account_pk, token_account_owner_pk) {
-            (None, Some(token_account_pk), None) => {
-                let token_account_data = rpc_client.get_account(&token_account_pk).await.unwrap();
-                let token_account =
-                    StateWithExtensions::<Account>::unpack(token_account_data.data.as_ref())
-                        .unwrap();
-                (
-                    token_account.base.mint,
-                    token_account_pk,
-                    token_account.base.owner,
-                    false,
-                    Vec::new(),
-                )
-            }
-            (Some(mint), None, Some(token_account_owner_pk)) => {
-                let token_account = get_associated_token_address_with_program_id(
-                    &token_account_owner_pk,
-                    &mint,
-                    &spl_token_2022_interface::ID,
-                );
-
-                let ix = create_associated_token_account(
-                    &payer.pubkey(),
-                    &token_account_owner_pk,
-                    &mint,
-                    &spl_token_2022_interface::ID,
-                );
-                instructions.push(ix);
-
-                let acc = Account {
-                    mint,
-                    owner: token_account_owner_pk,
-                    amount: 0,
-                    delegate: COption::None,
-                    state: AccountState::Frozen,
-                    is_native: COption::None,
-                    delegated_amount: 0,
-                    close_authority: COption::None,
-                };
-
-                let mut data = vec![0u8; Account::LEN];
-                Account::pack(acc, &mut data)?;
-
-                (mint, token_account, token_account_owner_pk, true, data)
-            }
-            _ => {
-                return Err(
-                    "error: token_account or token_account_owner and mint must be provided".into(),
-                )
-            }
-        };
-
-    println!("mint: {:?}", mint);
-    println!("token_account_pk: {:?}", token_account_pk);
-    println!("token_account_owner_pk: {:?}", token_account_owner_pk);
-
-    let config = token_acl_client::accounts::MintConfig::find_pda(&mint).0;
-
-    let ix = token_acl_client::create_thaw_permissionless_instruction_with_extra_metas(
-        &payer.pubkey(),
-        &token_account_pk,
-        &mint,
-        &config,
-        &spl_token_2022_interface::ID,
-        &token_account_owner_pk,
-        false,
-        |pubkey| {
-            let data = ata_data.clone();
-            async move {
-                if new_ata && pubkey == token_account_pk {
-                    return Ok(Some(data));
-                }
-                let data = rpc_client.get_account(&pubkey).await.map(|a| a.data).ok();
-                Ok(data)
-            }
-        },
-    )
-    .await
-    .unwrap();
+    let config = token_acl_client::accounts::MintConfig::find_pda(mint).0;
 
-    instructions.push(ix);
+    let ix = token_acl_client::instructions::MigrateConfigBuilder::new()
+        .payer(payer.pubkey())
+        .mint_config(config)
+        .instruction();
 
-    let mut transaction =
-        Transaction::new_unsigned(Message::new(&instructions, Some(&payer.pubkey())));
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
 
     let blockhash = rpc_client
         .get_latest_blockhash()
         .await
-        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
 
     transaction
         .try_sign(&[payer], blockhash)
-        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
 
     let signature = rpc_client
-        .send_and_confirm_transaction_with_spinner_and_config(
-            &transaction,
-            CommitmentConfig {
-                commitment: solana_commitment_config::CommitmentLevel::Confirmed,
-            },
-            RpcSendTransactionConfig {
-                skip_preflight: true,
-                ..Default::default()
-            },
-        )
-        //.send_and_confirm_transaction_with_spinner_and_config(&transaction, CommitmentConfig { commitment: solana_sdk::commitment_config::CommitmentLevel::Confirmed }, RpcSendTransactionConfig { skip_preflight: true, ..Default::default()})
+        .send_and_confirm_transaction_with_spinner(&transaction)
         .await
-        .map_err(|err| format!("error: send transaction: {}", err))?;
+        .map_err(|err| format!("send transaction: {}", err))?;
 
     Ok(signature)
 }
 
-async fn process_create_ata_and_thaw_permissionless(
+async fn get_mint_metadata(
     rpc_client: &Arc<RpcClient>,
-    payer: &Arc<dyn Signer>,
-    mint: Pubkey,
-    token_account_owner_pk: Pubkey,
-) -> Result<Signature, Box<dyn Error>> {
-    let instructions = token_acl_client::create_ata_and_thaw_permissionless(
-        &rpc_client.clone(),
-        &payer.pubkey(),
+    mint: &Pubkey,
+) -> Result<TokenMetadata, Box<dyn Error>> {
+    let mint_data = rpc_client
+        .get_account_data(mint)
+        .await
+        .map_err(|err| format!("unable to get mint data: {}", err))?;
+    let mint_unpacked = PodStateWithExtensions::<PodMint>::unpack(&mint_data)
+        .map_err(|err| format!("unable to unpack mint data: {}", err))?;
+    let metadata = mint_unpacked
+        .get_variable_len_extension::<TokenMetadata>()
+        .map_err(|err| format!("unable to get metadata: {}", err))?;
+    Ok(metadata)
+}
+
+async fn get_mint_config(
+    rpc_client: &Arc<RpcClient>,
+    mint: &Pubkey,
+) -> Result<token_acl_client::accounts::MintConfig, Box<dyn Error>> {
+    let config = token_acl_client::accounts::MintConfig::find_pda(mint).0;
+    let config_data = rpc_client
+        .get_account_data(&config)
+        .await
+        .map_err(|err| format!("unable to get mint config: {}", err))?;
+    let config = token_acl_client::accounts::MintConfig::from_account_data(&config_data)
+        .map_err(|err| format!("unable to parse mint config: {}", err))?;
+    Ok(config)
+}
+
+async fn get_pending_gating_program(
+    rpc_client: &Arc<RpcClient>,
+    mint: &Pubkey,
+) -> Result<Option<token_acl_client::accounts::PendingGatingProgram>, Box<dyn Error>> {
+    let pending = token_acl_client::accounts::PendingGatingProgram::find_pda(mint).0;
+    let account = rpc_client
+        .get_account_with_commitment(&pending, rpc_client.commitment())
+        .await
+        .map_err(|err| format!("unable to get pending gating program: {}", err))?
+        .value;
+    account
+        .map(|account| {
+            token_acl_client::accounts::PendingGatingProgram::from_bytes(&account.data)
+                .map_err(|err| format!("unable to parse pending gating program: {}", err).into())
+        })
+        .transpose()
+}
+
+async fn get_freeze_reason(
+    rpc_client: &Arc<RpcClient>,
+    token_account: &Pubkey,
+) -> Result<Option<token_acl_client::accounts::FreezeReason>, Box<dyn Error>> {
+    let freeze_reason = token_acl_client::accounts::FreezeReason::find_pda(token_account).0;
+    let account = rpc_client
+        .get_account_with_commitment(&freeze_reason, rpc_client.commitment())
+        .await
+        .map_err(|err| format!("unable to get freeze reason: {}", err))?
+        .value;
+    account
+        .map(|account| {
+            token_acl_client::accounts::FreezeReason::from_bytes(&account.data)
+                .map_err(|err| format!("unable to parse freeze reason: {}", err).into())
+        })
+        .transpose()
+}
+
+async fn process_set_gating_program(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: &Pubkey,
+    new_gating_program: &Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let config = token_acl_client::accounts::MintConfig::find_pda(mint).0;
+
+    let mut builder = token_acl_client::instructions::SetGatingProgramBuilder::new();
+    builder
+        .authority(payer.pubkey())
+        .new_gating_program(*new_gating_program)
+        .mint_config(config);
+
+    // only metadata stored in the mint is updated along with the config
+    if get_mint_metadata(rpc_client, mint).await.is_ok() {
+        builder.add_remaining_accounts(&set_gating_program_metadata_account_metas(
+            mint,
+            &payer.pubkey(),
+        ));
+    }
+
+    let ix = builder.instruction();
+
+    let instructions = vec![ix];
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new(instructions.as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_queue_gating_program(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: &Pubkey,
+    new_gating_program: Option<Pubkey>,
+    new_gating_program_delay: Option<i64>,
+) -> Result<Signature, Box<dyn Error>> {
+    let config = get_mint_config(rpc_client, mint).await?;
+
+    let ix = token_acl_client::create_queue_gating_program_instruction(
+        &payer.pubkey(),
+        &payer.pubkey(),
+        mint,
+        &new_gating_program.unwrap_or(config.gating_program),
+        new_gating_program_delay.unwrap_or(config.gating_program_delay),
+    );
+
+    let instructions = [ix];
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new(instructions.as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+/// Queues a gating chain and freeze gating program change, the parts that aren't given keep
+/// their queued value if they were queued already and their current value otherwise.
+async fn process_queue_gating_chain(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: &Pubkey,
+    gating_chain: Option<(
+        &[Pubkey],
+        token_acl_client::GateMode,
+        token_acl_client::GateMode,
+    )>,
+    freeze_gating_program: Option<&Pubkey>,
+) -> Result<Signature, Box<dyn Error>> {
+    let config = get_mint_config(rpc_client, mint).await?;
+    let (current_chain, current_thaw_mode, current_freeze_mode, current_freeze_gating_program) =
+        match get_pending_gating_program(rpc_client, mint).await? {
+            Some(pending) if pending.gating_chain_queued => (
+                pending.new_additional_gating_programs,
+                pending.new_thaw_gate_mode,
+                pending.new_freeze_gate_mode,
+                pending.new_freeze_gating_program,
+            ),
+            _ => (
+                config.additional_gating_programs,
+                config.thaw_gate_mode,
+                config.freeze_gate_mode,
+                config.freeze_gating_program,
+            ),
+        };
+    let gate_mode = |mode: u8| {
+        token_acl_client::GateMode::try_from(mode)
+            .map_err(|err| format!("invalid gate mode: {}", err))
+    };
+    let (additional_gating_programs, thaw_gate_mode, freeze_gate_mode) = match gating_chain {
+        Some(gating_chain) => gating_chain,
+        None => (
+            current_chain.as_slice(),
+            gate_mode(current_thaw_mode)?,
+            gate_mode(current_freeze_mode)?,
+        ),
+    };
+    let additional_gating_programs: Vec<Pubkey> = additional_gating_programs
+        .iter()
+        .copied()
+        .filter(|program| *program != Pubkey::default())
+        .collect();
+
+    let ix = token_acl_client::create_queue_gating_chain_instruction(
+        &payer.pubkey(),
+        &payer.pubkey(),
+        mint,
+        &additional_gating_programs,
+        thaw_gate_mode,
+        freeze_gate_mode,
+        freeze_gating_program.unwrap_or(&current_freeze_gating_program),
+    )
+    .map_err(|err| format!("invalid gating chain: {}", err))?;
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_finalize_gating_program(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: &Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let pending = get_pending_gating_program(rpc_client, mint)
+        .await?
+        .ok_or("no pending gating program")?;
+
+    let mut ix = token_acl_client::create_finalize_gating_program_instruction(mint, &pending.payer);
+
+    // keep the metadata entry in sync when the payer is allowed to update it
+    let update_authority = get_mint_metadata(rpc_client, mint)
+        .await
+        .ok()
+        .and_then(|metadata| Option::<Pubkey>::from(metadata.update_authority));
+    if pending.gating_program_queued && update_authority == Some(payer.pubkey()) {
+        ix.accounts
+            .extend(set_gating_program_metadata_account_metas(
+                mint,
+                &payer.pubkey(),
+            ));
+    }
+
+    let instructions = [ix];
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new(instructions.as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_cancel_gating_program(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: &Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let pending = get_pending_gating_program(rpc_client, mint)
+        .await?
+        .ok_or("no pending gating program")?;
+
+    let ix = token_acl_client::create_cancel_gating_program_instruction(
+        &payer.pubkey(),
+        mint,
+        &pending.payer,
+    );
+
+    let instructions = [ix];
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new(instructions.as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_show_pending_gating_program(
+    rpc_client: &Arc<RpcClient>,
+    mint: &Pubkey,
+) -> Result<String, Box<dyn Error>> {
+    let config = get_mint_config(rpc_client, mint).await?;
+    let mut output = format!(
+        "Gating program: {}\nGating program delay: {} seconds",
+        config.gating_program, config.gating_program_delay
+    );
+
+    match get_pending_gating_program(rpc_client, mint).await? {
+        Some(pending) => {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)?
+                .as_secs() as i64;
+            let status = if pending.is_active(now) {
+                "active, can be finalized".to_string()
+            } else {
+                format!("active in {} seconds", pending.activation_timestamp - now)
+            };
+            if pending.gating_program_queued {
+                output.push_str(&format!(
+                    "\nPending gating program: {}\nPending gating program delay: {} seconds",
+                    pending.new_gating_program, pending.new_gating_program_delay,
+                ));
+            }
+            if pending.gating_chain_queued {
+                output.push_str(&format!(
+                    "\nPending gating chain: {:?}\nPending thaw gate mode: {}\nPending freeze gate mode: {}\nPending freeze gating program: {}",
+                    pending
+                        .new_additional_gating_programs
+                        .iter()
+                        .filter(|program| **program != Pubkey::default())
+                        .collect::<Vec<_>>(),
+                    pending.new_thaw_gate_mode,
+                    pending.new_freeze_gate_mode,
+                    pending.new_freeze_gating_program,
+                ));
+            }
+            output.push_str(&format!(
+                "\nActivation timestamp: {} ({})",
+                pending.activation_timestamp, status
+            ));
+        }
+        None => output.push_str("\nNo pending gating program"),
+    }
+
+    Ok(output)
+}
+
+async fn process_set_freeze_gating_program(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: &Pubkey,
+    new_freeze_gating_program: &Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let config = token_acl_client::accounts::MintConfig::find_pda(mint).0;
+
+    let ix = token_acl_client::instructions::SetFreezeGatingProgramBuilder::new()
+        .authority(payer.pubkey())
+        .new_freeze_gating_program(*new_freeze_gating_program)
+        .mint_config(config)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_set_paused(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: &Pubkey,
+    paused: bool,
+) -> Result<Signature, Box<dyn Error>> {
+    let config = token_acl_client::accounts::MintConfig::find_pda(mint).0;
+
+    let ix = token_acl_client::instructions::SetPausedBuilder::new()
+        .authority(payer.pubkey())
+        .mint_config(config)
+        .paused(paused)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_set_sticky_freeze(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: &Pubkey,
+    sticky_freeze: bool,
+) -> Result<Signature, Box<dyn Error>> {
+    let config = token_acl_client::accounts::MintConfig::find_pda(mint).0;
+
+    let ix = token_acl_client::instructions::SetStickyFreezeBuilder::new()
+        .authority(payer.pubkey())
+        .mint_config(config)
+        .sticky_freeze(sticky_freeze)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_set_permissionless_fee(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: &Pubkey,
+    permissionless_fee: u64,
+) -> Result<Signature, Box<dyn Error>> {
+    let ix = token_acl_client::create_set_permissionless_fee_instruction(
+        &payer.pubkey(),
+        &payer.pubkey(),
+        mint,
+        permissionless_fee,
+    );
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_withdraw_fees(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: &Pubkey,
+    receiver: Option<&Pubkey>,
+    amount: u64,
+) -> Result<Signature, Box<dyn Error>> {
+    let payer_pk = payer.pubkey();
+    let receiver = receiver.unwrap_or(&payer_pk);
+
+    let ix = token_acl_client::create_withdraw_fees_instruction(&payer_pk, mint, receiver, amount);
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_set_thaw_ttl(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: &Pubkey,
+    thaw_ttl: i64,
+) -> Result<Signature, Box<dyn Error>> {
+    let ix = token_acl_client::create_set_thaw_ttl_instruction(&payer.pubkey(), mint, thaw_ttl);
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_set_pause_guardian(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: &Pubkey,
+    new_pause_guardian: &Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let config = token_acl_client::accounts::MintConfig::find_pda(mint).0;
+
+    let ix = token_acl_client::instructions::SetPauseGuardianBuilder::new()
+        .authority(payer.pubkey())
+        .mint_config(config)
+        .new_pause_guardian(*new_pause_guardian)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_set_gating_chain(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: &Pubkey,
+    additional_gating_programs: &[Pubkey],
+    thaw_gate_mode: token_acl_client::GateMode,
+    freeze_gate_mode: token_acl_client::GateMode,
+) -> Result<Signature, Box<dyn Error>> {
+    let ix = token_acl_client::create_set_gating_chain_instruction(
+        &payer.pubkey(),
+        mint,
+        additional_gating_programs,
+        thaw_gate_mode,
+        freeze_gate_mode,
+    )
+    .map_err(|err| format!("invalid gating chain: {}", err))?;
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_set_instructions(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: &Pubkey,
+    enable_thaw: bool,
+    enable_freeze: bool,
+) -> Result<Signature, Box<dyn Error>> {
+    let config = token_acl_client::accounts::MintConfig::find_pda(mint).0;
+
+    let ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
+        .authority(payer.pubkey())
+        .thaw_enabled(enable_thaw)
+        .freeze_enabled(enable_freeze)
+        .mint_config(config)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_freeze(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    token_account: Pubkey,
+    reason: Option<(u32, [u8; 32])>,
+) -> Result<Signature, Box<dyn Error>> {
+    let token_account_data = rpc_client.get_account(&token_account).await.unwrap();
+    let ta = StateWithExtensions::<Account>::unpack(token_account_data.data.as_ref()).unwrap();
+
+    let config = token_acl_client::accounts::MintConfig::find_pda(&ta.base.mint).0;
+
+    let ix = match reason {
+        Some((reason_code, memo_hash)) => {
+            token_acl_client::instructions::FreezeWithReasonBuilder::new()
+                .authority(payer.pubkey())
+                .payer(payer.pubkey())
+                .mint(ta.base.mint)
+                .token_account(token_account)
+                .mint_config(config)
+                .freeze_reason(token_acl_client::accounts::FreezeReason::find_pda(&token_account).0)
+                .token_program(token_account_data.owner)
+                .reason_code(reason_code)
+                .memo_hash(memo_hash)
+                .instruction()
+        }
+        None => token_acl_client::instructions::FreezeBuilder::new()
+            .authority(payer.pubkey())
+            .mint(ta.base.mint)
+            .token_account(token_account)
+            .mint_config(config)
+            .token_program(token_account_data.owner)
+            .instruction(),
+    };
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_show_freeze_reason(
+    rpc_client: &Arc<RpcClient>,
+    token_account: &Pubkey,
+) -> Result<String, Box<dyn Error>> {
+    match get_freeze_reason(rpc_client, token_account).await? {
+        Some(reason) => Ok(format!(
+            "Token account: {}\nMint: {}\nFrozen by: {}\nFrozen at: {}\nReason code: {}\nMemo hash: {}",
+            reason.token_account,
+            reason.mint,
+            reason.authority,
+            reason.frozen_at,
+            reason.reason_code,
+            solana_sdk::hash::Hash::new_from_array(reason.memo_hash)
+        )),
+        None => Ok("No freeze reason recorded".to_string()),
+    }
+}
+
+async fn process_freeze_permissionless(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: Option<Pubkey>,
+    token_account_pk: Option<Pubkey>,
+    token_account_owner_pk: Option<Pubkey>,
+) -> Result<Signature, Box<dyn Error>> {
+    let mut instructions = Vec::new();
+
+    let (mint, token_account_pk, token_account_owner_pk, token_program, new_ata, ata_data) =
+        match (mint, token_account_pk, token_account_owner_pk) {
+            (None, Some(token_account_pk), None) => {
+                let token_account_data = rpc_client.get_account(&token_account_pk).await.unwrap();
+                let token_account =
+                    StateWithExtensions::<Account>::unpack(token_account_data.data.as_ref())
+                        .unwrap();
+                (
+                    token_account.base.mint,
+                    token_account_pk,
+                    token_account.base.owner,
+                    token_account_data.owner,
+                    false,
+                    Vec::new(),
+                )
+            }
+            (Some(mint), None, Some(token_account_owner_pk)) => {
+                let token_program = rpc_client
+                    .get_account(&mint)
+                    .await
+                    .map_err(|err| format!("unable to get mint: {}", err))?
+                    .owner;
+                let token_account = get_associated_token_address_with_program_id(
+                    &token_account_owner_pk,
+                    &mint,
+                    &token_program,
+                );
+
+                let ix = create_associated_token_account(
+                    &payer.pubkey(),
+                    &token_account_owner_pk,
+                    &mint,
+                    &token_program,
+                );
+                instructions.push(ix);
+
+                let acc = Account {
+                    mint,
+                    owner: token_account_owner_pk,
+                    amount: 0,
+                    delegate: COption::None,
+                    state: AccountState::Frozen,
+                    is_native: COption::None,
+                    delegated_amount: 0,
+                    close_authority: COption::None,
+                };
+
+                let mut data = vec![0u8; Account::LEN];
+                Account::pack(acc, &mut data)?;
+
+                (
+                    mint,
+                    token_account,
+                    token_account_owner_pk,
+                    token_program,
+                    true,
+                    data,
+                )
+            }
+            _ => {
+                return Err("token_account or token_account_owner and mint must be provided".into())
+            }
+        };
+
+    let config = token_acl_client::accounts::MintConfig::find_pda(&mint).0;
+
+    println!("mint: {:?}", mint);
+    println!("token_account_pk: {:?}", token_account_pk);
+    println!("token_account_owner_pk: {:?}", token_account_owner_pk);
+
+    let ix = token_acl_client::create_freeze_permissionless_instruction_with_extra_metas(
+        &payer.pubkey(),
+        &token_account_pk,
+        &mint,
+        &config,
+        &token_program,
+        &token_account_owner_pk,
+        false,
+        |pubkey| {
+            let data = ata_data.clone();
+            async move {
+                if new_ata && pubkey == token_account_pk {
+                    return Ok(Some(data));
+                }
+                let data = rpc_client.get_account(&pubkey).await.map(|a| a.data).ok();
+                Ok(data)
+            }
+        },
+    )
+    .await
+    .unwrap();
+
+    instructions.push(ix);
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new(&instructions, Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_thaw(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    token_account: Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let token_account_data = rpc_client.get_account(&token_account).await.unwrap();
+    let ta = StateWithExtensions::<Account>::unpack(token_account_data.data.as_ref()).unwrap();
+
+    let config = get_mint_config(rpc_client, &ta.base.mint).await?;
+
+    // clear the freeze reason, refunding whoever paid for it
+    let freeze_reason = get_freeze_reason(rpc_client, &token_account).await?;
+    let ix = token_acl_client::create_thaw_instruction(
+        &payer.pubkey(),
+        &config,
+        &token_account,
+        &token_account_data.owner,
+        freeze_reason.as_ref().map(|reason| &reason.payer),
+    );
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_thaw_permissionless(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: Option<Pubkey>,
+    token_account_pk: Option<Pubkey>,
+    token_account_owner_pk: Option<Pubkey>,
+) -> Result<Signature, Box<dyn Error>> {
+    let mut instructions = Vec::new();
+
+    let (mint, token_account_pk, token_account_owner_pk, token_program, new_ata, ata_data) =
+        match (mint, token_account_pk, token_account_owner_pk) {
+            (None, Some(token_account_pk), None) => {
+                let token_account_data = rpc_client.get_account(&token_account_pk).await.unwrap();
+                let token_account =
+                    StateWithExtensions::<Account>::unpack(token_account_data.data.as_ref())
+                        .unwrap();
+                (
+                    token_account.base.mint,
+                    token_account_pk,
+                    token_account.base.owner,
+                    token_account_data.owner,
+                    false,
+                    Vec::new(),
+                )
+            }
+            (Some(mint), None, Some(token_account_owner_pk)) => {
+                let token_program = rpc_client
+                    .get_account(&mint)
+                    .await
+                    .map_err(|err| format!("unable to get mint: {}", err))?
+                    .owner;
+                let token_account = get_associated_token_address_with_program_id(
+                    &token_account_owner_pk,
+                    &mint,
+                    &token_program,
+                );
+
+                let ix = create_associated_token_account(
+                    &payer.pubkey(),
+                    &token_account_owner_pk,
+                    &mint,
+                    &token_program,
+                );
+                instructions.push(ix);
+
+                let acc = Account {
+                    mint,
+                    owner: token_account_owner_pk,
+                    amount: 0,
+                    delegate: COption::None,
+                    state: AccountState::Frozen,
+                    is_native: COption::None,
+                    delegated_amount: 0,
+                    close_authority: COption::None,
+                };
+
+                let mut data = vec![0u8; Account::LEN];
+                Account::pack(acc, &mut data)?;
+
+                (
+                    mint,
+                    token_account,
+                    token_account_owner_pk,
+                    token_program,
+                    true,
+                    data,
+                )
+            }
+            _ => {
+                return Err("token_account or token_account_owner and mint must be provided".into())
+            }
+        };
+
+    println!("mint: {:?}", mint);
+    println!("token_account_pk: {:?}", token_account_pk);
+    println!("token_account_owner_pk: {:?}", token_account_owner_pk);
+
+    let config = token_acl_client::accounts::MintConfig::find_pda(&mint).0;
+
+    let ix = token_acl_client::create_thaw_permissionless_instruction_with_extra_metas(
+        &payer.pubkey(),
+        &token_account_pk,
         &mint,
+        &config,
+        &token_program,
         &token_account_owner_pk,
         false,
+        |pubkey| {
+            let data = ata_data.clone();
+            async move {
+                if new_ata && pubkey == token_account_pk {
+                    return Ok(Some(data));
+                }
+                let data = rpc_client.get_account(&pubkey).await.map(|a| a.data).ok();
+                Ok(data)
+            }
+        },
     )
     .await
-    .map_err(|err| format!("error: create ata and thaw permissionless: {}", err))?;
+    .unwrap();
+
+    instructions.push(ix);
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new(&instructions, Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner_and_config(
+            &transaction,
+            CommitmentConfig {
+                commitment: solana_commitment_config::CommitmentLevel::Confirmed,
+            },
+            RpcSendTransactionConfig {
+                skip_preflight: true,
+                ..Default::default()
+            },
+        )
+        //.send_and_confirm_transaction_with_spinner_and_config(&transaction, CommitmentConfig { commitment: solana_sdk::commitment_config::CommitmentLevel::Confirmed }, RpcSendTransactionConfig { skip_preflight: true, ..Default::default()})
+        .await
+        .map_err(|err| format!("send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_create_ata_and_thaw_permissionless(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: Pubkey,
+    token_account_owner_pk: Pubkey,
+    single_instruction: bool,
+) -> Result<Signature, Box<dyn Error>> {
+    let token_program = rpc_client
+        .get_account(&mint)
+        .await
+        .map_err(|err| format!("unable to get mint: {}", err))?
+        .owner;
+
+    let instructions = if single_instruction {
+        let ix = token_acl_client::create_ata_and_thaw_permissionless_instruction_with_extra_metas(
+            &payer.pubkey(),
+            &mint,
+            &token_program,
+            &token_account_owner_pk,
+            &|pubkey| async move {
+                let data = rpc_client.get_account(&pubkey).await.map(|a| a.data).ok();
+                Ok(data)
+            },
+        )
+        .await
+        .map_err(|err| format!("create ata and thaw permissionless: {}", err))?;
+        vec![ix]
+    } else {
+        token_acl_client::create_ata_and_thaw_permissionless(
+            &rpc_client.clone(),
+            &payer.pubkey(),
+            &mint,
+            &token_account_owner_pk,
+            false,
+        )
+        .await
+        .map_err(|err| format!("create ata and thaw permissionless: {}", err))?
+    };
 
     let token_account_pk = get_associated_token_address_with_program_id(
         &token_account_owner_pk,
         &mint,
-        &spl_token_2022_interface::ID,
+        &token_program,
     );
 
     println!("mint: {:?}", mint);
@@ -638,11 +1473,11 @@ async fn process_create_ata_and_thaw_permissionless(
     let blockhash = rpc_client
         .get_latest_blockhash()
         .await
-        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+        .map_err(|err| format!("unable to get latest blockhash: {}", err))?;
 
     transaction
         .try_sign(&[payer], blockhash)
-        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+        .map_err(|err| format!("failed to sign transaction: {}", err))?;
 
     let signature = rpc_client
         .send_and_confirm_transaction_with_spinner_and_config(
@@ -657,11 +1492,417 @@ async fn process_create_ata_and_thaw_permissionless(
         )
         //.send_and_confirm_transaction_with_spinner_and_config(&transaction, CommitmentConfig { commitment: solana_sdk::commitment_config::CommitmentLevel::Confirmed }, RpcSendTransactionConfig { skip_preflight: true, ..Default::default()})
         .await
-        .map_err(|err| format!("error: send transaction: {}", err))?;
+        .map_err(|err| format!("send transaction: {}", err))?;
 
     Ok(signature)
 }
 
+/// How the read-only commands print their results.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum OutputFormat {
+    Display,
+    Json,
+    JsonCompact,
+}
+
+impl OutputFormat {
+    fn from_matches(matches: &clap::ArgMatches) -> Self {
+        match matches
+            .get_one::<String>("output_format")
+            .map(String::as_str)
+        {
+            Some("json") => Self::Json,
+            Some("json-compact") => Self::JsonCompact,
+            _ => Self::Display,
+        }
+    }
+
+    fn format(
+        self,
+        json: serde_json::Value,
+        display: impl FnOnce() -> String,
+    ) -> Result<String, Box<dyn Error>> {
+        match self {
+            Self::Display => Ok(display()),
+            Self::Json => Ok(serde_json::to_string_pretty(&json)?),
+            Self::JsonCompact => Ok(serde_json::to_string(&json)?),
+        }
+    }
+}
+
+/// `None` for unset pubkeys, which the mint config stores as the default pubkey.
+fn optional_pubkey(pubkey: &Pubkey) -> Option<String> {
+    (*pubkey != Pubkey::default()).then(|| pubkey.to_string())
+}
+
+fn gate_mode_name(gate_mode: u8) -> &'static str {
+    match GateMode::try_from(gate_mode) {
+        Ok(GateMode::All) => "all",
+        Ok(GateMode::Any) => "any",
+        Err(_) => "unknown",
+    }
+}
+
+fn extra_metas_json(extra_metas: &[GateExtraMetasStatus]) -> serde_json::Value {
+    extra_metas
+        .iter()
+        .map(|metas| {
+            json!({
+                "gatingProgram": metas.gating_program.to_string(),
+                "address": metas.address.to_string(),
+                "exists": metas.exists,
+            })
+        })
+        .collect()
+}
+
+fn extra_metas_display(extra_metas: &[GateExtraMetasStatus]) -> String {
+    if extra_metas.is_empty() {
+        return " none".to_string();
+    }
+    extra_metas
+        .iter()
+        .map(|metas| {
+            format!(
+                "\n  {} ({}, gate {})",
+                metas.address,
+                if metas.exists { "exists" } else { "missing" },
+                metas.gating_program
+            )
+        })
+        .collect()
+}
+
+async fn process_show_config(
+    rpc_client: &Arc<RpcClient>,
+    mint: &Pubkey,
+    output_format: OutputFormat,
+) -> Result<String, Box<dyn Error>> {
+    // inspecting doesn't sign anything
+    let client = TokenAclClient::new_with_rpc(
+        rpc_client.clone(),
+        Arc::new(NullSigner::new(&Pubkey::default())),
+    );
+    let status = client
+        .inspect_mint(mint)
+        .await
+        .map_err(|err| format!("unable to inspect mint: {}", err))?;
+    let config = status
+        .mint_config
+        .as_ref()
+        .ok_or("the mint has no mint config")?;
+
+    let fee_vault = token_acl_client::find_fee_vault_pda(mint).0;
+    let pending_gating_program = token_acl_client::accounts::PendingGatingProgram::find_pda(mint).0;
+    let additional_gating_programs: Vec<String> = config
+        .additional_gating_programs
+        .iter()
+        .filter_map(optional_pubkey)
+        .collect();
+
+    let json = json!({
+        "mint": mint.to_string(),
+        "mintConfig": status.mint_config_address.to_string(),
+        "tokenProgram": status.token_program.to_string(),
+        "managed": status.is_managed(),
+        "version": config.version,
+        "authority": config.freeze_authority.to_string(),
+        "pendingAuthority": optional_pubkey(&config.pending_authority),
+        "freezeOperator": config.freeze_operator.to_string(),
+        "thawOperator": config.thaw_operator.to_string(),
+        "pauseGuardian": optional_pubkey(&config.pause_guardian),
+        "gatingProgram": optional_pubkey(&config.gating_program),
+        "freezeGatingProgram": optional_pubkey(&config.freeze_gating_program),
+        "additionalGatingPrograms": additional_gating_programs,
+        "thawGateMode": gate_mode_name(config.thaw_gate_mode),
+        "freezeGateMode": gate_mode_name(config.freeze_gate_mode),
+        "enablePermissionlessThaw": config.enable_permissionless_thaw,
+        "enablePermissionlessFreeze": config.enable_permissionless_freeze,
+        "paused": config.paused,
+        "stickyFreeze": config.sticky_freeze,
+        "permissionlessFee": config.permissionless_fee,
+        "thawTtl": config.thaw_ttl,
+        "gatingProgramDelay": config.gating_program_delay,
+        "metadataGatingProgram": status.metadata_gating_program.map(|program| program.to_string()),
+        "metadataConsistent": status.is_metadata_consistent(),
+        "feeVault": fee_vault.to_string(),
+        "pendingGatingProgram": pending_gating_program.to_string(),
+        "thawExtraMetas": extra_metas_json(&status.thaw_extra_metas),
+        "freezeExtraMetas": extra_metas_json(&status.freeze_extra_metas),
+    });
+
+    output_format.format(json, || {
+        let none = || "none".to_string();
+        format!(
+            "Mint: {}\nMint config: {}\nToken program: {}\nManaged by Token ACL: {}\nVersion: {}\n\
+             Authority: {}\nPending authority: {}\nFreeze operator: {}\nThaw operator: {}\n\
+             Pause guardian: {}\nGating program: {}\nFreeze gating program: {}\n\
+             Additional gating programs: {}\nThaw gate mode: {}\nFreeze gate mode: {}\n\
+             Permissionless thaw: {}\nPermissionless freeze: {}\nPaused: {}\nSticky freeze: {}\n\
+             Permissionless fee: {} lamports\nThaw TTL: {} seconds\n\
+             Gating program delay: {} seconds\nMetadata gating program: {} ({})\nFee vault: {}\n\
+             Pending gating program: {}\nThaw extra metas:{}\nFreeze extra metas:{}",
+            mint,
+            status.mint_config_address,
+            status.token_program,
+            status.is_managed(),
+            config.version,
+            config.freeze_authority,
+            optional_pubkey(&config.pending_authority).unwrap_or_else(none),
+            config.freeze_operator,
+            config.thaw_operator,
+            optional_pubkey(&config.pause_guardian).unwrap_or_else(none),
+            optional_pubkey(&config.gating_program).unwrap_or_else(none),
+            optional_pubkey(&config.freeze_gating_program)
+                .unwrap_or_else(|| "same as gating program".to_string()),
+            if additional_gating_programs.is_empty() {
+                none()
+            } else {
+                additional_gating_programs.join(", ")
+            },
+            gate_mode_name(config.thaw_gate_mode),
+            gate_mode_name(config.freeze_gate_mode),
+            if config.enable_permissionless_thaw {
+                "enabled"
+            } else {
+                "disabled"
+            },
+            if config.enable_permissionless_freeze {
+                "enabled"
+            } else {
+                "disabled"
+            },
+            config.paused,
+            config.sticky_freeze,
+            config.permissionless_fee,
+            config.thaw_ttl,
+            config.gating_program_delay,
+            status
+                .metadata_gating_program
+                .map(|program| program.to_string())
+                .unwrap_or_else(none),
+            if status.is_metadata_consistent() {
+                "consistent"
+            } else {
+                "inconsistent"
+            },
+            fee_vault,
+            pending_gating_program,
+            extra_metas_display(&status.thaw_extra_metas),
+            extra_metas_display(&status.freeze_extra_metas),
+        )
+    })
+}
+
+/// Whether a permissionless thaw or freeze of the token account would currently succeed,
+/// with the reason when it wouldn't. The instruction is simulated so that the gates get
+/// their say.
+async fn check_permissionless(
+    rpc_client: &Arc<RpcClient>,
+    authority: &Pubkey,
+    token_account_pk: &Pubkey,
+    token_account: &Account,
+    token_program: &Pubkey,
+    thaw: bool,
+) -> Result<(), String> {
+    let expected_state = if thaw {
+        AccountState::Frozen
+    } else {
+        AccountState::Initialized
+    };
+    if token_account.state != expected_state {
+        return Err(format!("token account is {:?}", token_account.state).to_lowercase());
+    }
+
+    let mint_config = token_acl_client::accounts::MintConfig::find_pda(&token_account.mint).0;
+    let fetch_account_data_fn = |pubkey: Pubkey| async move {
+        Ok(rpc_client.get_account(&pubkey).await.map(|a| a.data).ok())
+    };
+    let ix = if thaw {
+        token_acl_client::create_thaw_permissionless_instruction_with_extra_metas(
+            authority,
+            token_account_pk,
+            &token_account.mint,
+            &mint_config,
+            token_program,
+            &token_account.owner,
+            false,
+            fetch_account_data_fn,
+        )
+        .await
+    } else {
+        token_acl_client::create_freeze_permissionless_instruction_with_extra_metas(
+            authority,
+            token_account_pk,
+            &token_account.mint,
+            &mint_config,
+            token_program,
+            &token_account.owner,
+            false,
+            fetch_account_data_fn,
+        )
+        .await
+    }
+    .map_err(|err| err.to_string())?;
+
+    let transaction = Transaction::new_unsigned(Message::new(&[ix], Some(authority)));
+    let result = rpc_client
+        .simulate_transaction_with_config(
+            &transaction,
+            RpcSimulateTransactionConfig {
+                sig_verify: false,
+                replace_recent_blockhash: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(|err| format!("unable to simulate: {}", err))?;
+
+    match result.value.err {
+        Some(err) => Err(err.to_string()),
+        None => Ok(()),
+    }
+}
+
+async fn process_inspect_account(
+    rpc_client: &Arc<RpcClient>,
+    payer: Option<&Arc<dyn Signer>>,
+    token_account_pk: &Pubkey,
+    output_format: OutputFormat,
+) -> Result<String, Box<dyn Error>> {
+    let token_account_data = rpc_client
+        .get_account(token_account_pk)
+        .await
+        .map_err(|err| format!("unable to get token account: {}", err))?;
+    let token_account = StateWithExtensions::<Account>::unpack(&token_account_data.data)
+        .map_err(|err| format!("unable to unpack token account: {}", err))?
+        .base;
+    let token_program = token_account_data.owner;
+
+    let flag_account = token_acl_client::accounts::FlagAccount::find_pda(token_account_pk).0;
+    let mint_config = token_acl_client::accounts::MintConfig::find_pda(&token_account.mint).0;
+
+    // without a keypair, the owner is the one expected to thaw or freeze
+    let authority = payer.map_or(token_account.owner, |payer| payer.pubkey());
+    let thaw = check_permissionless(
+        rpc_client,
+        &authority,
+        token_account_pk,
+        &token_account,
+        &token_program,
+        true,
+    )
+    .await;
+    let freeze = check_permissionless(
+        rpc_client,
+        &authority,
+        token_account_pk,
+        &token_account,
+        &token_program,
+        false,
+    )
+    .await;
+
+    let state = format!("{:?}", token_account.state).to_lowercase();
+    let json = json!({
+        "tokenAccount": token_account_pk.to_string(),
+        "mint": token_account.mint.to_string(),
+        "owner": token_account.owner.to_string(),
+        "state": state,
+        "tokenProgram": token_program.to_string(),
+        "mintConfig": mint_config.to_string(),
+        "flagAccount": flag_account.to_string(),
+        "permissionlessThaw": {
+            "allowed": thaw.is_ok(),
+            "reason": thaw.as_ref().err(),
+        },
+        "permissionlessFreeze": {
+            "allowed": freeze.is_ok(),
+            "reason": freeze.as_ref().err(),
+        },
+    });
+
+    output_format.format(json, || {
+        let allowed = |result: &Result<(), String>| match result {
+            Ok(()) => "allowed".to_string(),
+            Err(reason) => format!("not allowed ({})", reason),
+        };
+        format!(
+            "Token account: {}\nMint: {}\nOwner: {}\nState: {}\nToken program: {}\n\
+             Mint config: {}\nFlag account: {}\nPermissionless thaw: {}\n\
+             Permissionless freeze: {}",
+            token_account_pk,
+            token_account.mint,
+            token_account.owner,
+            state,
+            token_program,
+            mint_config,
+            flag_account,
+            allowed(&thaw),
+            allowed(&freeze),
+        )
+    })
+}
+
+async fn process_list_configs(
+    rpc_client: &Arc<RpcClient>,
+    filter: &MintConfigFilter,
+    after: Option<&Pubkey>,
+    limit: Option<usize>,
+    output_format: OutputFormat,
+) -> Result<String, Box<dyn Error>> {
+    let MintConfigPage {
+        mint_configs: page,
+        next_cursor,
+    } = get_mint_config_page(rpc_client, filter, after, limit.unwrap_or(usize::MAX))
+        .await
+        .map_err(|err| format!("unable to get mint configs: {}", err))?;
+
+    let json = json!({
+        "mintConfigs": page
+            .iter()
+            .map(|(address, config)| {
+                json!({
+                    "mint": config.mint.to_string(),
+                    "mintConfig": address.to_string(),
+                    "authority": config.freeze_authority.to_string(),
+                    "gatingProgram": optional_pubkey(&config.gating_program),
+                    "enablePermissionlessThaw": config.enable_permissionless_thaw,
+                    "enablePermissionlessFreeze": config.enable_permissionless_freeze,
+                    "paused": config.paused,
+                })
+            })
+            .collect::<Vec<_>>(),
+        "nextCursor": next_cursor.map(|mint| mint.to_string()),
+    });
+
+    output_format.format(json, || {
+        let enabled = |enabled: bool| if enabled { "enabled" } else { "disabled" };
+        let mut output = format!(
+            "{:<44}  {:<44}  {:<44}  {:<8}  {:<8}",
+            "Mint", "Authority", "Gating program", "Thaw", "Freeze"
+        );
+        for (_, config) in &page {
+            output.push_str(&format!(
+                "\n{:<44}  {:<44}  {:<44}  {:<8}  {:<8}",
+                config.mint.to_string(),
+                config.freeze_authority.to_string(),
+                optional_pubkey(&config.gating_program).unwrap_or_else(|| "none".to_string()),
+                enabled(config.enable_permissionless_thaw),
+                enabled(config.enable_permissionless_freeze),
+            ));
+        }
+        match next_cursor {
+            Some(mint) => output.push_str(&format!(
+                "\n\nMore mint configs available, continue with --after {}",
+                mint
+            )),
+            None => output.push_str(&format!("\n\n{} mint config(s)", page.len())),
+        }
+        output
+    })
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
     let app_matches = Command::new(crate_name!())
@@ -712,8 +1953,215 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 .help("JSON RPC URL for the cluster [default: value from configuration file]"),
         )
         .subcommand(
-            Command::new("create-config")
-                .about("Creates a new mint config and transfers the freeze authority to the TokenACL program.")
+            Command::new("create-config")
+                .about("Creates a new mint config and transfers the freeze authority to the TokenACL program.")
+                .arg(
+                    Arg::new("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the mint address"),
+                )
+                .arg(
+                    Arg::new("gating_program")
+                        .value_name("GATING_PROGRAM")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(false)
+                        .short('g')
+                        .long("gating-program")
+                        .help("Specify the gating program address"),
+                )
+                .arg(
+                    Arg::new("freeze_authority")
+                        .value_name("FREEZE_AUTHORITY")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(false)
+                        .short('f')
+                        .long("freeze-authority")
+                        .help("Specify the freeze authority address"),
+                )
+        )
+        .subcommand(
+            Command::new("show-config")
+                .about("Shows the mint config of a mint, its PDAs and whether the gates are set up")
+                .arg(
+                    Arg::new("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .help("Specify the mint address"),
+                )
+                .arg(
+                    Arg::new("output_format")
+                        .long("output")
+                        .value_name("FORMAT")
+                        .takes_value(true)
+                        .possible_values(["display", "json", "json-compact"])
+                        .default_value("display")
+                        .help("Return information in specified output format"),
+                )
+        )
+        .subcommand(
+            Command::new("list-configs")
+                .about("Lists the mint configs owned by an authority or using a gating program")
+                .arg(
+                    Arg::new("authority")
+                        .value_name("AUTHORITY")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .long("authority")
+                        .help("Only list the mint configs with this authority"),
+                )
+                .arg(
+                    Arg::new("gating_program")
+                        .value_name("GATING_PROGRAM")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .long("gating-program")
+                        .help("Only list the mint configs using this primary gating program"),
+                )
+                .arg(
+                    Arg::new("freeze_gating_program")
+                        .value_name("FREEZE_GATING_PROGRAM")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .long("freeze-gating-program")
+                        .help("Only list the mint configs using this freeze gating program"),
+                )
+                .arg(
+                    Arg::new("permissionless_thaw")
+                        .value_name("STATE")
+                        .takes_value(true)
+                        .long("permissionless-thaw")
+                        .possible_values(["enabled", "disabled"])
+                        .help("Only list the mint configs with permissionless thaw enabled or disabled"),
+                )
+                .arg(
+                    Arg::new("permissionless_freeze")
+                        .value_name("STATE")
+                        .takes_value(true)
+                        .long("permissionless-freeze")
+                        .possible_values(["enabled", "disabled"])
+                        .help("Only list the mint configs with permissionless freeze enabled or disabled"),
+                )
+                .arg(
+                    Arg::new("after")
+                        .value_name("MINT_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .long("after")
+                        .help("Only list the mint configs of mints after this one, to continue from a previous page"),
+                )
+                .arg(
+                    Arg::new("limit")
+                        .value_name("LIMIT")
+                        .value_parser(clap::value_parser!(u64).range(1..))
+                        .takes_value(true)
+                        .long("limit")
+                        .help("Maximum number of mint configs to list"),
+                )
+                .arg(
+                    Arg::new("output_format")
+                        .long("output")
+                        .value_name("FORMAT")
+                        .takes_value(true)
+                        .possible_values(["display", "json", "json-compact"])
+                        .default_value("display")
+                        .help("Return information in specified output format"),
+                )
+        )
+        .subcommand(
+            Command::new("delete-config")
+                .about("Deletes a mint config, handing the freeze authority back")
+                .arg(
+                    Arg::new("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the mint address"),
+                )
+                .arg(
+                    Arg::new("receiver_address")
+                        .short('r')
+                        .long("receiver")
+                        .value_name("RECEIVER_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(false)
+                        .help("Specify the receiver address"),
+                )
+                .arg(
+                    Arg::new("new_freeze_authority")
+                        .long("new-freeze-authority")
+                        .value_name("NEW_FREEZE_AUTHORITY")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(false)
+                        .help("Specify the new freeze authority of the mint, defaults to the payer"),
+        ))
+        .subcommand(
+            Command::new("set-authority")
+                .about("Sets the authority of a mint config")
+                .arg(
+                    Arg::new("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the mint address"),
+                )
+                .arg(
+                    Arg::new("new_authority")
+                        .value_name("NEW_AUTHORITY")
+                        .value_parser(SignerSourceParserBuilder::default().allow_all().build())
+                        .takes_value(true)
+                        .required_unless_present_any(["accept", "cancel"])
+                        .conflicts_with_all(&["accept", "cancel"])
+                        .short('a')
+                        .long("new-authority")
+                        .help("Specify the new authority, a keypair unless proposing it with --propose"),
+                )
+                .arg(
+                    Arg::new("propose")
+                        .takes_value(false)
+                        .long("propose")
+                        .required(false)
+                        .help("Propose the new authority; it must accept before taking over"),
+                )
+                .arg(
+                    Arg::new("accept")
+                        .takes_value(false)
+                        .long("accept")
+                        .required(false)
+                        .help("Accept a pending authority transfer, signing as the pending authority"),
+                )
+                .arg(
+                    Arg::new("cancel")
+                        .takes_value(false)
+                        .long("cancel")
+                        .required(false)
+                        .help("Cancel a pending authority transfer"),
+                )
+                .group(ArgGroup::new("transfer")
+                    .required(false)
+                    .args(&["propose", "accept", "cancel"])
+                )
+        )
+        .subcommand(
+            Command::new("set-freeze-operator")
+                .about("Sets the freeze operator of a mint config")
                 .arg(
                     Arg::new("mint_address")
                         .value_name("MINT_ADDRESS")
@@ -725,29 +2173,152 @@ async fn main() -> Result<(), Box<dyn Error>> {
                         .help("Specify the mint address"),
                 )
                 .arg(
-                    Arg::new("gating_program")
-                        .value_name("GATING_PROGRAM")
+                    Arg::new("new_operator")
+                        .value_name("NEW_OPERATOR")
                         .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
                         .takes_value(true)
-                        .required(false)
-                        .short('g')
-                        .long("gating-program")
-                        .help("Specify the gating program address"),
+                        .required(true)
+                        .short('o')
+                        .long("new-operator")
+                        .help("Specify the new freeze operator address"),
+        ))
+        .subcommand(
+            Command::new("set-thaw-operator")
+                .about("Sets the thaw operator of a mint config")
+                .arg(
+                    Arg::new("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the mint address"),
                 )
                 .arg(
-                    Arg::new("freeze_authority")
-                        .value_name("FREEZE_AUTHORITY")
+                    Arg::new("new_operator")
+                        .value_name("NEW_OPERATOR")
                         .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
                         .takes_value(true)
-                        .required(false)
-                        .short('f')
-                        .long("freeze-authority")
-                        .help("Specify the freeze authority address"),
+                        .required(true)
+                        .short('o')
+                        .long("new-operator")
+                        .help("Specify the new thaw operator address"),
+        ))
+        .subcommand(
+            Command::new("migrate-config")
+                .about("Migrates a mint config to the current account layout")
+                .arg(
+                    Arg::new("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the mint address"),
+        ))
+        .subcommand(
+            Command::new("pause")
+                .about("Pauses the permissionless instructions of a mint config")
+                .arg(
+                    Arg::new("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the mint address"),
+        ))
+        .subcommand(
+            Command::new("unpause")
+                .about("Unpauses the permissionless instructions of a mint config")
+                .arg(
+                    Arg::new("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the mint address"),
+        ))
+        .subcommand(
+            Command::new("enable-sticky-freeze")
+                .about("Prevents permissionless thaws of accounts frozen by the freeze operator")
+                .arg(
+                    Arg::new("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the mint address"),
+        ))
+        .subcommand(
+            Command::new("disable-sticky-freeze")
+                .about("Allows permissionless thaws of accounts frozen by the freeze operator")
+                .arg(
+                    Arg::new("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the mint address"),
+        ))
+        .subcommand(
+            Command::new("set-permissionless-fee")
+                .about("Sets the lamports charged to the caller of the permissionless instructions")
+                .arg(
+                    Arg::new("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the mint address"),
+                )
+                .arg(
+                    Arg::new("fee")
+                        .value_name("LAMPORTS")
+                        .value_parser(clap::value_parser!(u64))
+                        .takes_value(true)
+                        .required(true)
+                        .long("fee")
+                        .help("Fee paid into the fee vault, 0 to disable it"),
                 )
         )
         .subcommand(
-            Command::new("delete-config")
-                .about("Deletes a list")
+            Command::new("set-thaw-ttl")
+                .about("Sets how long permissionless thaws last before anyone can freeze the account again")
+                .arg(
+                    Arg::new("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the mint address"),
+                )
+                .arg(
+                    Arg::new("ttl")
+                        .value_name("SECONDS")
+                        .value_parser(clap::value_parser!(i64).range(0..))
+                        .takes_value(true)
+                        .required(true)
+                        .long("ttl")
+                        .help("Seconds a permissionless thaw lasts, 0 to disable expiry"),
+                )
+        )
+        .subcommand(
+            Command::new("withdraw-fees")
+                .about("Withdraws permissionless fees from the fee vault of a mint config")
                 .arg(
                     Arg::new("mint_address")
                         .value_name("MINT_ADDRESS")
@@ -758,6 +2329,15 @@ async fn main() -> Result<(), Box<dyn Error>> {
                         .display_order(1)
                         .help("Specify the mint address"),
                 )
+                .arg(
+                    Arg::new("amount")
+                        .value_name("LAMPORTS")
+                        .value_parser(clap::value_parser!(u64))
+                        .takes_value(true)
+                        .required(true)
+                        .long("amount")
+                        .help("Lamports to withdraw"),
+                )
                 .arg(
                     Arg::new("receiver_address")
                         .short('r')
@@ -767,10 +2347,11 @@ async fn main() -> Result<(), Box<dyn Error>> {
                         .takes_value(true)
                         .required(false)
                         .help("Specify the receiver address"),
-        ))
+                )
+        )
         .subcommand(
-            Command::new("set-authority")
-                .about("Sets the authority of a mint config")
+            Command::new("set-pause-guardian")
+                .about("Sets the pause guardian of a mint config")
                 .arg(
                     Arg::new("mint_address")
                         .value_name("MINT_ADDRESS")
@@ -782,15 +2363,23 @@ async fn main() -> Result<(), Box<dyn Error>> {
                         .help("Specify the mint address"),
                 )
                 .arg(
-                    Arg::new("new_authority")
-                        .value_name("NEW_AUTHORITY")
+                    Arg::new("new_pause_guardian")
+                        .value_name("NEW_PAUSE_GUARDIAN")
                         .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
                         .takes_value(true)
-                        .required(true)
-                        .short('a')
-                        .long("new-authority")
-                        .help("Specify the new authority address"),
-        ))
+                        .required_unless_present("clear")
+                        .short('p')
+                        .long("new-pause-guardian")
+                        .help("Specify the new pause guardian address"),
+                )
+                .arg(
+                    Arg::new("clear")
+                        .long("clear")
+                        .takes_value(false)
+                        .conflicts_with("new_pause_guardian")
+                        .help("Remove the pause guardian"),
+                )
+        )
         .subcommand(
             Command::new("set-gating-program")
                 .about("Sets the gating program of a mint config")
@@ -809,12 +2398,144 @@ async fn main() -> Result<(), Box<dyn Error>> {
                         .value_name("NEW_GATING_PROGRAM")
                         .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
                         .takes_value(true)
-                        .required(true)
+                        .required_unless_present_any(["queue", "finalize", "cancel", "show_pending"])
+                        .conflicts_with_all(&["finalize", "cancel", "show_pending"])
                         .display_order(2)
                         .short('g')
                         .long("new-gating-program")
                         .help("Specify the new gating program address"),
-        ))
+                )
+                .arg(
+                    Arg::new("queue")
+                        .takes_value(false)
+                        .long("queue")
+                        .required(false)
+                        .help("Queue the change, required while the config has a gating program delay"),
+                )
+                .arg(
+                    Arg::new("delay")
+                        .value_name("SECONDS")
+                        .value_parser(clap::value_parser!(i64).range(0..))
+                        .takes_value(true)
+                        .long("delay")
+                        .requires("queue")
+                        .help("Gating program delay applied once the queued change is finalized"),
+                )
+                .arg(
+                    Arg::new("finalize")
+                        .takes_value(false)
+                        .long("finalize")
+                        .required(false)
+                        .help("Finalize the queued change once its delay has passed"),
+                )
+                .arg(
+                    Arg::new("cancel")
+                        .takes_value(false)
+                        .long("cancel")
+                        .required(false)
+                        .help("Cancel the queued change"),
+                )
+                .arg(
+                    Arg::new("show_pending")
+                        .takes_value(false)
+                        .long("show-pending")
+                        .required(false)
+                        .help("Show the gating program delay and the queued change, if any"),
+                )
+                .group(ArgGroup::new("timelock")
+                    .required(false)
+                    .args(&["queue", "finalize", "cancel", "show_pending"])
+                )
+        )
+        .subcommand(
+            Command::new("set-freeze-gating-program")
+                .about("Sets the gating program used for permissionless freeze")
+                .arg(
+                    Arg::new("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the mint address"),
+                )
+                .arg(
+                    Arg::new("new_gating_program")
+                        .value_name("NEW_GATING_PROGRAM")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required_unless_present("clear")
+                        .display_order(2)
+                        .short('g')
+                        .long("new-gating-program")
+                        .help("Specify the new freeze gating program address"),
+                )
+                .arg(
+                    Arg::new("clear")
+                        .long("clear")
+                        .takes_value(false)
+                        .conflicts_with("new_gating_program")
+                        .help("Use the mint config gating program for permissionless freeze again"),
+                )
+                .arg(
+                    Arg::new("queue")
+                        .takes_value(false)
+                        .long("queue")
+                        .required(false)
+                        .help("Queue the change, required while the config has a gating program delay. Finalize it with set-gating-program --finalize"),
+                )
+        )
+        .subcommand(
+            Command::new("set-gating-chain")
+                .about("Chains additional gating programs after the gating program of a mint config")
+                .arg(
+                    Arg::new("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the mint address"),
+                )
+                .arg(
+                    Arg::new("additional_gating_programs")
+                        .value_name("GATING_PROGRAM")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .multiple_occurrences(true)
+                        .required(false)
+                        .short('g')
+                        .long("gating-program")
+                        .help("Gating program to chain, in order; omit to clear the chain"),
+                )
+                .arg(
+                    Arg::new("thaw_mode")
+                        .value_name("MODE")
+                        .takes_value(true)
+                        .possible_values(["all", "any"])
+                        .default_value("all")
+                        .long("thaw-mode")
+                        .help("Whether permissionless thaw needs all gating programs or any one of them, with any the caller picks the gate"),
+                )
+                .arg(
+                    Arg::new("freeze_mode")
+                        .value_name("MODE")
+                        .takes_value(true)
+                        .possible_values(["all", "any"])
+                        .default_value("all")
+                        .long("freeze-mode")
+                        .help("Whether permissionless freeze needs all gating programs or any one of them, with any the caller picks the gate"),
+                )
+                .arg(
+                    Arg::new("queue")
+                        .takes_value(false)
+                        .long("queue")
+                        .required(false)
+                        .help("Queue the change, required while the config has a gating program delay. Finalize it with set-gating-program --finalize"),
+                )
+        )
         .subcommand(
             Command::new("set-instructions")
                 .about("Sets the gating program of a mint config")
@@ -927,6 +2648,12 @@ async fn main() -> Result<(), Box<dyn Error>> {
                         .required(true)
                         .help("Specify the token account owner address"),
                 )
+                .arg(
+                    Arg::new("single_instruction")
+                        .long("single-instruction")
+                        .takes_value(false)
+                        .help("Use a single CreateAtaAndThawPermissionless instruction, which doesn't fail if the account exists"),
+                )
         )
         .subcommand(
             Command::new("freeze-permissionless")
@@ -954,27 +2681,77 @@ async fn main() -> Result<(), Box<dyn Error>> {
                     .help("Specify the token account address"),
             )
             .arg(
-                Arg::new("token_account_owner")
-                    .value_name("TOKEN_ACCOUNT_OWNER")
-                    .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                Arg::new("token_account_owner")
+                    .value_name("TOKEN_ACCOUNT_OWNER")
+                    .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                    .takes_value(true)
+                    .long("owner")
+                    .required_unless_present("token_account")
+                    .conflicts_with("token_account")
+                    .help("Specify the token account owner address. Requires the mint address to be specified."),
+            )
+        )
+        .subcommand(
+            Command::new("freeze")
+            .about("Freezes a token account using the defined freeze authority.")
+            .arg(
+                Arg::new("token_account")
+                    .value_name("TOKEN_ACCOUNT")
+                    .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                    .takes_value(true)
+                    .help("Specify the token account address"),
+            )
+            .arg(
+                Arg::new("reason")
+                    .value_name("CODE")
+                    .value_parser(clap::value_parser!(u32))
                     .takes_value(true)
-                    .long("owner")
-                    .required_unless_present("token_account")
-                    .conflicts_with("token_account")
-                    .help("Specify the token account owner address. Requires the mint address to be specified."),
+                    .long("reason")
+                    .help("Record an issuer-defined reason code for the freeze"),
+            )
+            .arg(
+                Arg::new("memo")
+                    .value_name("TEXT")
+                    .takes_value(true)
+                    .long("memo")
+                    .requires("reason")
+                    .help("Memo stored by its SHA-256 hash alongside the reason code"),
             )
         )
         .subcommand(
-            Command::new("freeze")
-            .about("Freezes a token account using the defined freeze authority.")
+            Command::new("freeze-reason")
+            .about("Shows the reason recorded when a token account was frozen.")
             .arg(
                 Arg::new("token_account")
                     .value_name("TOKEN_ACCOUNT")
                     .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
                     .takes_value(true)
+                    .required(true)
                     .help("Specify the token account address"),
             )
         )
+        .subcommand(
+            Command::new("inspect-account")
+                .about("Shows a token account and whether it can currently be thawed or frozen permissionlessly")
+                .arg(
+                    Arg::new("token_account")
+                        .value_name("TOKEN_ACCOUNT")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .help("Specify the token account address"),
+                )
+                .arg(
+                    Arg::new("output_format")
+                        .long("output")
+                        .value_name("FORMAT")
+                        .takes_value(true)
+                        .possible_values(["display", "json", "json-compact"])
+                        .default_value("display")
+                        .help("Return information in specified output format"),
+                )
+        )
         .subcommand(
             Command::new("thaw")
             .about("Thaws a token account using the defined freeze authority.")
@@ -998,17 +2775,28 @@ async fn main() -> Result<(), Box<dyn Error>> {
             solana_cli_config::Config::default()
         };
 
+        let read_only = matches!(
+            command,
+            "show-config" | "list-configs" | "inspect-account" | "freeze-reason"
+        ) || (command == "set-gating-program"
+            && matches.contains_id("show_pending"));
+
         let payer = if let Ok(Some((signer, _))) =
             SignerSource::try_get_signer(matches, "payer", &mut wallet_manager)
         {
-            Box::new(signer)
+            Some(signer)
         } else {
-            signer_from_path(
+            match signer_from_path(
                 matches,
                 &cli_config.keypair_path,
                 "payer",
                 &mut wallet_manager,
-            )?
+            ) {
+                Ok(signer) => Some(signer),
+                // read-only commands don't sign anything, they can run without a keypair
+                Err(_) if read_only => None,
+                Err(err) => return Err(err),
+            }
         };
 
         let json_rpc_url = normalize_to_url_if_moniker(
@@ -1019,7 +2807,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
         Config {
             commitment_config: CommitmentConfig::confirmed(),
-            payer: Arc::from(payer),
+            payer: payer.map(Arc::from),
             json_rpc_url,
             verbose: matches.try_contains_id("verbose")?,
         }
@@ -1048,7 +2836,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
                     .unwrap();
             let response = process_create_config(
                 &rpc_client,
-                &config.payer,
+                config.payer()?,
                 freeze_authority,
                 &mint_address,
                 gating_program.as_ref(),
@@ -1060,7 +2848,71 @@ async fn main() -> Result<(), Box<dyn Error>> {
             });
             println!("{}", response);
         }
-        ("delete-list", arg_matches) => {
+        ("show-config", arg_matches) => {
+            let mint_address =
+                SignerSource::try_get_pubkey(arg_matches, "mint_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_show_config(
+                &rpc_client,
+                &mint_address,
+                OutputFormat::from_matches(arg_matches),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: show-config: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("list-configs", arg_matches) => {
+            let enabled = |name: &str| {
+                arg_matches
+                    .get_one::<String>(name)
+                    .map(|state| state == "enabled")
+            };
+            let filter = MintConfigFilter {
+                authority: SignerSource::try_get_pubkey(
+                    arg_matches,
+                    "authority",
+                    &mut wallet_manager,
+                )
+                .unwrap(),
+                gating_program: SignerSource::try_get_pubkey(
+                    arg_matches,
+                    "gating_program",
+                    &mut wallet_manager,
+                )
+                .unwrap(),
+                freeze_gating_program: SignerSource::try_get_pubkey(
+                    arg_matches,
+                    "freeze_gating_program",
+                    &mut wallet_manager,
+                )
+                .unwrap(),
+                enable_permissionless_thaw: enabled("permissionless_thaw"),
+                enable_permissionless_freeze: enabled("permissionless_freeze"),
+            };
+            let after =
+                SignerSource::try_get_pubkey(arg_matches, "after", &mut wallet_manager).unwrap();
+            let limit = arg_matches
+                .get_one::<u64>("limit")
+                .map(|limit| *limit as usize);
+            let response = process_list_configs(
+                &rpc_client,
+                &filter,
+                after.as_ref(),
+                limit,
+                OutputFormat::from_matches(arg_matches),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: list-configs: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("delete-config", arg_matches) => {
             let mint_address =
                 SignerSource::try_get_pubkey(arg_matches, "mint_address", &mut wallet_manager)
                     .unwrap()
@@ -1068,15 +2920,22 @@ async fn main() -> Result<(), Box<dyn Error>> {
             let receiver_address =
                 SignerSource::try_get_pubkey(arg_matches, "receiver_address", &mut wallet_manager)
                     .unwrap();
+            let new_freeze_authority = SignerSource::try_get_pubkey(
+                arg_matches,
+                "new_freeze_authority",
+                &mut wallet_manager,
+            )
+            .unwrap();
             let response = process_delete_config(
                 &rpc_client,
-                &config.payer,
+                config.payer()?,
                 &mint_address,
                 receiver_address.as_ref(),
+                new_freeze_authority.as_ref(),
             )
             .await
             .unwrap_or_else(|err| {
-                eprintln!("error: delete-list: {}", err);
+                eprintln!("error: delete-config: {}", err);
                 exit(1);
             });
             println!("{}", response);
@@ -1088,42 +2947,346 @@ async fn main() -> Result<(), Box<dyn Error>> {
                     .unwrap();
             let new_authority =
                 SignerSource::try_get_pubkey(arg_matches, "new_authority", &mut wallet_manager)
+                    .unwrap();
+            let response = if arg_matches.contains_id("accept") {
+                process_accept_authority(&rpc_client, config.payer()?, &mint_address).await
+            } else if arg_matches.contains_id("cancel") {
+                process_cancel_authority_transfer(&rpc_client, config.payer()?, &mint_address).await
+            } else if arg_matches.contains_id("propose") {
+                process_propose_authority(
+                    &rpc_client,
+                    config.payer()?,
+                    &mint_address,
+                    &new_authority.unwrap(),
+                )
+                .await
+            } else {
+                // the one step transfer needs the new authority's signature
+                match SignerSource::try_get_signer(
+                    arg_matches,
+                    "new_authority",
+                    &mut wallet_manager,
+                ) {
+                    Ok(Some((new_authority, _))) => {
+                        process_set_authority(
+                            &rpc_client,
+                            config.payer()?,
+                            &mint_address,
+                            new_authority.as_ref(),
+                        )
+                        .await
+                    }
+                    _ => Err(
+                        "the new authority has to sign, pass its keypair or use --propose".into(),
+                    ),
+                }
+            }
+            .unwrap_or_else(|err| {
+                eprintln!("error: set-authority: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("set-freeze-operator", arg_matches) => {
+            let mint_address =
+                SignerSource::try_get_pubkey(arg_matches, "mint_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let new_operator =
+                SignerSource::try_get_pubkey(arg_matches, "new_operator", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_set_freeze_operator(
+                &rpc_client,
+                config.payer()?,
+                &mint_address,
+                &new_operator,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: set-freeze-operator: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("set-thaw-operator", arg_matches) => {
+            let mint_address =
+                SignerSource::try_get_pubkey(arg_matches, "mint_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let new_operator =
+                SignerSource::try_get_pubkey(arg_matches, "new_operator", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_set_thaw_operator(
+                &rpc_client,
+                config.payer()?,
+                &mint_address,
+                &new_operator,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: set-thaw-operator: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("migrate-config", arg_matches) => {
+            let mint_address =
+                SignerSource::try_get_pubkey(arg_matches, "mint_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_migrate_config(&rpc_client, config.payer()?, &mint_address)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: migrate-config: {}", err);
+                    exit(1);
+                });
+            println!("{}", response);
+        }
+        ("pause", arg_matches) | ("unpause", arg_matches) => {
+            let paused = command == "pause";
+            let mint_address =
+                SignerSource::try_get_pubkey(arg_matches, "mint_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_set_paused(&rpc_client, config.payer()?, &mint_address, paused)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: set paused: {}", err);
+                    exit(1);
+                });
+            println!("{}", response);
+        }
+        ("enable-sticky-freeze", arg_matches) | ("disable-sticky-freeze", arg_matches) => {
+            let sticky_freeze = command == "enable-sticky-freeze";
+            let mint_address =
+                SignerSource::try_get_pubkey(arg_matches, "mint_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_set_sticky_freeze(
+                &rpc_client,
+                config.payer()?,
+                &mint_address,
+                sticky_freeze,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: set sticky freeze: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("set-permissionless-fee", arg_matches) => {
+            let mint_address =
+                SignerSource::try_get_pubkey(arg_matches, "mint_address", &mut wallet_manager)
                     .unwrap()
                     .unwrap();
+            let fee = *arg_matches.get_one::<u64>("fee").unwrap();
             let response =
-                process_set_authority(&rpc_client, &config.payer, &mint_address, &new_authority)
+                process_set_permissionless_fee(&rpc_client, config.payer()?, &mint_address, fee)
                     .await
                     .unwrap_or_else(|err| {
-                        eprintln!("error: set-authority: {}", err);
+                        eprintln!("error: set permissionless fee: {}", err);
                         exit(1);
                     });
             println!("{}", response);
         }
-        ("set-gating-program", arg_matches) => {
+        ("withdraw-fees", arg_matches) => {
             let mint_address =
                 SignerSource::try_get_pubkey(arg_matches, "mint_address", &mut wallet_manager)
                     .unwrap()
                     .unwrap();
-            let new_gating_program = SignerSource::try_get_pubkey(
+            let receiver_address =
+                SignerSource::try_get_pubkey(arg_matches, "receiver_address", &mut wallet_manager)
+                    .unwrap();
+            let amount = *arg_matches.get_one::<u64>("amount").unwrap();
+            let response = process_withdraw_fees(
+                &rpc_client,
+                config.payer()?,
+                &mint_address,
+                receiver_address.as_ref(),
+                amount,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: withdraw fees: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("set-thaw-ttl", arg_matches) => {
+            let mint_address =
+                SignerSource::try_get_pubkey(arg_matches, "mint_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let thaw_ttl = *arg_matches.get_one::<i64>("ttl").unwrap();
+            let response =
+                process_set_thaw_ttl(&rpc_client, config.payer()?, &mint_address, thaw_ttl)
+                    .await
+                    .unwrap_or_else(|err| {
+                        eprintln!("error: set thaw ttl: {}", err);
+                        exit(1);
+                    });
+            println!("{}", response);
+        }
+        ("set-pause-guardian", arg_matches) => {
+            let mint_address =
+                SignerSource::try_get_pubkey(arg_matches, "mint_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let new_pause_guardian = SignerSource::try_get_pubkey(
                 arg_matches,
-                "new_gating_program",
+                "new_pause_guardian",
                 &mut wallet_manager,
             )
             .unwrap()
-            .unwrap();
-            let response = process_set_gating_program(
+            .unwrap_or_default();
+            let response = process_set_pause_guardian(
                 &rpc_client,
-                &config.payer,
+                config.payer()?,
                 &mint_address,
-                &new_gating_program,
+                &new_pause_guardian,
             )
             .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: set-pause-guardian: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("set-gating-program", arg_matches) => {
+            let mint_address =
+                SignerSource::try_get_pubkey(arg_matches, "mint_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let new_gating_program = SignerSource::try_get_pubkey(
+                arg_matches,
+                "new_gating_program",
+                &mut wallet_manager,
+            )
+            .unwrap();
+            if arg_matches.contains_id("show_pending") {
+                let response = process_show_pending_gating_program(&rpc_client, &mint_address)
+                    .await
+                    .unwrap_or_else(|err| {
+                        eprintln!("error: set-gating-program: {}", err);
+                        exit(1);
+                    });
+                println!("{}", response);
+                return Ok(());
+            }
+            let response = if arg_matches.contains_id("queue") {
+                process_queue_gating_program(
+                    &rpc_client,
+                    config.payer()?,
+                    &mint_address,
+                    new_gating_program,
+                    arg_matches.get_one::<i64>("delay").copied(),
+                )
+                .await
+            } else if arg_matches.contains_id("finalize") {
+                process_finalize_gating_program(&rpc_client, config.payer()?, &mint_address).await
+            } else if arg_matches.contains_id("cancel") {
+                process_cancel_gating_program(&rpc_client, config.payer()?, &mint_address).await
+            } else {
+                process_set_gating_program(
+                    &rpc_client,
+                    config.payer()?,
+                    &mint_address,
+                    &new_gating_program.unwrap(),
+                )
+                .await
+            }
             .unwrap_or_else(|err| {
                 eprintln!("error: set-gating-program: {}", err);
                 exit(1);
             });
             println!("{}", response);
         }
+        ("set-freeze-gating-program", arg_matches) => {
+            let mint_address =
+                SignerSource::try_get_pubkey(arg_matches, "mint_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let new_freeze_gating_program = SignerSource::try_get_pubkey(
+                arg_matches,
+                "new_gating_program",
+                &mut wallet_manager,
+            )
+            .unwrap()
+            .unwrap_or_default();
+            let response = if arg_matches.contains_id("queue") {
+                process_queue_gating_chain(
+                    &rpc_client,
+                    config.payer()?,
+                    &mint_address,
+                    None,
+                    Some(&new_freeze_gating_program),
+                )
+                .await
+            } else {
+                process_set_freeze_gating_program(
+                    &rpc_client,
+                    config.payer()?,
+                    &mint_address,
+                    &new_freeze_gating_program,
+                )
+                .await
+            }
+            .unwrap_or_else(|err| {
+                eprintln!("error: set-freeze-gating-program: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("set-gating-chain", arg_matches) => {
+            let mint_address =
+                SignerSource::try_get_pubkey(arg_matches, "mint_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let additional_gating_programs = SignerSource::try_get_pubkeys(
+                arg_matches,
+                "additional_gating_programs",
+                &mut wallet_manager,
+            )
+            .unwrap()
+            .unwrap_or_default();
+            let gate_mode =
+                |name: &str| match arg_matches.get_one::<String>(name).map(String::as_str) {
+                    Some("any") => token_acl_client::GateMode::Any,
+                    _ => token_acl_client::GateMode::All,
+                };
+            let response = if arg_matches.contains_id("queue") {
+                process_queue_gating_chain(
+                    &rpc_client,
+                    config.payer()?,
+                    &mint_address,
+                    Some((
+                        additional_gating_programs.as_slice(),
+                        gate_mode("thaw_mode"),
+                        gate_mode("freeze_mode"),
+                    )),
+                    None,
+                )
+                .await
+            } else {
+                process_set_gating_chain(
+                    &rpc_client,
+                    config.payer()?,
+                    &mint_address,
+                    &additional_gating_programs,
+                    gate_mode("thaw_mode"),
+                    gate_mode("freeze_mode"),
+                )
+                .await
+            }
+            .unwrap_or_else(|err| {
+                eprintln!("error: set-gating-chain: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
         ("set-instructions", arg_matches) => {
             let mint_address =
                 SignerSource::try_get_pubkey(arg_matches, "mint_address", &mut wallet_manager)
@@ -1137,7 +3300,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
             let response = process_set_instructions(
                 &rpc_client,
-                &config.payer,
+                config.payer()?,
                 &mint_address,
                 enable_thaw,
                 enable_freeze,
@@ -1164,7 +3327,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
             .unwrap();
             let response = process_thaw_permissionless(
                 &rpc_client,
-                &config.payer,
+                config.payer()?,
                 mint_address,
                 token_account,
                 token_account_owner,
@@ -1188,11 +3351,13 @@ async fn main() -> Result<(), Box<dyn Error>> {
             )
             .unwrap()
             .unwrap();
+            let single_instruction = arg_matches.is_present("single_instruction");
             let response = process_create_ata_and_thaw_permissionless(
                 &rpc_client,
-                &config.payer,
+                config.payer()?,
                 mint_address,
                 token_account_owner,
+                single_instruction,
             )
             .await
             .unwrap_or_else(|err| {
@@ -1216,7 +3381,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
             .unwrap();
             let response = process_freeze_permissionless(
                 &rpc_client,
-                &config.payer,
+                config.payer()?,
                 mint_address,
                 token_account,
                 token_account_owner,
@@ -1233,7 +3398,14 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 SignerSource::try_get_pubkey(arg_matches, "token_account", &mut wallet_manager)
                     .unwrap()
                     .unwrap();
-            let response = process_freeze(&rpc_client, &config.payer, token_account)
+            let reason = arg_matches.get_one::<u32>("reason").map(|reason_code| {
+                let memo_hash = arg_matches
+                    .get_one::<String>("memo")
+                    .map(|memo| solana_sdk::hash::hash(memo.as_bytes()).to_bytes())
+                    .unwrap_or_default();
+                (*reason_code, memo_hash)
+            });
+            let response = process_freeze(&rpc_client, config.payer()?, token_account, reason)
                 .await
                 .unwrap_or_else(|err| {
                     eprintln!("error: freeze: {}", err);
@@ -1241,12 +3413,43 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 });
             println!("{}", response);
         }
+        ("freeze-reason", arg_matches) => {
+            let token_account =
+                SignerSource::try_get_pubkey(arg_matches, "token_account", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_show_freeze_reason(&rpc_client, &token_account)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: freeze-reason: {}", err);
+                    exit(1);
+                });
+            println!("{}", response);
+        }
+        ("inspect-account", arg_matches) => {
+            let token_account =
+                SignerSource::try_get_pubkey(arg_matches, "token_account", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_inspect_account(
+                &rpc_client,
+                config.payer.as_ref(),
+                &token_account,
+                OutputFormat::from_matches(arg_matches),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: inspect-account: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
         ("thaw", arg_matches) => {
             let token_account =
                 SignerSource::try_get_pubkey(arg_matches, "token_account", &mut wallet_manager)
                     .unwrap()
                     .unwrap();
-            let response = process_thaw(&rpc_client, &config.payer, token_account)
+            let response = process_thaw(&rpc_client, config.payer()?, token_account)
                 .await
                 .unwrap_or_else(|err| {
                     eprintln!("error: thaw: {}", err);
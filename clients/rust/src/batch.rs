@@ -0,0 +1,188 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+
+use crate::{
+    accounts::{FreezeReason, MintConfig},
+    thaw_expiry_account_meta,
+};
+
+/// Maximum number of token accounts passed to a single `FreezeBatch` or `ThawBatch`
+/// instruction, chosen so that a transaction holding one batch instruction fits the
+/// packet size limit, even with a fee payer other than the authority.
+pub const MAX_BATCH_TOKEN_ACCOUNTS: usize = 24;
+
+/// Maximum number of token accounts passed to a single `FreezeBatchWithReason`
+/// instruction, each token account also needs its freeze reason account.
+pub const MAX_BATCH_WITH_REASON_TOKEN_ACCOUNTS: usize = 11;
+
+/// Maximum number of token accounts passed to a single `ThawBatch` instruction when the
+/// config has a `thaw_ttl`, each token account also needs its thaw expiry account.
+pub const MAX_BATCH_WITH_THAW_EXPIRY_TOKEN_ACCOUNTS: usize = 11;
+
+/// Maximum number of token accounts passed to a single `ThawBatch` instruction that closes
+/// their freeze reasons, each token account also needs its freeze reason and rent receiver.
+pub const MAX_BATCH_WITH_FREEZE_REASON_TOKEN_ACCOUNTS: usize = 7;
+
+/// Maximum number of token accounts passed to a single `ThawBatch` instruction that closes
+/// their freeze reasons when the config has a `thaw_ttl`, each token account also needs its
+/// thaw expiry, freeze reason and rent receiver.
+pub const MAX_BATCH_WITH_THAW_EXPIRY_AND_FREEZE_REASON_TOKEN_ACCOUNTS: usize = 5;
+
+/// Builds `FreezeBatch` instructions for `token_accounts`, split into chunks of at most
+/// [`MAX_BATCH_TOKEN_ACCOUNTS`]. Each instruction is meant to be sent in its own transaction.
+pub fn create_freeze_batch_instructions(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    token_accounts: &[Pubkey],
+    skip_frozen: bool,
+) -> Vec<Instruction> {
+    let mint_config = MintConfig::find_pda(mint).0;
+
+    token_accounts
+        .chunks(MAX_BATCH_TOKEN_ACCOUNTS)
+        .map(|chunk| {
+            crate::instructions::FreezeBatchBuilder::new()
+                .authority(*authority)
+                .mint(*mint)
+                .mint_config(mint_config)
+                .skip_frozen(skip_frozen)
+                .add_remaining_accounts(&batch_account_metas(chunk))
+                .instruction()
+        })
+        .collect()
+}
+
+/// Builds `FreezeBatchWithReason` instructions for `token_accounts`, split into chunks of
+/// at most [`MAX_BATCH_WITH_REASON_TOKEN_ACCOUNTS`]. Each instruction is meant to be sent
+/// in its own transaction.
+pub fn create_freeze_batch_with_reason_instructions(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    mint: &Pubkey,
+    token_accounts: &[Pubkey],
+    skip_frozen: bool,
+    reason_code: u32,
+    memo_hash: [u8; 32],
+) -> Vec<Instruction> {
+    let mint_config = MintConfig::find_pda(mint).0;
+
+    token_accounts
+        .chunks(MAX_BATCH_WITH_REASON_TOKEN_ACCOUNTS)
+        .map(|chunk| {
+            let metas: Vec<AccountMeta> = chunk
+                .iter()
+                .flat_map(|token_account| {
+                    [
+                        AccountMeta::new(*token_account, false),
+                        AccountMeta::new(FreezeReason::find_pda(token_account).0, false),
+                    ]
+                })
+                .collect();
+
+            crate::instructions::FreezeBatchWithReasonBuilder::new()
+                .authority(*authority)
+                .payer(*payer)
+                .mint(*mint)
+                .mint_config(mint_config)
+                .skip_frozen(skip_frozen)
+                .reason_code(reason_code)
+                .memo_hash(memo_hash)
+                .add_remaining_accounts(&metas)
+                .instruction()
+        })
+        .collect()
+}
+
+/// Builds `ThawBatch` instructions for `token_accounts`, split into chunks of at most
+/// [`MAX_BATCH_TOKEN_ACCOUNTS`], or [`MAX_BATCH_WITH_THAW_EXPIRY_TOKEN_ACCOUNTS`] when the
+/// config has a `thaw_ttl` and each token account is followed by its thaw expiry. Each
+/// instruction is meant to be sent in its own transaction.
+///
+/// The freeze reasons of the token accounts are left open, with sticky freeze the batch fails,
+/// use [`create_thaw_batch_with_reason_instructions`] instead.
+pub fn create_thaw_batch_instructions(
+    authority: &Pubkey,
+    mint_config: &MintConfig,
+    token_accounts: &[Pubkey],
+    skip_thawed: bool,
+) -> Vec<Instruction> {
+    let mint_config_pubkey = MintConfig::find_pda(&mint_config.mint).0;
+    let chunk_size = if mint_config.thaw_ttl > 0 {
+        MAX_BATCH_WITH_THAW_EXPIRY_TOKEN_ACCOUNTS
+    } else {
+        MAX_BATCH_TOKEN_ACCOUNTS
+    };
+
+    token_accounts
+        .chunks(chunk_size)
+        .map(|chunk| {
+            let metas: Vec<AccountMeta> = chunk
+                .iter()
+                .flat_map(|token_account| {
+                    std::iter::once(AccountMeta::new(*token_account, false))
+                        .chain(thaw_expiry_account_meta(mint_config, token_account))
+                })
+                .collect();
+
+            crate::instructions::ThawBatchBuilder::new()
+                .authority(*authority)
+                .mint(mint_config.mint)
+                .mint_config(mint_config_pubkey)
+                .skip_thawed(skip_thawed)
+                .add_remaining_accounts(&metas)
+                .instruction()
+        })
+        .collect()
+}
+
+/// Builds `ThawBatch` instructions that also close the freeze reasons of `token_accounts`,
+/// given as `(token_account, receiver)` pairs where the receiver is the payer recorded in the
+/// freeze reason, or any account if there is none. Split into chunks of at most
+/// [`MAX_BATCH_WITH_FREEZE_REASON_TOKEN_ACCOUNTS`], or
+/// [`MAX_BATCH_WITH_THAW_EXPIRY_AND_FREEZE_REASON_TOKEN_ACCOUNTS`] when the config has a
+/// `thaw_ttl`. Each instruction is meant to be sent in its own transaction.
+pub fn create_thaw_batch_with_reason_instructions(
+    authority: &Pubkey,
+    mint_config: &MintConfig,
+    token_accounts: &[(Pubkey, Pubkey)],
+    skip_thawed: bool,
+) -> Vec<Instruction> {
+    let mint_config_pubkey = MintConfig::find_pda(&mint_config.mint).0;
+    let chunk_size = if mint_config.thaw_ttl > 0 {
+        MAX_BATCH_WITH_THAW_EXPIRY_AND_FREEZE_REASON_TOKEN_ACCOUNTS
+    } else {
+        MAX_BATCH_WITH_FREEZE_REASON_TOKEN_ACCOUNTS
+    };
+
+    token_accounts
+        .chunks(chunk_size)
+        .map(|chunk| {
+            let metas: Vec<AccountMeta> = chunk
+                .iter()
+                .flat_map(|(token_account, receiver)| {
+                    std::iter::once(AccountMeta::new(*token_account, false))
+                        .chain(thaw_expiry_account_meta(mint_config, token_account))
+                        .chain([
+                            AccountMeta::new(FreezeReason::find_pda(token_account).0, false),
+                            AccountMeta::new(*receiver, false),
+                        ])
+                })
+                .collect();
+
+            crate::instructions::ThawBatchBuilder::new()
+                .authority(*authority)
+                .mint(mint_config.mint)
+                .mint_config(mint_config_pubkey)
+                .skip_thawed(skip_thawed)
+                .add_remaining_accounts(&metas)
+                .instruction()
+        })
+        .collect()
+}
+
+fn batch_account_metas(token_accounts: &[Pubkey]) -> Vec<AccountMeta> {
+    token_accounts
+        .iter()
+        .map(|token_account| AccountMeta::new(*token_account, false))
+        .collect()
+}
@@ -0,0 +1,534 @@
+use std::{collections::HashMap, future::Future, pin::Pin, sync::Arc};
+
+use solana_account::Account;
+use solana_hash::Hash;
+use solana_instruction::Instruction;
+use solana_program_pack::Pack;
+use solana_pubkey::Pubkey;
+use solana_signature::Signature;
+use solana_signer::{Signer, SignerError};
+use solana_transaction::Transaction;
+use spl_associated_token_account_interface::address::get_associated_token_address_with_program_id;
+use spl_tlv_account_resolution::state::AccountFetchError;
+
+use crate::accounts::{FreezeReason, MintConfig, PendingGatingProgram};
+
+/// Future returned by [`AccountFetcher::get_account`].
+pub type FetchAccountFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Option<Account>, AccountFetchError>> + Send + 'a>>;
+
+/// Error returned by [`TransactionSender`] implementations.
+pub type TransactionSendError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Future returned by the [`TransactionSender`] methods.
+pub type SendFuture<'a, T> =
+    Pin<Box<dyn Future<Output = Result<T, TransactionSendError>> + Send + 'a>>;
+
+/// Signer used by [`TokenAclClient`], which has to be shareable across threads for the
+/// client's futures to be `Send`.
+pub type ClientSigner = Arc<dyn Signer + Send + Sync>;
+
+/// Source of on-chain accounts for [`TokenAclClient`], implemented for the nonblocking
+/// `RpcClient` with the `fetch` feature and for in-memory maps. Tests can implement it on
+/// top of LiteSVM.
+pub trait AccountFetcher: Send + Sync {
+    /// Fetches `pubkey`, `None` when the account doesn't exist.
+    fn get_account<'a>(&'a self, pubkey: &'a Pubkey) -> FetchAccountFuture<'a>;
+}
+
+/// Submits the transactions built by [`TokenAclClient`], implemented for the nonblocking
+/// `RpcClient` with the `fetch` feature.
+pub trait TransactionSender: Send + Sync {
+    fn get_latest_blockhash(&self) -> SendFuture<'_, Hash>;
+
+    /// Sends `transaction` and waits for its confirmation.
+    fn send_transaction<'a>(&'a self, transaction: &'a Transaction) -> SendFuture<'a, Signature>;
+}
+
+impl AccountFetcher for HashMap<Pubkey, Account> {
+    fn get_account<'a>(&'a self, pubkey: &'a Pubkey) -> FetchAccountFuture<'a> {
+        Box::pin(async move { Ok(self.get(pubkey).cloned()) })
+    }
+}
+
+#[cfg(feature = "fetch")]
+impl AccountFetcher for solana_client::nonblocking::rpc_client::RpcClient {
+    fn get_account<'a>(&'a self, pubkey: &'a Pubkey) -> FetchAccountFuture<'a> {
+        Box::pin(async move {
+            self.get_account_with_commitment(pubkey, self.commitment())
+                .await
+                .map(|response| response.value)
+                .map_err(Into::into)
+        })
+    }
+}
+
+#[cfg(feature = "fetch")]
+impl TransactionSender for solana_client::nonblocking::rpc_client::RpcClient {
+    fn get_latest_blockhash(&self) -> SendFuture<'_, Hash> {
+        Box::pin(async move {
+            solana_client::nonblocking::rpc_client::RpcClient::get_latest_blockhash(self)
+                .await
+                .map_err(Into::into)
+        })
+    }
+
+    fn send_transaction<'a>(&'a self, transaction: &'a Transaction) -> SendFuture<'a, Signature> {
+        Box::pin(async move {
+            self.send_and_confirm_transaction(transaction)
+                .await
+                .map_err(Into::into)
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TokenAclClientError {
+    #[error("unable to fetch account: {0}")]
+    Fetch(AccountFetchError),
+    #[error("account {0} not found")]
+    AccountNotFound(Pubkey),
+    #[error("account {0} has invalid data")]
+    InvalidAccountData(Pubkey),
+    #[error("no transaction sender configured")]
+    NoTransactionSender,
+    #[error("unable to send transaction: {0}")]
+    Send(TransactionSendError),
+    #[error("unable to sign transaction: {0}")]
+    Signer(#[from] SignerError),
+}
+
+/// Builds and sends Token ACL transactions for a payer, deriving every PDA and looking up
+/// the token program, configs and gate extra accounts through its [`AccountFetcher`].
+///
+/// Management instructions are signed by the authority, which is the payer unless set with
+/// [`TokenAclClient::with_authority`].
+#[derive(Clone)]
+pub struct TokenAclClient {
+    fetcher: Arc<dyn AccountFetcher>,
+    sender: Option<Arc<dyn TransactionSender>>,
+    payer: ClientSigner,
+    authority: Option<ClientSigner>,
+}
+
+impl TokenAclClient {
+    /// Client that only builds instructions and transactions, see
+    /// [`TokenAclClient::with_sender`] to send them.
+    pub fn new(fetcher: Arc<dyn AccountFetcher>, payer: ClientSigner) -> Self {
+        Self {
+            fetcher,
+            sender: None,
+            payer,
+            authority: None,
+        }
+    }
+
+    /// Client fetching accounts from and sending transactions to `rpc`.
+    #[cfg(feature = "fetch")]
+    pub fn new_with_rpc(
+        rpc: Arc<solana_client::nonblocking::rpc_client::RpcClient>,
+        payer: ClientSigner,
+    ) -> Self {
+        Self::new(rpc.clone(), payer).with_sender(rpc)
+    }
+
+    pub fn with_sender(mut self, sender: Arc<dyn TransactionSender>) -> Self {
+        self.sender = Some(sender);
+        self
+    }
+
+    pub fn with_authority(mut self, authority: ClientSigner) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
+    pub fn authority(&self) -> Pubkey {
+        self.authority.as_ref().unwrap_or(&self.payer).pubkey()
+    }
+
+    pub async fn get_account(&self, pubkey: &Pubkey) -> Result<Account, TokenAclClientError> {
+        self.fetch_account(pubkey)
+            .await?
+            .ok_or(TokenAclClientError::AccountNotFound(*pubkey))
+    }
+
+    /// Same as `get_account`, `None` when the account doesn't exist.
+    pub async fn fetch_account(
+        &self,
+        pubkey: &Pubkey,
+    ) -> Result<Option<Account>, TokenAclClientError> {
+        self.fetcher
+            .get_account(pubkey)
+            .await
+            .map_err(TokenAclClientError::Fetch)
+    }
+
+    pub async fn get_mint_config(&self, mint: &Pubkey) -> Result<MintConfig, TokenAclClientError> {
+        let mint_config = MintConfig::find_pda(mint).0;
+        let account = self.get_account(&mint_config).await?;
+        MintConfig::from_account_data(&account.data)
+            .map_err(|_| TokenAclClientError::InvalidAccountData(mint_config))
+    }
+
+    /// Token program owning `mint`.
+    pub async fn get_token_program(&self, mint: &Pubkey) -> Result<Pubkey, TokenAclClientError> {
+        Ok(self.get_account(mint).await?.owner)
+    }
+
+    /// `CreateConfig` for `mint`, the authority must be the mint's freeze authority. When it
+    /// is also the metadata update authority, the `token_acl` metadata key is set as well.
+    pub async fn create_config(
+        &self,
+        mint: &Pubkey,
+        gating_program: Option<Pubkey>,
+    ) -> Result<Vec<Instruction>, TokenAclClientError> {
+        let mint_account = self.get_account(mint).await?;
+        let mut builder = crate::instructions::CreateConfigBuilder::new();
+        builder
+            .payer(self.payer())
+            .authority(self.authority())
+            .mint(*mint)
+            .mint_config(MintConfig::find_pda(mint).0)
+            .gating_program(gating_program.unwrap_or_default())
+            .token_program(mint_account.owner);
+
+        if gating_program.is_some() && self.is_metadata_update_authority(&mint_account) {
+            builder.add_remaining_accounts(&crate::create_config_metadata_account_metas(
+                &self.authority(),
+            ));
+        }
+
+        Ok(vec![builder.instruction()])
+    }
+
+    /// `DeleteConfig` for `mint`, handing the freeze authority over to `new_freeze_authority`
+    /// and the config's rent to the payer. A pending gate change is closed as well.
+    pub async fn delete_config(
+        &self,
+        mint: &Pubkey,
+        new_freeze_authority: &Pubkey,
+    ) -> Result<Vec<Instruction>, TokenAclClientError> {
+        let mut builder = crate::instructions::DeleteConfigBuilder::new();
+        builder
+            .authority(self.authority())
+            .receiver(self.payer())
+            .mint(*mint)
+            .mint_config(MintConfig::find_pda(mint).0)
+            .token_program(self.get_token_program(mint).await?)
+            .pending_gating_program(PendingGatingProgram::find_pda(mint).0)
+            .pending_payer(self.payer())
+            .new_freeze_authority(*new_freeze_authority);
+
+        // the queued change is refunded to whoever paid for it
+        if let Some(pending) = self.get_pending_gating_program(mint).await? {
+            builder.pending_payer(pending.payer);
+        }
+
+        Ok(vec![builder.instruction()])
+    }
+
+    /// Gate change queued for `mint`, `None` when nothing is queued.
+    pub async fn get_pending_gating_program(
+        &self,
+        mint: &Pubkey,
+    ) -> Result<Option<PendingGatingProgram>, TokenAclClientError> {
+        let pending = PendingGatingProgram::find_pda(mint).0;
+        self.fetch_account(&pending)
+            .await?
+            .map(|account| {
+                PendingGatingProgram::from_bytes(&account.data)
+                    .map_err(|_| TokenAclClientError::InvalidAccountData(pending))
+            })
+            .transpose()
+    }
+
+    /// `SetGatingProgram` for `mint`, also updating the `token_acl` metadata key when the
+    /// authority is the metadata update authority.
+    pub async fn set_gating_program(
+        &self,
+        mint: &Pubkey,
+        gating_program: &Pubkey,
+    ) -> Result<Vec<Instruction>, TokenAclClientError> {
+        let mint_account = self.get_account(mint).await?;
+        let mut builder = crate::instructions::SetGatingProgramBuilder::new();
+        builder
+            .authority(self.authority())
+            .mint_config(MintConfig::find_pda(mint).0)
+            .new_gating_program(*gating_program);
+
+        if self.is_metadata_update_authority(&mint_account) {
+            builder.add_remaining_accounts(&crate::set_gating_program_metadata_account_metas(
+                mint,
+                &self.authority(),
+            ));
+        }
+
+        Ok(vec![builder.instruction()])
+    }
+
+    pub async fn toggle_permissionless_instructions(
+        &self,
+        mint: &Pubkey,
+        thaw_enabled: bool,
+        freeze_enabled: bool,
+    ) -> Result<Vec<Instruction>, TokenAclClientError> {
+        let ix = crate::instructions::TogglePermissionlessInstructionsBuilder::new()
+            .authority(self.authority())
+            .mint_config(MintConfig::find_pda(mint).0)
+            .thaw_enabled(thaw_enabled)
+            .freeze_enabled(freeze_enabled)
+            .instruction();
+
+        Ok(vec![ix])
+    }
+
+    /// `Freeze` of `token_account`, which the program rejects when the config has sticky
+    /// freeze, see [`TokenAclClient::freeze_with_reason`].
+    pub async fn freeze(
+        &self,
+        token_account: &Pubkey,
+    ) -> Result<Vec<Instruction>, TokenAclClientError> {
+        let (mint, token_program) = self.get_token_account_mint(token_account).await?;
+        let ix = crate::instructions::FreezeBuilder::new()
+            .authority(self.authority())
+            .mint(mint)
+            .token_account(*token_account)
+            .mint_config(MintConfig::find_pda(&mint).0)
+            .token_program(token_program)
+            .instruction();
+
+        Ok(vec![ix])
+    }
+
+    /// `FreezeWithReason` of `token_account`, recording `reason_code` and `memo_hash` in its
+    /// freeze reason, paid for by the payer.
+    pub async fn freeze_with_reason(
+        &self,
+        token_account: &Pubkey,
+        reason_code: u32,
+        memo_hash: [u8; 32],
+    ) -> Result<Vec<Instruction>, TokenAclClientError> {
+        let (mint, token_program) = self.get_token_account_mint(token_account).await?;
+        let ix = crate::instructions::FreezeWithReasonBuilder::new()
+            .authority(self.authority())
+            .payer(self.payer())
+            .mint(mint)
+            .token_account(*token_account)
+            .mint_config(MintConfig::find_pda(&mint).0)
+            .freeze_reason(FreezeReason::find_pda(token_account).0)
+            .token_program(token_program)
+            .reason_code(reason_code)
+            .memo_hash(memo_hash)
+            .instruction();
+
+        Ok(vec![ix])
+    }
+
+    /// `Thaw` of `token_account`, closing its freeze reason if it has one.
+    pub async fn thaw(
+        &self,
+        token_account: &Pubkey,
+    ) -> Result<Vec<Instruction>, TokenAclClientError> {
+        let (mint, token_program) = self.get_token_account_mint(token_account).await?;
+        let mint_config = self.get_mint_config(&mint).await?;
+        let freeze_reason = self.get_freeze_reason(token_account).await?;
+        let ix = crate::create_thaw_instruction(
+            &self.authority(),
+            &mint_config,
+            token_account,
+            &token_program,
+            freeze_reason.as_ref().map(|reason| &reason.payer),
+        );
+
+        Ok(vec![ix])
+    }
+
+    /// Freeze reason recorded for `token_account`, `None` when it has none.
+    pub async fn get_freeze_reason(
+        &self,
+        token_account: &Pubkey,
+    ) -> Result<Option<FreezeReason>, TokenAclClientError> {
+        let freeze_reason = FreezeReason::find_pda(token_account).0;
+        self.fetch_account(&freeze_reason)
+            .await?
+            .map(|account| {
+                FreezeReason::from_bytes(&account.data)
+                    .map_err(|_| TokenAclClientError::InvalidAccountData(freeze_reason))
+            })
+            .transpose()
+    }
+
+    /// Permissionless thaw of `owner`'s associated token account, signed by the payer.
+    pub async fn thaw_permissionless(
+        &self,
+        mint: &Pubkey,
+        owner: &Pubkey,
+        idempotent: bool,
+    ) -> Result<Vec<Instruction>, TokenAclClientError> {
+        let token_program = self.get_token_program(mint).await?;
+        let token_account =
+            get_associated_token_address_with_program_id(owner, mint, &token_program);
+
+        let ix = crate::create_thaw_permissionless_instruction_with_extra_metas(
+            &self.payer(),
+            &token_account,
+            mint,
+            &MintConfig::find_pda(mint).0,
+            &token_program,
+            owner,
+            idempotent,
+            self.fetch_account_data_fn(),
+        )
+        .await
+        .map_err(TokenAclClientError::Fetch)?;
+
+        Ok(vec![ix])
+    }
+
+    /// Permissionless freeze of `owner`'s associated token account, signed by the payer.
+    pub async fn freeze_permissionless(
+        &self,
+        mint: &Pubkey,
+        owner: &Pubkey,
+        idempotent: bool,
+    ) -> Result<Vec<Instruction>, TokenAclClientError> {
+        let token_program = self.get_token_program(mint).await?;
+        let token_account =
+            get_associated_token_address_with_program_id(owner, mint, &token_program);
+
+        let ix = crate::create_freeze_permissionless_instruction_with_extra_metas(
+            &self.payer(),
+            &token_account,
+            mint,
+            &MintConfig::find_pda(mint).0,
+            &token_program,
+            owner,
+            idempotent,
+            self.fetch_account_data_fn(),
+        )
+        .await
+        .map_err(TokenAclClientError::Fetch)?;
+
+        Ok(vec![ix])
+    }
+
+    /// Creates `owner`'s associated token account and thaws it permissionlessly when the
+    /// mint is gated.
+    pub async fn create_ata_and_thaw_permissionless(
+        &self,
+        mint: &Pubkey,
+        owner: &Pubkey,
+        idempotent: bool,
+    ) -> Result<Vec<Instruction>, TokenAclClientError> {
+        let token_program = self.get_token_program(mint).await?;
+
+        crate::create_ata_and_thaw_permissionless_instructions(
+            &self.payer(),
+            mint,
+            &token_program,
+            owner,
+            idempotent,
+            &self.fetch_account_data_fn(),
+        )
+        .await
+        .map_err(TokenAclClientError::Fetch)
+    }
+
+    /// Transaction paid by the payer and signed by the authority when it signs any of
+    /// `instructions`.
+    pub fn transaction_with_blockhash(
+        &self,
+        instructions: &[Instruction],
+        recent_blockhash: Hash,
+    ) -> Result<Transaction, TokenAclClientError> {
+        let payer = self.payer();
+        let mut transaction = Transaction::new_with_payer(instructions, Some(&payer));
+
+        let authority = self.authority.as_ref().filter(|authority| {
+            authority.pubkey() != payer
+                && transaction.message.account_keys
+                    [..transaction.message.header.num_required_signatures as usize]
+                    .contains(&authority.pubkey())
+        });
+        let signers: Vec<&dyn Signer> = std::iter::once(&self.payer)
+            .chain(authority)
+            .map(|signer| signer.as_ref() as &dyn Signer)
+            .collect();
+        transaction.try_sign(&signers, recent_blockhash)?;
+
+        Ok(transaction)
+    }
+
+    /// Signed transaction with the sender's latest blockhash.
+    pub async fn transaction(
+        &self,
+        instructions: &[Instruction],
+    ) -> Result<Transaction, TokenAclClientError> {
+        let sender = self.sender()?;
+        let recent_blockhash = sender
+            .get_latest_blockhash()
+            .await
+            .map_err(TokenAclClientError::Send)?;
+
+        self.transaction_with_blockhash(instructions, recent_blockhash)
+    }
+
+    /// Signs and sends `instructions` in a single transaction and waits for confirmation.
+    pub async fn send(
+        &self,
+        instructions: &[Instruction],
+    ) -> Result<Signature, TokenAclClientError> {
+        let transaction = self.transaction(instructions).await?;
+
+        self.sender()?
+            .send_transaction(&transaction)
+            .await
+            .map_err(TokenAclClientError::Send)
+    }
+
+    fn is_metadata_update_authority(&self, mint_account: &Account) -> bool {
+        mint_account.owner == spl_token_2022_interface::ID
+            && crate::get_metadata_update_authority_from_mint_data(&mint_account.data)
+                == Some(self.authority())
+    }
+
+    fn sender(&self) -> Result<&Arc<dyn TransactionSender>, TokenAclClientError> {
+        self.sender
+            .as_ref()
+            .ok_or(TokenAclClientError::NoTransactionSender)
+    }
+
+    async fn get_token_account_mint(
+        &self,
+        token_account: &Pubkey,
+    ) -> Result<(Pubkey, Pubkey), TokenAclClientError> {
+        let account = self.get_account(token_account).await?;
+        let data = account
+            .data
+            .get(..spl_token_2022_interface::state::Account::LEN)
+            .ok_or(TokenAclClientError::InvalidAccountData(*token_account))?;
+        let token_account_state = spl_token_2022_interface::state::Account::unpack(data)
+            .map_err(|_| TokenAclClientError::InvalidAccountData(*token_account))?;
+
+        Ok((token_account_state.mint, account.owner))
+    }
+
+    fn fetch_account_data_fn(
+        &self,
+    ) -> impl Fn(Pubkey) -> Pin<Box<dyn Future<Output = crate::AccountDataResult> + Send>> {
+        let fetcher = self.fetcher.clone();
+        move |pubkey: Pubkey| {
+            let fetcher = fetcher.clone();
+            Box::pin(async move {
+                fetcher
+                    .get_account(&pubkey)
+                    .await
+                    .map(|account| account.map(|account| account.data))
+            })
+        }
+    }
+}
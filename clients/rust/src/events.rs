@@ -0,0 +1,289 @@
+use base64::{prelude::BASE64_STANDARD, Engine};
+use borsh::BorshDeserialize;
+use solana_pubkey::Pubkey;
+
+const PROGRAM_DATA_LOG_PREFIX: &str = "Program data: ";
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ConfigCreated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub gating_program: Pubkey,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ConfigDeleted {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub new_freeze_authority: Pubkey,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct AuthorityChanged {
+    pub mint: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PendingAuthorityChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct FreezeOperatorChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub new_freeze_operator: Pubkey,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ThawOperatorChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub new_thaw_operator: Pubkey,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct GatingProgramChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub old_gating_program: Pubkey,
+    pub new_gating_program: Pubkey,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PermissionlessInstructionsToggled {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub freeze_enabled: bool,
+    pub thaw_enabled: bool,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TokenAccountFrozen {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub caller: Pubkey,
+    pub permissionless: bool,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TokenAccountThawed {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub caller: Pubkey,
+    pub permissionless: bool,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ConfigMigrated {
+    pub mint: Pubkey,
+    pub old_version: u8,
+    pub new_version: u8,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct GatingChainChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub additional_gating_programs: [Pubkey; 3],
+    pub thaw_gate_mode: u8,
+    pub freeze_gate_mode: u8,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct FreezeGatingProgramChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub old_freeze_gating_program: Pubkey,
+    pub new_freeze_gating_program: Pubkey,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PausedChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub paused: bool,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PauseGuardianChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub new_pause_guardian: Pubkey,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct GatingProgramQueued {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub new_gating_program: Pubkey,
+    pub new_gating_program_delay: i64,
+    pub activation_timestamp: i64,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct QueuedGatingProgramCancelled {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub new_gating_program: Pubkey,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct GatingProgramDelayChanged {
+    pub mint: Pubkey,
+    pub old_gating_program_delay: i64,
+    pub new_gating_program_delay: i64,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct FreezeReasonRecorded {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub authority: Pubkey,
+    pub reason_code: u32,
+    pub memo_hash: [u8; 32],
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct StickyFreezeChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub sticky_freeze: bool,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PermissionlessFeeChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub permissionless_fee: u64,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct FeesWithdrawn {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub receiver: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ThawTtlChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub thaw_ttl: i64,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ThawExpiryRecorded {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub expires_at: i64,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct FreezeReasonClosed {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub receiver: Pubkey,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ThawExpiryClosed {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub receiver: Pubkey,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct GatingChainQueued {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub new_additional_gating_programs: [Pubkey; 3],
+    pub new_thaw_gate_mode: u8,
+    pub new_freeze_gate_mode: u8,
+    pub new_freeze_gating_program: Pubkey,
+    pub activation_timestamp: i64,
+}
+
+/// Events logged by the Token ACL program through `sol_log_data`.
+///
+/// Each event is a single `Program data:` entry holding a one byte discriminator
+/// followed by the event fields.
+#[derive(BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[borsh(use_discriminant = false)]
+pub enum TokenAclEvent {
+    ConfigCreated(ConfigCreated),
+    ConfigDeleted(ConfigDeleted),
+    AuthorityChanged(AuthorityChanged),
+    PendingAuthorityChanged(PendingAuthorityChanged),
+    FreezeOperatorChanged(FreezeOperatorChanged),
+    ThawOperatorChanged(ThawOperatorChanged),
+    GatingProgramChanged(GatingProgramChanged),
+    PermissionlessInstructionsToggled(PermissionlessInstructionsToggled),
+    TokenAccountFrozen(TokenAccountFrozen),
+    TokenAccountThawed(TokenAccountThawed),
+    ConfigMigrated(ConfigMigrated),
+    GatingChainChanged(GatingChainChanged),
+    FreezeGatingProgramChanged(FreezeGatingProgramChanged),
+    PausedChanged(PausedChanged),
+    PauseGuardianChanged(PauseGuardianChanged),
+    GatingProgramQueued(GatingProgramQueued),
+    QueuedGatingProgramCancelled(QueuedGatingProgramCancelled),
+    GatingProgramDelayChanged(GatingProgramDelayChanged),
+    FreezeReasonRecorded(FreezeReasonRecorded),
+    StickyFreezeChanged(StickyFreezeChanged),
+    PermissionlessFeeChanged(PermissionlessFeeChanged),
+    FeesWithdrawn(FeesWithdrawn),
+    ThawTtlChanged(ThawTtlChanged),
+    ThawExpiryRecorded(ThawExpiryRecorded),
+    FreezeReasonClosed(FreezeReasonClosed),
+    ThawExpiryClosed(ThawExpiryClosed),
+    GatingChainQueued(GatingChainQueued),
+}
+
+impl TokenAclEvent {
+    /// Decodes an event from the raw bytes of a `Program data:` log entry.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        Self::try_from_slice(data)
+    }
+}
+
+/// Decodes the events emitted by the Token ACL program from a transaction's log messages.
+///
+/// `Program data:` entries are only decoded while the Token ACL program is the innermost
+/// program being invoked, so data logged by gating programs or other CPIs is ignored.
+pub fn parse_token_acl_events<S: AsRef<str>>(
+    logs: &[S],
+) -> Result<Vec<TokenAclEvent>, std::io::Error> {
+    let program_id = crate::TOKEN_ACL_ID.to_string();
+    let mut invoke_stack: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+
+    for log in logs {
+        let log = log.as_ref();
+        if let Some(data) = log.strip_prefix(PROGRAM_DATA_LOG_PREFIX) {
+            if invoke_stack.last() != Some(&program_id.as_str()) {
+                continue;
+            }
+            let data = BASE64_STANDARD
+                .decode(data.trim())
+                .map_err(std::io::Error::other)?;
+            events.push(TokenAclEvent::from_bytes(&data)?);
+        } else if let Some(rest) = log.strip_prefix("Program ") {
+            let mut parts = rest.split_whitespace();
+            match (parts.next(), parts.next()) {
+                (Some(program), Some("invoke")) => invoke_stack.push(program),
+                (Some(_), Some("success")) | (Some(_), Some("failed:")) => {
+                    invoke_stack.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(events)
+}
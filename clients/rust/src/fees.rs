@@ -0,0 +1,73 @@
+use std::future::Future;
+
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+use spl_tlv_account_resolution::state::{AccountDataResult, AccountFetchError};
+
+use crate::accounts::MintConfig;
+
+pub const FEE_VAULT_SEED: &[u8] = b"FEE_VAULT";
+
+/// Finds the PDA holding the permissionless fees paid for a mint.
+pub fn find_fee_vault_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_VAULT_SEED, mint.as_ref()], &crate::TOKEN_ACL_ID)
+}
+
+/// Fee vault expected by the permissionless instructions when the config charges a fee,
+/// `None` otherwise, see [`crate::permissionless_account_metas`].
+pub fn permissionless_fee_account_meta(mint_config: &MintConfig) -> Option<AccountMeta> {
+    (mint_config.permissionless_fee > 0)
+        .then(|| AccountMeta::new(find_fee_vault_pda(&mint_config.mint).0, false))
+}
+
+/// Lamports a permissionless thaw or freeze of the mint's token accounts charges its
+/// `authority`, on top of the transaction fees.
+pub async fn estimate_permissionless_fee<F, Fut>(
+    mint: &Pubkey,
+    fetch_account_data_fn: F,
+) -> Result<u64, AccountFetchError>
+where
+    F: Fn(Pubkey) -> Fut,
+    Fut: Future<Output = AccountDataResult>,
+{
+    let mint_config = fetch_account_data_fn(MintConfig::find_pda(mint).0)
+        .await?
+        .and_then(|data| MintConfig::from_account_data(&data).ok())
+        .ok_or(ProgramError::InvalidAccountData)?;
+
+    Ok(mint_config.permissionless_fee)
+}
+
+/// Builds a `SetPermissionlessFee` instruction. Setting a fee tops the fee vault up to its
+/// rent exempt minimum from `payer`.
+pub fn create_set_permissionless_fee_instruction(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    mint: &Pubkey,
+    permissionless_fee: u64,
+) -> Instruction {
+    crate::instructions::SetPermissionlessFeeBuilder::new()
+        .authority(*authority)
+        .payer(*payer)
+        .mint_config(MintConfig::find_pda(mint).0)
+        .fee_vault(find_fee_vault_pda(mint).0)
+        .permissionless_fee(permissionless_fee)
+        .instruction()
+}
+
+/// Builds a `WithdrawFees` instruction, the fee vault keeps its rent exempt minimum.
+pub fn create_withdraw_fees_instruction(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    receiver: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    crate::instructions::WithdrawFeesBuilder::new()
+        .authority(*authority)
+        .mint_config(MintConfig::find_pda(mint).0)
+        .fee_vault(find_fee_vault_pda(mint).0)
+        .receiver(*receiver)
+        .amount(amount)
+        .instruction()
+}
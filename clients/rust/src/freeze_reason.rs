@@ -0,0 +1,71 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+
+use crate::{
+    accounts::{FreezeReason, MintConfig},
+    thaw_expiry_account_meta,
+};
+
+/// Builds a `Thaw` instruction with the accounts `mint_config` requires: the thaw expiry
+/// when the config has a `thaw_ttl`, which the thaw resets, followed by the freeze reason.
+///
+/// `freeze_reason_payer` is the payer recorded in the token account's freeze reason, which
+/// is closed and its rent returned to them. With sticky freeze the freeze reason has to be
+/// passed even if there is none, `authority` is used as the receiver then.
+pub fn create_thaw_instruction(
+    authority: &Pubkey,
+    mint_config: &MintConfig,
+    token_account: &Pubkey,
+    token_program: &Pubkey,
+    freeze_reason_payer: Option<&Pubkey>,
+) -> Instruction {
+    let receiver = match freeze_reason_payer {
+        Some(payer) => Some(payer),
+        None if mint_config.sticky_freeze => Some(authority),
+        None => None,
+    };
+
+    let mut remaining_accounts: Vec<AccountMeta> =
+        thaw_expiry_account_meta(mint_config, token_account)
+            .into_iter()
+            .collect();
+    if let Some(receiver) = receiver {
+        remaining_accounts.extend([
+            AccountMeta::new(FreezeReason::find_pda(token_account).0, false),
+            AccountMeta::new(*receiver, false),
+        ]);
+    }
+
+    crate::instructions::ThawBuilder::new()
+        .authority(*authority)
+        .mint(mint_config.mint)
+        .token_account(*token_account)
+        .mint_config(MintConfig::find_pda(&mint_config.mint).0)
+        .token_program(*token_program)
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction()
+}
+
+/// Builds a `CloseFreezeReason` instruction for a token account that is no longer frozen,
+/// `payer` is the payer recorded in the freeze reason and receives its rent back.
+pub fn create_close_freeze_reason_instruction(
+    token_account: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    crate::instructions::CloseFreezeReasonBuilder::new()
+        .token_account(*token_account)
+        .freeze_reason(FreezeReason::find_pda(token_account).0)
+        .payer(*payer)
+        .instruction()
+}
+
+/// Freeze reason account expected by `ThawPermissionless` when the config has
+/// `sticky_freeze` enabled, `None` otherwise, see [`crate::permissionless_account_metas`].
+pub fn sticky_freeze_account_meta(
+    mint_config: &MintConfig,
+    token_account: &Pubkey,
+) -> Option<AccountMeta> {
+    mint_config
+        .sticky_freeze
+        .then(|| AccountMeta::new_readonly(FreezeReason::find_pda(token_account).0, false))
+}
@@ -0,0 +1,107 @@
+use solana_instruction::Instruction;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+
+use crate::accounts::MintConfig;
+
+/// Maximum number of gating programs chained after the config's `gating_program`.
+pub const MAX_ADDITIONAL_GATING_PROGRAMS: usize = 3;
+
+/// How the chained gating programs of a mint config are combined for an operation.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum GateMode {
+    /// Every gating program is invoked and has to approve the operation.
+    #[default]
+    All = 0,
+    /// Only the gating program passed by the caller is invoked, it can be any of
+    /// the configured ones. Callers pick the most permissive gate, so the chain
+    /// approves whatever any single gate approves.
+    Any = 1,
+}
+
+impl TryFrom<u8> for GateMode {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::All),
+            1 => Ok(Self::Any),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+impl MintConfig {
+    /// Configured gating programs in invocation order, starting with `gating_program`.
+    pub fn gating_programs(&self) -> Vec<Pubkey> {
+        std::iter::once(self.gating_program)
+            .chain(self.chained_gating_programs())
+            .collect()
+    }
+
+    /// Gating program invoked first by a permissionless freeze: `freeze_gating_program`
+    /// if set and `gating_program` otherwise.
+    pub fn effective_freeze_gating_program(&self) -> Pubkey {
+        if self.freeze_gating_program != Pubkey::default() {
+            self.freeze_gating_program
+        } else {
+            self.gating_program
+        }
+    }
+
+    /// Gating programs a permissionless thaw has to resolve extra accounts for.
+    ///
+    /// In [`GateMode::Any`] only the first gating program is used.
+    pub fn thaw_gating_programs(&self) -> Vec<Pubkey> {
+        gating_programs_for_mode(self.gating_programs(), self.thaw_gate_mode)
+    }
+
+    /// Gating programs a permissionless freeze has to resolve extra accounts for.
+    ///
+    /// In [`GateMode::Any`] only the first gating program is used.
+    pub fn freeze_gating_programs(&self) -> Vec<Pubkey> {
+        let gating_programs = std::iter::once(self.effective_freeze_gating_program())
+            .chain(self.chained_gating_programs())
+            .collect();
+        gating_programs_for_mode(gating_programs, self.freeze_gate_mode)
+    }
+
+    fn chained_gating_programs(&self) -> impl Iterator<Item = Pubkey> + '_ {
+        self.additional_gating_programs
+            .iter()
+            .copied()
+            .take_while(|program| *program != Pubkey::default())
+    }
+}
+
+fn gating_programs_for_mode(mut gating_programs: Vec<Pubkey>, gate_mode: u8) -> Vec<Pubkey> {
+    if GateMode::try_from(gate_mode) == Ok(GateMode::Any) {
+        gating_programs.truncate(1);
+    }
+    gating_programs
+}
+
+/// Builds a `SetGatingChain` instruction chaining `additional_gating_programs` after the
+/// config's `gating_program`.
+pub fn create_set_gating_chain_instruction(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    additional_gating_programs: &[Pubkey],
+    thaw_gate_mode: GateMode,
+    freeze_gate_mode: GateMode,
+) -> Result<Instruction, ProgramError> {
+    if additional_gating_programs.len() > MAX_ADDITIONAL_GATING_PROGRAMS {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let mut programs = [Pubkey::default(); MAX_ADDITIONAL_GATING_PROGRAMS];
+    programs[..additional_gating_programs.len()].copy_from_slice(additional_gating_programs);
+
+    Ok(crate::instructions::SetGatingChainBuilder::new()
+        .authority(*authority)
+        .mint_config(MintConfig::find_pda(mint).0)
+        .additional_gating_programs(programs)
+        .thaw_gate_mode(thaw_gate_mode as u8)
+        .freeze_gate_mode(freeze_gate_mode as u8)
+        .instruction())
+}
@@ -12,7 +12,7 @@ use solana_pubkey::Pubkey;
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FlagAccount {
-    pub is_thawing: bool,
+    pub operation: u8,
 }
 
 impl FlagAccount {
@@ -31,6 +31,46 @@ pub struct MintConfig {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub gating_program: Pubkey,
+    pub version: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pending_authority: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub freeze_operator: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub thaw_operator: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::DisplayFromStr; 3]>")
+    )]
+    pub additional_gating_programs: [Pubkey; 3],
+    pub thaw_gate_mode: u8,
+    pub freeze_gate_mode: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub freeze_gating_program: Pubkey,
+    pub paused: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pause_guardian: Pubkey,
+    pub gating_program_delay: i64,
+    pub sticky_freeze: bool,
+    pub permissionless_fee: u64,
+    pub thaw_ttl: i64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    pub reserved: [u8; 68],
 }
 
 pub const MINT_CONFIG_DISCRIMINATOR: u8 = 1;
@@ -152,7 +192,7 @@ pub fn fetch_all_maybe_mint_config(
 #[cfg(feature = "anchor")]
 impl anchor_lang::AccountDeserialize for MintConfig {
     fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
-        Ok(Self::deserialize(buf)?)
+        Ok(Self::from_bytes(buf)?)
     }
 }
 
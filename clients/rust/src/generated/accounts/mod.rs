@@ -6,7 +6,13 @@
 //!
 
 pub(crate) mod r#flag_account;
+pub(crate) mod r#freeze_reason;
 pub(crate) mod r#mint_config;
+pub(crate) mod r#pending_gating_program;
+pub(crate) mod r#thaw_expiry;
 
 pub use self::r#flag_account::*;
+pub use self::r#freeze_reason::*;
 pub use self::r#mint_config::*;
+pub use self::r#pending_gating_program::*;
+pub use self::r#thaw_expiry::*;
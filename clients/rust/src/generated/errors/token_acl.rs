@@ -37,6 +37,60 @@ pub enum TokenAclError {
     /// 8 - An invalid token account owner was provided
     #[error("An invalid token account owner was provided")]
     InvalidTokenAccountOwner = 0x8,
+    /// 9 - An invalid flag account was provided
+    #[error("An invalid flag account was provided")]
+    InvalidFlagAccount = 0x9,
+    /// 10 - No pending authority transfer exists
+    #[error("No pending authority transfer exists")]
+    NoPendingAuthority = 0xa,
+    /// 11 - The mint is not allowed by this program instance
+    #[error("The mint is not allowed by this program instance")]
+    MintNotAllowed = 0xb,
+    /// 12 - Mint config must be migrated to the current version
+    #[error("Mint config must be migrated to the current version")]
+    MintConfigMigrationRequired = 0xc,
+    /// 13 - Config is paused
+    #[error("Config is paused")]
+    ConfigPaused = 0xd,
+    /// 14 - Gating program changes are timelocked and must be queued
+    #[error("Gating program changes are timelocked and must be queued")]
+    GatingProgramTimelocked = 0xe,
+    /// 15 - Queued gating program change is not active yet
+    #[error("Queued gating program change is not active yet")]
+    GatingProgramChangeNotActive = 0xf,
+    /// 16 - Invalid pending gating program account
+    #[error("Invalid pending gating program account")]
+    InvalidPendingGatingProgram = 0x10,
+    /// 17 - An invalid associated token program was provided
+    #[error("An invalid associated token program was provided")]
+    InvalidAssociatedTokenProgram = 0x11,
+    /// 18 - An invalid freeze reason was provided
+    #[error("An invalid freeze reason was provided")]
+    InvalidFreezeReason = 0x12,
+    /// 19 - Token account is still frozen
+    #[error("Token account is still frozen")]
+    TokenAccountStillFrozen = 0x13,
+    /// 20 - A freeze reason is required while sticky freeze is enabled
+    #[error("A freeze reason is required while sticky freeze is enabled")]
+    FreezeReasonRequired = 0x14,
+    /// 21 - Token account was frozen by the freeze operator
+    #[error("Token account was frozen by the freeze operator")]
+    TokenAccountStickyFrozen = 0x15,
+    /// 22 - An invalid fee vault was provided
+    #[error("An invalid fee vault was provided")]
+    InvalidFeeVault = 0x16,
+    /// 23 - Fee vault balance is too low
+    #[error("Fee vault balance is too low")]
+    InsufficientFeeVaultBalance = 0x17,
+    /// 24 - An invalid thaw expiry was provided
+    #[error("An invalid thaw expiry was provided")]
+    InvalidThawExpiry = 0x18,
+    /// 25 - Thaw expiry is still active
+    #[error("Thaw expiry is still active")]
+    ThawExpiryStillActive = 0x19,
+    /// 26 - Token metadata is kept in another account
+    #[error("Token metadata is kept in another account")]
+    ExternalTokenMetadata = 0x1a,
 }
 
 impl From<TokenAclError> for solana_program_error::ProgramError {
@@ -23,6 +23,10 @@ pub struct DeleteConfig {
     pub mint_config: solana_pubkey::Pubkey,
 
     pub token_program: solana_pubkey::Pubkey,
+
+    pub pending_gating_program: solana_pubkey::Pubkey,
+
+    pub pending_payer: solana_pubkey::Pubkey,
 }
 
 impl DeleteConfig {
@@ -39,7 +43,7 @@ impl DeleteConfig {
         args: DeleteConfigInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.authority,
             true,
@@ -54,6 +58,14 @@ impl DeleteConfig {
             self.token_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.pending_gating_program,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.pending_payer,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = DeleteConfigInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -110,6 +122,8 @@ impl DeleteConfigInstructionArgs {
 ///   2. `[writable]` mint
 ///   3. `[writable]` mint_config
 ///   4. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   5. `[writable]` pending_gating_program
+///   6. `[writable]` pending_payer
 #[derive(Clone, Debug, Default)]
 pub struct DeleteConfigBuilder {
     authority: Option<solana_pubkey::Pubkey>,
@@ -117,6 +131,8 @@ pub struct DeleteConfigBuilder {
     mint: Option<solana_pubkey::Pubkey>,
     mint_config: Option<solana_pubkey::Pubkey>,
     token_program: Option<solana_pubkey::Pubkey>,
+    pending_gating_program: Option<solana_pubkey::Pubkey>,
+    pending_payer: Option<solana_pubkey::Pubkey>,
     new_freeze_authority: Option<Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -152,6 +168,19 @@ impl DeleteConfigBuilder {
         self
     }
     #[inline(always)]
+    pub fn pending_gating_program(
+        &mut self,
+        pending_gating_program: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.pending_gating_program = Some(pending_gating_program);
+        self
+    }
+    #[inline(always)]
+    pub fn pending_payer(&mut self, pending_payer: solana_pubkey::Pubkey) -> &mut Self {
+        self.pending_payer = Some(pending_payer);
+        self
+    }
+    #[inline(always)]
     pub fn new_freeze_authority(&mut self, new_freeze_authority: Pubkey) -> &mut Self {
         self.new_freeze_authority = Some(new_freeze_authority);
         self
@@ -181,6 +210,10 @@ impl DeleteConfigBuilder {
             token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!(
                 "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
             )),
+            pending_gating_program: self
+                .pending_gating_program
+                .expect("pending_gating_program is not set"),
+            pending_payer: self.pending_payer.expect("pending_payer is not set"),
         };
         let args = DeleteConfigInstructionArgs {
             new_freeze_authority: self
@@ -204,6 +237,10 @@ pub struct DeleteConfigCpiAccounts<'a, 'b> {
     pub mint_config: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub pending_gating_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub pending_payer: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `delete_config` CPI instruction.
@@ -220,6 +257,10 @@ pub struct DeleteConfigCpi<'a, 'b> {
     pub mint_config: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub pending_gating_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub pending_payer: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: DeleteConfigInstructionArgs,
 }
@@ -237,6 +278,8 @@ impl<'a, 'b> DeleteConfigCpi<'a, 'b> {
             mint: accounts.mint,
             mint_config: accounts.mint_config,
             token_program: accounts.token_program,
+            pending_gating_program: accounts.pending_gating_program,
+            pending_payer: accounts.pending_payer,
             __args: args,
         }
     }
@@ -263,7 +306,7 @@ impl<'a, 'b> DeleteConfigCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.authority.key,
             true,
@@ -281,6 +324,14 @@ impl<'a, 'b> DeleteConfigCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.pending_gating_program.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.pending_payer.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -297,13 +348,15 @@ impl<'a, 'b> DeleteConfigCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.receiver.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.mint_config.clone());
         account_infos.push(self.token_program.clone());
+        account_infos.push(self.pending_gating_program.clone());
+        account_infos.push(self.pending_payer.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -325,6 +378,8 @@ impl<'a, 'b> DeleteConfigCpi<'a, 'b> {
 ///   2. `[writable]` mint
 ///   3. `[writable]` mint_config
 ///   4. `[]` token_program
+///   5. `[writable]` pending_gating_program
+///   6. `[writable]` pending_payer
 #[derive(Clone, Debug)]
 pub struct DeleteConfigCpiBuilder<'a, 'b> {
     instruction: Box<DeleteConfigCpiBuilderInstruction<'a, 'b>>,
@@ -339,6 +394,8 @@ impl<'a, 'b> DeleteConfigCpiBuilder<'a, 'b> {
             mint: None,
             mint_config: None,
             token_program: None,
+            pending_gating_program: None,
+            pending_payer: None,
             new_freeze_authority: None,
             __remaining_accounts: Vec::new(),
         });
@@ -376,6 +433,22 @@ impl<'a, 'b> DeleteConfigCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn pending_gating_program(
+        &mut self,
+        pending_gating_program: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pending_gating_program = Some(pending_gating_program);
+        self
+    }
+    #[inline(always)]
+    pub fn pending_payer(
+        &mut self,
+        pending_payer: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pending_payer = Some(pending_payer);
+        self
+    }
+    #[inline(always)]
     pub fn new_freeze_authority(&mut self, new_freeze_authority: Pubkey) -> &mut Self {
         self.instruction.new_freeze_authority = Some(new_freeze_authority);
         self
@@ -439,6 +512,16 @@ impl<'a, 'b> DeleteConfigCpiBuilder<'a, 'b> {
                 .instruction
                 .token_program
                 .expect("token_program is not set"),
+
+            pending_gating_program: self
+                .instruction
+                .pending_gating_program
+                .expect("pending_gating_program is not set"),
+
+            pending_payer: self
+                .instruction
+                .pending_payer
+                .expect("pending_payer is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -456,6 +539,8 @@ struct DeleteConfigCpiBuilderInstruction<'a, 'b> {
     mint: Option<&'b solana_account_info::AccountInfo<'a>>,
     mint_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    pending_gating_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    pending_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     new_freeze_authority: Option<Pubkey>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
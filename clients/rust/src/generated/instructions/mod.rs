@@ -5,26 +5,76 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#accept_authority;
+pub(crate) mod r#cancel_authority_transfer;
+pub(crate) mod r#cancel_gating_program;
+pub(crate) mod r#close_freeze_reason;
+pub(crate) mod r#close_thaw_expiry;
+pub(crate) mod r#create_ata_and_thaw_permissionless;
 pub(crate) mod r#create_config;
 pub(crate) mod r#delete_config;
+pub(crate) mod r#finalize_gating_program;
 pub(crate) mod r#freeze;
+pub(crate) mod r#freeze_batch;
+pub(crate) mod r#freeze_batch_with_reason;
 pub(crate) mod r#freeze_permissionless;
 pub(crate) mod r#freeze_permissionless_idempotent;
+pub(crate) mod r#freeze_with_reason;
+pub(crate) mod r#migrate_config;
+pub(crate) mod r#propose_authority;
+pub(crate) mod r#queue_gating_chain;
+pub(crate) mod r#queue_gating_program;
 pub(crate) mod r#set_authority;
+pub(crate) mod r#set_freeze_gating_program;
+pub(crate) mod r#set_freeze_operator;
+pub(crate) mod r#set_gating_chain;
 pub(crate) mod r#set_gating_program;
+pub(crate) mod r#set_pause_guardian;
+pub(crate) mod r#set_paused;
+pub(crate) mod r#set_permissionless_fee;
+pub(crate) mod r#set_sticky_freeze;
+pub(crate) mod r#set_thaw_operator;
+pub(crate) mod r#set_thaw_ttl;
 pub(crate) mod r#thaw;
+pub(crate) mod r#thaw_batch;
 pub(crate) mod r#thaw_permissionless;
 pub(crate) mod r#thaw_permissionless_idempotent;
 pub(crate) mod r#toggle_permissionless_instructions;
+pub(crate) mod r#withdraw_fees;
 
+pub use self::r#accept_authority::*;
+pub use self::r#cancel_authority_transfer::*;
+pub use self::r#cancel_gating_program::*;
+pub use self::r#close_freeze_reason::*;
+pub use self::r#close_thaw_expiry::*;
+pub use self::r#create_ata_and_thaw_permissionless::*;
 pub use self::r#create_config::*;
 pub use self::r#delete_config::*;
+pub use self::r#finalize_gating_program::*;
 pub use self::r#freeze::*;
+pub use self::r#freeze_batch::*;
+pub use self::r#freeze_batch_with_reason::*;
 pub use self::r#freeze_permissionless::*;
 pub use self::r#freeze_permissionless_idempotent::*;
+pub use self::r#freeze_with_reason::*;
+pub use self::r#migrate_config::*;
+pub use self::r#propose_authority::*;
+pub use self::r#queue_gating_chain::*;
+pub use self::r#queue_gating_program::*;
 pub use self::r#set_authority::*;
+pub use self::r#set_freeze_gating_program::*;
+pub use self::r#set_freeze_operator::*;
+pub use self::r#set_gating_chain::*;
 pub use self::r#set_gating_program::*;
+pub use self::r#set_pause_guardian::*;
+pub use self::r#set_paused::*;
+pub use self::r#set_permissionless_fee::*;
+pub use self::r#set_sticky_freeze::*;
+pub use self::r#set_thaw_operator::*;
+pub use self::r#set_thaw_ttl::*;
 pub use self::r#thaw::*;
+pub use self::r#thaw_batch::*;
 pub use self::r#thaw_permissionless::*;
 pub use self::r#thaw_permissionless_idempotent::*;
 pub use self::r#toggle_permissionless_instructions::*;
+pub use self::r#withdraw_fees::*;
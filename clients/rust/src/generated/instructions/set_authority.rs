@@ -7,7 +7,6 @@
 
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
-use solana_pubkey::Pubkey;
 
 pub const SET_AUTHORITY_DISCRIMINATOR: u8 = 1;
 
@@ -17,23 +16,21 @@ pub struct SetAuthority {
     pub authority: solana_pubkey::Pubkey,
 
     pub mint_config: solana_pubkey::Pubkey,
+
+    pub new_authority: solana_pubkey::Pubkey,
 }
 
 impl SetAuthority {
-    pub fn instruction(
-        &self,
-        args: SetAuthorityInstructionArgs,
-    ) -> solana_instruction::Instruction {
-        self.instruction_with_remaining_accounts(args, &[])
+    pub fn instruction(&self) -> solana_instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
     }
     #[allow(clippy::arithmetic_side_effects)]
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
-        args: SetAuthorityInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.authority,
             true,
@@ -42,10 +39,12 @@ impl SetAuthority {
             self.mint_config,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.new_authority,
+            true,
+        ));
         accounts.extend_from_slice(remaining_accounts);
-        let mut data = SetAuthorityInstructionData::new().try_to_vec().unwrap();
-        let mut args = args.try_to_vec().unwrap();
-        data.append(&mut args);
+        let data = SetAuthorityInstructionData::new().try_to_vec().unwrap();
 
         solana_instruction::Instruction {
             program_id: crate::TOKEN_ACL_ID,
@@ -77,29 +76,18 @@ impl Default for SetAuthorityInstructionData {
     }
 }
 
-#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct SetAuthorityInstructionArgs {
-    pub new_authority: Pubkey,
-}
-
-impl SetAuthorityInstructionArgs {
-    pub(crate) fn try_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
-        borsh::to_vec(self)
-    }
-}
-
 /// Instruction builder for `SetAuthority`.
 ///
 /// ### Accounts:
 ///
 ///   0. `[signer]` authority
 ///   1. `[writable]` mint_config
+///   2. `[signer]` new_authority
 #[derive(Clone, Debug, Default)]
 pub struct SetAuthorityBuilder {
     authority: Option<solana_pubkey::Pubkey>,
     mint_config: Option<solana_pubkey::Pubkey>,
-    new_authority: Option<Pubkey>,
+    new_authority: Option<solana_pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -118,7 +106,7 @@ impl SetAuthorityBuilder {
         self
     }
     #[inline(always)]
-    pub fn new_authority(&mut self, new_authority: Pubkey) -> &mut Self {
+    pub fn new_authority(&mut self, new_authority: solana_pubkey::Pubkey) -> &mut Self {
         self.new_authority = Some(new_authority);
         self
     }
@@ -142,15 +130,10 @@ impl SetAuthorityBuilder {
         let accounts = SetAuthority {
             authority: self.authority.expect("authority is not set"),
             mint_config: self.mint_config.expect("mint_config is not set"),
-        };
-        let args = SetAuthorityInstructionArgs {
-            new_authority: self
-                .new_authority
-                .clone()
-                .expect("new_authority is not set"),
+            new_authority: self.new_authority.expect("new_authority is not set"),
         };
 
-        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
     }
 }
 
@@ -159,6 +142,8 @@ pub struct SetAuthorityCpiAccounts<'a, 'b> {
     pub authority: &'b solana_account_info::AccountInfo<'a>,
 
     pub mint_config: &'b solana_account_info::AccountInfo<'a>,
+
+    pub new_authority: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `set_authority` CPI instruction.
@@ -169,21 +154,20 @@ pub struct SetAuthorityCpi<'a, 'b> {
     pub authority: &'b solana_account_info::AccountInfo<'a>,
 
     pub mint_config: &'b solana_account_info::AccountInfo<'a>,
-    /// The arguments for the instruction.
-    pub __args: SetAuthorityInstructionArgs,
+
+    pub new_authority: &'b solana_account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> SetAuthorityCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_account_info::AccountInfo<'a>,
         accounts: SetAuthorityCpiAccounts<'a, 'b>,
-        args: SetAuthorityInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
             authority: accounts.authority,
             mint_config: accounts.mint_config,
-            __args: args,
+            new_authority: accounts.new_authority,
         }
     }
     #[inline(always)]
@@ -209,7 +193,7 @@ impl<'a, 'b> SetAuthorityCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.authority.key,
             true,
@@ -218,6 +202,10 @@ impl<'a, 'b> SetAuthorityCpi<'a, 'b> {
             *self.mint_config.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.new_authority.key,
+            true,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -225,19 +213,18 @@ impl<'a, 'b> SetAuthorityCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let mut data = SetAuthorityInstructionData::new().try_to_vec().unwrap();
-        let mut args = self.__args.try_to_vec().unwrap();
-        data.append(&mut args);
+        let data = SetAuthorityInstructionData::new().try_to_vec().unwrap();
 
         let instruction = solana_instruction::Instruction {
             program_id: crate::TOKEN_ACL_ID,
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.mint_config.clone());
+        account_infos.push(self.new_authority.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -256,6 +243,7 @@ impl<'a, 'b> SetAuthorityCpi<'a, 'b> {
 ///
 ///   0. `[signer]` authority
 ///   1. `[writable]` mint_config
+///   2. `[signer]` new_authority
 #[derive(Clone, Debug)]
 pub struct SetAuthorityCpiBuilder<'a, 'b> {
     instruction: Box<SetAuthorityCpiBuilderInstruction<'a, 'b>>,
@@ -286,7 +274,10 @@ impl<'a, 'b> SetAuthorityCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn new_authority(&mut self, new_authority: Pubkey) -> &mut Self {
+    pub fn new_authority(
+        &mut self,
+        new_authority: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
         self.instruction.new_authority = Some(new_authority);
         self
     }
@@ -324,13 +315,6 @@ impl<'a, 'b> SetAuthorityCpiBuilder<'a, 'b> {
     #[allow(clippy::clone_on_copy)]
     #[allow(clippy::vec_init_then_push)]
     pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
-        let args = SetAuthorityInstructionArgs {
-            new_authority: self
-                .instruction
-                .new_authority
-                .clone()
-                .expect("new_authority is not set"),
-        };
         let instruction = SetAuthorityCpi {
             __program: self.instruction.__program,
 
@@ -340,7 +324,11 @@ impl<'a, 'b> SetAuthorityCpiBuilder<'a, 'b> {
                 .instruction
                 .mint_config
                 .expect("mint_config is not set"),
-            __args: args,
+
+            new_authority: self
+                .instruction
+                .new_authority
+                .expect("new_authority is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -354,7 +342,7 @@ struct SetAuthorityCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_account_info::AccountInfo<'a>,
     authority: Option<&'b solana_account_info::AccountInfo<'a>>,
     mint_config: Option<&'b solana_account_info::AccountInfo<'a>>,
-    new_authority: Option<Pubkey>,
+    new_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -1,13 +1,36 @@
+mod batch;
+mod client;
+mod events;
+mod fees;
+mod freeze_reason;
+mod gates;
+// kept as rendered by codama
+#[allow(clippy::io_other_error)]
 mod generated;
+mod list;
 mod metadata;
+mod mint_config_ext;
+mod status;
+mod thaw_expiry;
+mod timelock;
 use std::future::Future;
 
+pub use batch::*;
+pub use client::*;
+pub use events::*;
+pub use fees::*;
+pub use freeze_reason::*;
+pub use gates::*;
 pub use generated::*;
+pub use list::*;
 pub use metadata::*;
+pub use status::*;
+pub use thaw_expiry::*;
+pub use timelock::*;
 
 #[cfg(feature = "fetch")]
 use solana_client::nonblocking;
-use solana_instruction::Instruction;
+use solana_instruction::{AccountMeta, Instruction};
 use solana_program_error::ProgramError;
 use solana_program_option::COption;
 use solana_program_pack::Pack;
@@ -19,9 +42,31 @@ use spl_associated_token_account_interface::instruction::{
 pub use spl_tlv_account_resolution::state::{AccountDataResult, AccountFetchError};
 use spl_token_2022_interface::state::{Account, AccountState};
 use spl_token_2022_interface::ID as SPL_TOKEN_2022_ID;
+pub use token_acl_interface::offchain::SimulationOutcome;
+use token_acl_interface::{error::ThawFreezeGateError, PermissionlessOperation};
 
 use crate::generated::errors::token_acl::TokenAclError;
 
+/// Token ACL accounts the permissionless instructions expect after the gate accounts, in this
+/// order and each only when the config requires it: the fee vault, the token account's thaw
+/// expiry and, for thaws with sticky freeze, its freeze reason.
+pub fn permissionless_account_metas(
+    mint_config: &crate::accounts::MintConfig,
+    token_account: &Pubkey,
+    operation: PermissionlessOperation,
+) -> Vec<AccountMeta> {
+    let freeze_reason = match operation {
+        PermissionlessOperation::Thaw => sticky_freeze_account_meta(mint_config, token_account),
+        PermissionlessOperation::Freeze => None,
+    };
+
+    permissionless_fee_account_meta(mint_config)
+        .into_iter()
+        .chain(thaw_expiry_account_meta(mint_config, token_account))
+        .chain(freeze_reason)
+        .collect()
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn create_thaw_permissionless_instruction_with_extra_metas<F, Fut>(
     signer_pubkey: &Pubkey,
@@ -33,13 +78,125 @@ pub async fn create_thaw_permissionless_instruction_with_extra_metas<F, Fut>(
     idempotent: bool,
     fetch_account_data_fn: F,
 ) -> Result<Instruction, AccountFetchError>
+where
+    F: Fn(Pubkey) -> Fut,
+    Fut: Future<Output = AccountDataResult>,
+{
+    let (mut ix, mint_config, flag_account) = thaw_permissionless_instruction(
+        signer_pubkey,
+        token_account_pubkey,
+        mint_pubkey,
+        mint_config_pubkey,
+        token_program_pubkey,
+        token_account_owner_pubkey,
+        idempotent,
+        &fetch_account_data_fn,
+    )
+    .await?;
+
+    if mint_config.gating_program != Pubkey::default() {
+        token_acl_interface::offchain::add_extra_account_metas_for_thaw_gates(
+            &mut ix,
+            &mint_config.thaw_gating_programs(),
+            signer_pubkey,
+            token_account_pubkey,
+            mint_pubkey,
+            token_account_owner_pubkey,
+            &flag_account,
+            fetch_account_data_fn,
+        )
+        .await?;
+    }
+
+    ix.accounts.extend(permissionless_account_metas(
+        &mint_config,
+        token_account_pubkey,
+        PermissionlessOperation::Thaw,
+    ));
+
+    Ok(ix)
+}
+
+/// Same as `create_thaw_permissionless_instruction_with_extra_metas`, but the gate accounts
+/// that can't be resolved off-chain are found by simulating the instruction with
+/// `simulate_fn`, see `resolve_missing_gate_accounts_with_simulation` in the interface.
+#[allow(clippy::too_many_arguments)]
+pub async fn create_thaw_permissionless_instruction_with_simulation<F, Fut, S, SFut>(
+    signer_pubkey: &Pubkey,
+    token_account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    mint_config_pubkey: &Pubkey,
+    token_program_pubkey: &Pubkey,
+    token_account_owner_pubkey: &Pubkey,
+    idempotent: bool,
+    fetch_account_data_fn: F,
+    simulate_fn: S,
+) -> Result<Instruction, AccountFetchError>
+where
+    F: Fn(Pubkey) -> Fut,
+    Fut: Future<Output = AccountDataResult>,
+    S: Fn(Instruction) -> SFut,
+    SFut: Future<Output = SimulationOutcome>,
+{
+    let (mut ix, mint_config, flag_account) = thaw_permissionless_instruction(
+        signer_pubkey,
+        token_account_pubkey,
+        mint_pubkey,
+        mint_config_pubkey,
+        token_program_pubkey,
+        token_account_owner_pubkey,
+        idempotent,
+        &fetch_account_data_fn,
+    )
+    .await?;
+
+    let program_accounts = permissionless_account_metas(
+        &mint_config,
+        token_account_pubkey,
+        PermissionlessOperation::Thaw,
+    );
+
+    if mint_config.gating_program != Pubkey::default() {
+        token_acl_interface::offchain::add_extra_account_metas_for_thaw_gates_with_simulation(
+            &mut ix,
+            &mint_config.thaw_gating_programs(),
+            signer_pubkey,
+            token_account_pubkey,
+            mint_pubkey,
+            token_account_owner_pubkey,
+            &flag_account,
+            &program_accounts,
+            fetch_account_data_fn,
+            simulate_fn,
+        )
+        .await?;
+    } else {
+        ix.accounts.extend(program_accounts);
+    }
+
+    Ok(ix)
+}
+
+/// `ThawPermissionless` instruction without the gate and program accounts, along with the
+/// config it was built from and the flag account.
+#[allow(clippy::too_many_arguments)]
+async fn thaw_permissionless_instruction<F, Fut>(
+    signer_pubkey: &Pubkey,
+    token_account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    mint_config_pubkey: &Pubkey,
+    token_program_pubkey: &Pubkey,
+    token_account_owner_pubkey: &Pubkey,
+    idempotent: bool,
+    fetch_account_data_fn: &F,
+) -> Result<(Instruction, crate::accounts::MintConfig, Pubkey), AccountFetchError>
 where
     F: Fn(Pubkey) -> Fut,
     Fut: Future<Output = AccountDataResult>,
 {
     let mint_config = fetch_account_data_fn(*mint_config_pubkey)
         .await?
-        .and_then(|data| crate::accounts::MintConfig::from_bytes(&data).ok())
+        .and_then(|data| crate::accounts::MintConfig::from_account_data(&data).ok())
         .ok_or(ProgramError::InvalidAccountData)?;
 
     let flag_account = crate::accounts::FlagAccount::find_pda(token_account_pubkey).0;
@@ -48,7 +205,11 @@ where
         return Err(TokenAclError::PermissionlessThawNotEnabled.into());
     }
 
-    let mut ix = if idempotent {
+    if mint_config.paused {
+        return Err(TokenAclError::ConfigPaused.into());
+    }
+
+    let ix = if idempotent {
         crate::instructions::ThawPermissionlessIdempotentBuilder::new()
             .gating_program(mint_config.gating_program)
             .authority(*signer_pubkey)
@@ -74,10 +235,41 @@ where
             .instruction()
     };
 
-    if mint_config.gating_program != Pubkey::default() {
-        token_acl_interface::offchain::add_extra_account_metas_for_thaw(
+    Ok((ix, mint_config, flag_account))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn create_freeze_permissionless_instruction_with_extra_metas<F, Fut>(
+    signer_pubkey: &Pubkey,
+    token_account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    mint_config_pubkey: &Pubkey,
+    token_program_pubkey: &Pubkey,
+    token_account_owner_pubkey: &Pubkey,
+    idempotent: bool,
+    fetch_account_data_fn: F,
+) -> Result<Instruction, AccountFetchError>
+where
+    F: Fn(Pubkey) -> Fut,
+    Fut: Future<Output = AccountDataResult>,
+{
+    let (mut ix, mint_config, flag_account) = freeze_permissionless_instruction(
+        signer_pubkey,
+        token_account_pubkey,
+        mint_pubkey,
+        mint_config_pubkey,
+        token_program_pubkey,
+        token_account_owner_pubkey,
+        idempotent,
+        &fetch_account_data_fn,
+    )
+    .await?;
+
+    let freeze_gating_program = mint_config.effective_freeze_gating_program();
+    if mint_config.enable_permissionless_freeze && freeze_gating_program != Pubkey::default() {
+        token_acl_interface::offchain::add_extra_account_metas_for_freeze_gates(
             &mut ix,
-            &mint_config.gating_program,
+            &mint_config.freeze_gating_programs(),
             signer_pubkey,
             token_account_pubkey,
             mint_pubkey,
@@ -88,11 +280,19 @@ where
         .await?;
     }
 
+    ix.accounts.extend(permissionless_account_metas(
+        &mint_config,
+        token_account_pubkey,
+        PermissionlessOperation::Freeze,
+    ));
+
     Ok(ix)
 }
 
+/// Same as `create_freeze_permissionless_instruction_with_extra_metas`, with the simulation
+/// fallback of `create_thaw_permissionless_instruction_with_simulation`.
 #[allow(clippy::too_many_arguments)]
-pub async fn create_freeze_permissionless_instruction_with_extra_metas<F, Fut>(
+pub async fn create_freeze_permissionless_instruction_with_simulation<F, Fut, S, SFut>(
     signer_pubkey: &Pubkey,
     token_account_pubkey: &Pubkey,
     mint_pubkey: &Pubkey,
@@ -101,25 +301,91 @@ pub async fn create_freeze_permissionless_instruction_with_extra_metas<F, Fut>(
     token_account_owner_pubkey: &Pubkey,
     idempotent: bool,
     fetch_account_data_fn: F,
+    simulate_fn: S,
 ) -> Result<Instruction, AccountFetchError>
+where
+    F: Fn(Pubkey) -> Fut,
+    Fut: Future<Output = AccountDataResult>,
+    S: Fn(Instruction) -> SFut,
+    SFut: Future<Output = SimulationOutcome>,
+{
+    let (mut ix, mint_config, flag_account) = freeze_permissionless_instruction(
+        signer_pubkey,
+        token_account_pubkey,
+        mint_pubkey,
+        mint_config_pubkey,
+        token_program_pubkey,
+        token_account_owner_pubkey,
+        idempotent,
+        &fetch_account_data_fn,
+    )
+    .await?;
+
+    let program_accounts = permissionless_account_metas(
+        &mint_config,
+        token_account_pubkey,
+        PermissionlessOperation::Freeze,
+    );
+
+    let freeze_gating_program = mint_config.effective_freeze_gating_program();
+    if mint_config.enable_permissionless_freeze && freeze_gating_program != Pubkey::default() {
+        token_acl_interface::offchain::add_extra_account_metas_for_freeze_gates_with_simulation(
+            &mut ix,
+            &mint_config.freeze_gating_programs(),
+            signer_pubkey,
+            token_account_pubkey,
+            mint_pubkey,
+            token_account_owner_pubkey,
+            &flag_account,
+            &program_accounts,
+            fetch_account_data_fn,
+            simulate_fn,
+        )
+        .await?;
+    } else {
+        ix.accounts.extend(program_accounts);
+    }
+
+    Ok(ix)
+}
+
+/// `FreezePermissionless` instruction without the gate and program accounts, along with the
+/// config it was built from and the flag account.
+#[allow(clippy::too_many_arguments)]
+async fn freeze_permissionless_instruction<F, Fut>(
+    signer_pubkey: &Pubkey,
+    token_account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    mint_config_pubkey: &Pubkey,
+    token_program_pubkey: &Pubkey,
+    token_account_owner_pubkey: &Pubkey,
+    idempotent: bool,
+    fetch_account_data_fn: &F,
+) -> Result<(Instruction, crate::accounts::MintConfig, Pubkey), AccountFetchError>
 where
     F: Fn(Pubkey) -> Fut,
     Fut: Future<Output = AccountDataResult>,
 {
     let mint_config = fetch_account_data_fn(*mint_config_pubkey)
         .await?
-        .and_then(|data| crate::accounts::MintConfig::from_bytes(&data).ok())
+        .and_then(|data| crate::accounts::MintConfig::from_account_data(&data).ok())
         .ok_or(ProgramError::InvalidAccountData)?;
 
-    if !mint_config.enable_permissionless_freeze {
+    // expired thaws can be frozen even with permissionless freeze disabled
+    if !mint_config.enable_permissionless_freeze && mint_config.thaw_ttl == 0 {
         return Err(TokenAclError::PermissionlessFreezeNotEnabled.into());
     }
 
-    let flag_account = crate::accounts::FlagAccount::find_pda(&token_account_pubkey).0;
+    if mint_config.paused {
+        return Err(TokenAclError::ConfigPaused.into());
+    }
+
+    let flag_account = crate::accounts::FlagAccount::find_pda(token_account_pubkey).0;
+    let freeze_gating_program = mint_config.effective_freeze_gating_program();
 
-    let mut ix = if idempotent {
+    let ix = if idempotent {
         crate::instructions::FreezePermissionlessIdempotentBuilder::new()
-            .gating_program(mint_config.gating_program)
+            .gating_program(freeze_gating_program)
             .authority(*signer_pubkey)
             .mint(*mint_pubkey)
             .token_account(*token_account_pubkey)
@@ -131,7 +397,7 @@ where
             .instruction()
     } else {
         crate::instructions::FreezePermissionlessBuilder::new()
-            .gating_program(mint_config.gating_program)
+            .gating_program(freeze_gating_program)
             .authority(*signer_pubkey)
             .mint(*mint_pubkey)
             .token_account(*token_account_pubkey)
@@ -143,21 +409,7 @@ where
             .instruction()
     };
 
-    if mint_config.gating_program != Pubkey::default() {
-        token_acl_interface::offchain::add_extra_account_metas_for_freeze(
-            &mut ix,
-            &mint_config.gating_program,
-            signer_pubkey,
-            token_account_pubkey,
-            mint_pubkey,
-            token_account_owner_pubkey,
-            &flag_account,
-            fetch_account_data_fn,
-        )
-        .await?;
-    }
-
-    Ok(ix)
+    Ok((ix, mint_config, flag_account))
 }
 
 #[cfg(feature = "fetch")]
@@ -175,10 +427,16 @@ pub async fn create_ata_and_thaw_permissionless(
             .map_err(Into::<AccountFetchError>::into)
     };
 
+    let token_program_pubkey = rpc
+        .get_account(mint_pubkey)
+        .await
+        .map_err(Into::<AccountFetchError>::into)?
+        .owner;
+
     create_ata_and_thaw_permissionless_instructions(
         payer_pubkey,
         mint_pubkey,
-        &SPL_TOKEN_2022_ID,
+        &token_program_pubkey,
         token_account_owner_pubkey,
         idempotent,
         &fetch_account_data_fn,
@@ -200,52 +458,45 @@ where
     Fut: Future<Output = AccountDataResult>,
 {
     let token_account = get_associated_token_address_with_program_id(
-        &token_account_owner_pubkey,
-        &mint_pubkey,
-        &SPL_TOKEN_2022_ID,
+        token_account_owner_pubkey,
+        mint_pubkey,
+        token_program_pubkey,
     );
 
     let ix = if idempotent {
         create_associated_token_account_idempotent(
-            &payer_pubkey,
-            &token_account_owner_pubkey,
-            &mint_pubkey,
-            &SPL_TOKEN_2022_ID,
+            payer_pubkey,
+            token_account_owner_pubkey,
+            mint_pubkey,
+            token_program_pubkey,
         )
     } else {
         create_associated_token_account(
-            &payer_pubkey,
-            &token_account_owner_pubkey,
-            &mint_pubkey,
-            &SPL_TOKEN_2022_ID,
+            payer_pubkey,
+            token_account_owner_pubkey,
+            mint_pubkey,
+            token_program_pubkey,
         )
     };
     let mut instructions = vec![ix];
 
-    // assume account doesn't exist, so we mock it
-    let acc = Account {
-        mint: *mint_pubkey,
-        owner: *token_account_owner_pubkey,
-        amount: 0,
-        delegate: COption::None,
-        state: AccountState::Frozen,
-        is_native: COption::None,
-        delegated_amount: 0,
-        close_authority: COption::None,
-    };
-
-    let mut data = vec![0u8; Account::LEN];
-    Account::pack(acc, &mut data)?;
+    // without DefaultAccountState, SPL Token accounts are created initialized and there is
+    // nothing to thaw
+    if *token_program_pubkey != SPL_TOKEN_2022_ID {
+        return Ok(instructions);
+    }
 
     let mint_data = fetch_account_data_fn(*mint_pubkey)
         .await?
         .ok_or(Into::<ProgramError>::into(TokenAclError::InvalidTokenMint))?;
 
-    let mint_config_pubkey = crate::accounts::MintConfig::find_pda(mint_pubkey).0;
-    let gating_program = get_gating_program_from_mint_data(&mint_data);
-    let flag_account = crate::accounts::FlagAccount::find_pda(&token_account).0;
+    if let Ok(gating_programs) =
+        get_thaw_gating_programs(mint_pubkey, &mint_data, fetch_account_data_fn).await
+    {
+        let gating_program = gating_programs[0];
+        let mint_config_pubkey = crate::accounts::MintConfig::find_pda(mint_pubkey).0;
+        let flag_account = crate::accounts::FlagAccount::find_pda(&token_account).0;
 
-    if let Ok(gating_program) = gating_program {
         let mut ix = if idempotent {
             crate::instructions::ThawPermissionlessIdempotentBuilder::new()
                 .gating_program(gating_program)
@@ -272,24 +523,15 @@ where
                 .instruction()
         };
 
-        token_acl_interface::offchain::add_extra_account_metas_for_thaw(
+        add_extra_account_metas_for_new_token_account(
             &mut ix,
-            &gating_program,
+            &gating_programs,
             payer_pubkey,
             &token_account,
             mint_pubkey,
             token_account_owner_pubkey,
             &flag_account,
-            |pubkey| {
-                let data = data.clone();
-                async move {
-                    if pubkey == token_account {
-                        return Ok(Some(data));
-                    }
-                    let data = fetch_account_data_fn(pubkey).await.unwrap_or(None);
-                    Ok(data)
-                }
-            },
+            fetch_account_data_fn,
         )
         .await?;
 
@@ -298,3 +540,150 @@ where
 
     Ok(instructions)
 }
+
+/// Same as `create_ata_and_thaw_permissionless_instructions`, as a single
+/// `CreateAtaAndThawPermissionless` instruction that creates the associated token account
+/// idempotently and thaws it if it's frozen.
+pub async fn create_ata_and_thaw_permissionless_instruction_with_extra_metas<F, Fut>(
+    payer_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    token_program_pubkey: &Pubkey,
+    token_account_owner_pubkey: &Pubkey,
+    fetch_account_data_fn: &F,
+) -> Result<Instruction, AccountFetchError>
+where
+    F: Fn(Pubkey) -> Fut,
+    Fut: Future<Output = AccountDataResult>,
+{
+    let token_account = get_associated_token_address_with_program_id(
+        token_account_owner_pubkey,
+        mint_pubkey,
+        token_program_pubkey,
+    );
+
+    let mint_data = fetch_account_data_fn(*mint_pubkey)
+        .await?
+        .ok_or(Into::<ProgramError>::into(TokenAclError::InvalidTokenMint))?;
+
+    let gating_programs = get_thaw_gating_programs(mint_pubkey, &mint_data, fetch_account_data_fn)
+        .await
+        .map_err(|_| Into::<ProgramError>::into(TokenAclError::InvalidGatingProgram))?;
+    let flag_account = crate::accounts::FlagAccount::find_pda(&token_account).0;
+
+    let mut ix = crate::instructions::CreateAtaAndThawPermissionlessBuilder::new()
+        .gating_program(gating_programs[0])
+        .authority(*payer_pubkey)
+        .mint(*mint_pubkey)
+        .token_account(token_account)
+        .token_account_owner(*token_account_owner_pubkey)
+        .mint_config(crate::accounts::MintConfig::find_pda(mint_pubkey).0)
+        .token_program(*token_program_pubkey)
+        .flag_account(flag_account)
+        .instruction();
+
+    add_extra_account_metas_for_new_token_account(
+        &mut ix,
+        &gating_programs,
+        payer_pubkey,
+        &token_account,
+        mint_pubkey,
+        token_account_owner_pubkey,
+        &flag_account,
+        fetch_account_data_fn,
+    )
+    .await?;
+
+    Ok(ix)
+}
+
+async fn get_thaw_gating_programs<F, Fut>(
+    mint_pubkey: &Pubkey,
+    mint_data: &[u8],
+    fetch_account_data_fn: &F,
+) -> Result<Vec<Pubkey>, ThawFreezeGateError>
+where
+    F: Fn(Pubkey) -> Fut,
+    Fut: Future<Output = AccountDataResult>,
+{
+    let mint_config_pubkey = crate::accounts::MintConfig::find_pda(mint_pubkey).0;
+    // the metadata only names a single gating program, so prefer the chain from the config
+    match fetch_account_data_fn(mint_config_pubkey)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|data| crate::accounts::MintConfig::from_account_data(&data).ok())
+    {
+        Some(mint_config) if mint_config.gating_program != Pubkey::default() => {
+            Ok(mint_config.thaw_gating_programs())
+        }
+        _ => get_gating_program_from_mint_data(mint_data).map(|program| vec![program]),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn add_extra_account_metas_for_new_token_account<F, Fut>(
+    ix: &mut Instruction,
+    gating_programs: &[Pubkey],
+    payer_pubkey: &Pubkey,
+    token_account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    token_account_owner_pubkey: &Pubkey,
+    flag_account_pubkey: &Pubkey,
+    fetch_account_data_fn: &F,
+) -> Result<(), AccountFetchError>
+where
+    F: Fn(Pubkey) -> Fut,
+    Fut: Future<Output = AccountDataResult>,
+{
+    // assume account doesn't exist, so we mock it
+    let acc = Account {
+        mint: *mint_pubkey,
+        owner: *token_account_owner_pubkey,
+        amount: 0,
+        delegate: COption::None,
+        state: AccountState::Frozen,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    };
+
+    let mut data = vec![0u8; Account::LEN];
+    Account::pack(acc, &mut data)?;
+
+    token_acl_interface::offchain::add_extra_account_metas_for_thaw_gates(
+        ix,
+        gating_programs,
+        payer_pubkey,
+        token_account_pubkey,
+        mint_pubkey,
+        token_account_owner_pubkey,
+        flag_account_pubkey,
+        |pubkey| {
+            let data = data.clone();
+            async move {
+                if pubkey == *token_account_pubkey {
+                    return Ok(Some(data));
+                }
+                let data = fetch_account_data_fn(pubkey).await.unwrap_or(None);
+                Ok(data)
+            }
+        },
+    )
+    .await?;
+
+    let mint_config_pubkey = crate::accounts::MintConfig::find_pda(mint_pubkey).0;
+    if let Some(mint_config) = fetch_account_data_fn(mint_config_pubkey)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|data| crate::accounts::MintConfig::from_account_data(&data).ok())
+    {
+        ix.accounts.extend(permissionless_account_metas(
+            &mint_config,
+            token_account_pubkey,
+            PermissionlessOperation::Thaw,
+        ));
+    }
+
+    Ok(())
+}
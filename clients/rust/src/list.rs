@@ -0,0 +1,198 @@
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "fetch")]
+use crate::accounts::MintConfig;
+use crate::accounts::MINT_CONFIG_DISCRIMINATOR;
+
+/// Offsets of the `MintConfig` fields that can be filtered on, shared by every config
+/// version.
+pub const MINT_CONFIG_ENABLE_PERMISSIONLESS_THAW_OFFSET: usize = 2;
+pub const MINT_CONFIG_ENABLE_PERMISSIONLESS_FREEZE_OFFSET: usize = 3;
+pub const MINT_CONFIG_MINT_OFFSET: usize = 4;
+pub const MINT_CONFIG_AUTHORITY_OFFSET: usize = 36;
+pub const MINT_CONFIG_GATING_PROGRAM_OFFSET: usize = 68;
+/// Offset of `freeze_gating_program`, which version 1 configs don't have.
+pub const MINT_CONFIG_FREEZE_GATING_PROGRAM_OFFSET: usize = 295;
+
+/// Criteria for [`get_mint_configs`], fields left unset match every config.
+///
+/// The filters are ANDed memcmp filters on fixed offsets, so `gating_program` only matches
+/// the primary gate. The `additional_gating_programs` of a chain sit in any of three slots
+/// and have to be checked on the returned configs.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MintConfigFilter {
+    pub authority: Option<Pubkey>,
+    pub gating_program: Option<Pubkey>,
+    /// Gate replacing `gating_program` for permissionless freezes, never matches version 1
+    /// configs.
+    pub freeze_gating_program: Option<Pubkey>,
+    pub enable_permissionless_thaw: Option<bool>,
+    pub enable_permissionless_freeze: Option<bool>,
+}
+
+impl MintConfigFilter {
+    /// `(offset, bytes)` pairs to use as `getProgramAccounts` memcmp filters on the Token
+    /// ACL program, the first one only matches mint configs.
+    pub fn memcmp_filters(&self) -> Vec<(usize, Vec<u8>)> {
+        let mut filters = vec![(0, vec![MINT_CONFIG_DISCRIMINATOR])];
+        if let Some(authority) = self.authority {
+            filters.push((MINT_CONFIG_AUTHORITY_OFFSET, authority.to_bytes().to_vec()));
+        }
+        if let Some(gating_program) = self.gating_program {
+            filters.push((
+                MINT_CONFIG_GATING_PROGRAM_OFFSET,
+                gating_program.to_bytes().to_vec(),
+            ));
+        }
+        if let Some(freeze_gating_program) = self.freeze_gating_program {
+            filters.push((
+                MINT_CONFIG_FREEZE_GATING_PROGRAM_OFFSET,
+                freeze_gating_program.to_bytes().to_vec(),
+            ));
+        }
+        if let Some(enabled) = self.enable_permissionless_thaw {
+            filters.push((
+                MINT_CONFIG_ENABLE_PERMISSIONLESS_THAW_OFFSET,
+                vec![u8::from(enabled)],
+            ));
+        }
+        if let Some(enabled) = self.enable_permissionless_freeze {
+            filters.push((
+                MINT_CONFIG_ENABLE_PERMISSIONLESS_FREEZE_OFFSET,
+                vec![u8::from(enabled)],
+            ));
+        }
+        filters
+    }
+}
+
+/// Page of [`get_mint_config_page`].
+#[cfg(feature = "fetch")]
+#[derive(Clone, Debug)]
+pub struct MintConfigPage {
+    pub mint_configs: Vec<(Pubkey, MintConfig)>,
+    /// Mint to resume after for the next page, `None` on the last page.
+    pub next_cursor: Option<Pubkey>,
+}
+
+/// Mint configs matching `filter` with their addresses, sorted by mint. Fetches every
+/// matching config in one `getProgramAccounts` call, see [`get_mint_config_page`] to fetch
+/// them a page at a time.
+#[cfg(feature = "fetch")]
+pub async fn get_mint_configs(
+    rpc: &solana_client::nonblocking::rpc_client::RpcClient,
+    filter: &MintConfigFilter,
+) -> Result<Vec<(Pubkey, MintConfig)>, crate::AccountFetchError> {
+    use solana_client::{
+        rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig, UiAccountEncoding},
+        rpc_filter::{Memcmp, RpcFilterType},
+    };
+
+    let filters = filter
+        .memcmp_filters()
+        .into_iter()
+        .map(|(offset, bytes)| RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, bytes)))
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let accounts = rpc
+        .get_program_ui_accounts_with_config(&crate::TOKEN_ACL_ID, config)
+        .await?;
+
+    let mut mint_configs: Vec<(Pubkey, MintConfig)> = accounts
+        .into_iter()
+        .filter_map(|(address, account)| {
+            account
+                .data
+                .decode()
+                .and_then(|data| MintConfig::from_account_data(&data).ok())
+                .map(|mint_config| (address, mint_config))
+        })
+        .collect();
+    mint_configs.sort_by_key(|(_, mint_config)| mint_config.mint);
+
+    Ok(mint_configs)
+}
+
+/// Up to `limit` mint configs matching `filter`, sorted by mint, starting after the mint
+/// `after`. Only the mints of the matching configs are fetched to pick the page, through a
+/// `getProgramAccounts` data slice, and then the configs of the page.
+#[cfg(feature = "fetch")]
+pub async fn get_mint_config_page(
+    rpc: &solana_client::nonblocking::rpc_client::RpcClient,
+    filter: &MintConfigFilter,
+    after: Option<&Pubkey>,
+    limit: usize,
+) -> Result<MintConfigPage, crate::AccountFetchError> {
+    use solana_client::{
+        rpc_config::{
+            RpcAccountInfoConfig, RpcProgramAccountsConfig, UiAccountEncoding, UiDataSliceConfig,
+        },
+        rpc_filter::{Memcmp, RpcFilterType},
+        rpc_request::MAX_MULTIPLE_ACCOUNTS,
+    };
+
+    let filters = filter
+        .memcmp_filters()
+        .into_iter()
+        .map(|(offset, bytes)| RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, bytes)))
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            data_slice: Some(UiDataSliceConfig {
+                offset: MINT_CONFIG_MINT_OFFSET,
+                length: 32,
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let accounts = rpc
+        .get_program_ui_accounts_with_config(&crate::TOKEN_ACL_ID, config)
+        .await?;
+
+    let mut mints: Vec<(Pubkey, Pubkey)> = accounts
+        .into_iter()
+        .filter_map(|(address, account)| {
+            let mint = Pubkey::try_from(account.data.decode()?.as_slice()).ok()?;
+            Some((address, mint))
+        })
+        .filter(|(_, mint)| after.is_none_or(|after| mint > after))
+        .collect();
+    mints.sort_by_key(|(_, mint)| *mint);
+
+    let next_cursor = if mints.len() > limit {
+        mints.truncate(limit);
+        mints.last().map(|(_, mint)| *mint).or(after.copied())
+    } else {
+        None
+    };
+
+    let mut mint_configs = Vec::with_capacity(mints.len());
+    for chunk in mints.chunks(MAX_MULTIPLE_ACCOUNTS) {
+        let addresses: Vec<Pubkey> = chunk.iter().map(|(address, _)| *address).collect();
+        let accounts = rpc.get_multiple_accounts(&addresses).await?;
+        // configs deleted since the first call are left out
+        mint_configs.extend(addresses.into_iter().zip(accounts).filter_map(
+            |(address, account)| {
+                let mint_config = MintConfig::from_account_data(&account?.data).ok()?;
+                Some((address, mint_config))
+            },
+        ));
+    }
+
+    Ok(MintConfigPage {
+        mint_configs,
+        next_cursor,
+    })
+}
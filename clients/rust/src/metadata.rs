@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use solana_instruction::Instruction;
+use solana_instruction::{AccountMeta, Instruction};
 use solana_pubkey::Pubkey;
 use spl_token_2022_interface::{
     extension::{BaseStateWithExtensions, PodStateWithExtensions},
@@ -26,6 +26,38 @@ pub fn set_mint_tacl_metadata_ix(
     )
 }
 
+/// Account to append to `CreateConfig` so that it also sets the `token_acl` metadata key of
+/// a Token-2022 mint, the payer covers the additional rent.
+pub fn create_config_metadata_account_metas(
+    metadata_update_authority: &Pubkey,
+) -> Vec<AccountMeta> {
+    vec![AccountMeta::new_readonly(*metadata_update_authority, true)]
+}
+
+/// Accounts to append to `SetGatingProgram` or `FinalizeGatingProgram` so that it also
+/// updates the `token_acl` metadata key of a Token-2022 mint, the update authority covers
+/// the additional rent.
+pub fn set_gating_program_metadata_account_metas(
+    mint: &Pubkey,
+    metadata_update_authority: &Pubkey,
+) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(*mint, false),
+        AccountMeta::new_readonly(SPL_TOKEN_2022_ID, false),
+        AccountMeta::new(*metadata_update_authority, true),
+    ]
+}
+
+/// Update authority of the mint's metadata, `None` for mints without metadata or whose
+/// metadata can't be updated. Metadata kept in another account through the metadata pointer
+/// isn't read, Token ACL doesn't update it.
+pub fn get_metadata_update_authority_from_mint_data(data: &[u8]) -> Option<Pubkey> {
+    let mint = PodStateWithExtensions::<PodMint>::unpack(data).ok()?;
+    let metadata = mint.get_variable_len_extension::<TokenMetadata>().ok()?;
+    Option::<Pubkey>::from(metadata.update_authority)
+}
+
+/// Gating program named by the `token_acl` key of the metadata stored in the mint.
 pub fn get_gating_program_from_mint_data(data: &[u8]) -> Result<Pubkey, ThawFreezeGateError> {
     let mint = PodStateWithExtensions::<PodMint>::unpack(data)
         .map_err(|_| ThawFreezeGateError::InvalidTokenMint)?;
@@ -41,7 +73,7 @@ pub fn get_gating_program_from_mint_data(data: &[u8]) -> Result<Pubkey, ThawFree
         .map(|(_, val)| val)
         .ok_or(ThawFreezeGateError::InvalidTokenMint)?;
 
-    Pubkey::from_str(&gating_program).map_err(|_| ThawFreezeGateError::InvalidTokenMint)
+    Pubkey::from_str(gating_program).map_err(|_| ThawFreezeGateError::InvalidTokenMint)
 }
 
 #[cfg(feature = "fetch")]
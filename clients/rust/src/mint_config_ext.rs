@@ -0,0 +1,54 @@
+use borsh::BorshDeserialize;
+use solana_pubkey::Pubkey;
+
+use crate::accounts::MintConfig;
+
+impl MintConfig {
+    /// Size of configs created before the `version` field was introduced.
+    pub const V1_LEN: usize = 100;
+
+    /// Reads a config account of any version. Unlike `from_bytes`, which only reads the
+    /// current layout, version 1 configs are returned with the fields the program assumes
+    /// for them until `MigrateConfig` is run.
+    pub fn from_account_data(data: &[u8]) -> Result<Self, std::io::Error> {
+        if data.len() == Self::V1_LEN {
+            return Self::from_v1_bytes(data);
+        }
+        Self::from_bytes(data)
+    }
+
+    fn from_v1_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        let discriminator = u8::deserialize(&mut data)?;
+        let bump = u8::deserialize(&mut data)?;
+        let enable_permissionless_thaw = bool::deserialize(&mut data)?;
+        let enable_permissionless_freeze = bool::deserialize(&mut data)?;
+        let mint = Pubkey::deserialize(&mut data)?;
+        let freeze_authority = Pubkey::deserialize(&mut data)?;
+        let gating_program = Pubkey::deserialize(&mut data)?;
+        Ok(Self {
+            discriminator,
+            bump,
+            enable_permissionless_thaw,
+            enable_permissionless_freeze,
+            mint,
+            freeze_authority,
+            gating_program,
+            version: 1,
+            pending_authority: Pubkey::default(),
+            freeze_operator: freeze_authority,
+            thaw_operator: freeze_authority,
+            additional_gating_programs: [Pubkey::default(); 3],
+            thaw_gate_mode: 0,
+            freeze_gate_mode: 0,
+            freeze_gating_program: Pubkey::default(),
+            paused: false,
+            pause_guardian: Pubkey::default(),
+            gating_program_delay: 0,
+            sticky_freeze: false,
+            permissionless_fee: 0,
+            thaw_ttl: 0,
+            reserved: [0; 68],
+        })
+    }
+}
@@ -0,0 +1,167 @@
+use solana_program_option::COption;
+use solana_pubkey::Pubkey;
+use spl_token_2022_interface::{
+    extension::{
+        default_account_state::DefaultAccountState, BaseStateWithExtensions, StateWithExtensions,
+    },
+    state::{AccountState, Mint},
+    ID as SPL_TOKEN_2022_ID,
+};
+use token_acl_interface::{
+    get_freeze_extra_account_metas_address, get_thaw_extra_account_metas_address,
+};
+
+use crate::{accounts::MintConfig, TokenAclClient, TokenAclClientError};
+
+/// Extra account metas PDA of a gating program for a mint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GateExtraMetasStatus {
+    pub gating_program: Pubkey,
+    pub address: Pubkey,
+    pub exists: bool,
+}
+
+/// How a mint is set up for Token ACL, as returned by [`TokenAclClient::inspect_mint`].
+#[derive(Clone, Debug)]
+pub struct TokenAclStatus {
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub freeze_authority: Option<Pubkey>,
+    /// State of new token accounts from the Default Account State extension, `None`
+    /// without the extension.
+    pub default_account_state: Option<AccountState>,
+    pub mint_config_address: Pubkey,
+    /// `None` when there is no valid mint config for the mint.
+    pub mint_config: Option<MintConfig>,
+    /// Gating program named by the `token_acl` metadata key, if any.
+    pub metadata_gating_program: Option<Pubkey>,
+    pub thaw_extra_metas: Vec<GateExtraMetasStatus>,
+    pub freeze_extra_metas: Vec<GateExtraMetasStatus>,
+}
+
+impl TokenAclStatus {
+    /// Whether the mint uses Token ACL: its freeze authority is the mint config PDA and
+    /// the config exists.
+    pub fn is_managed(&self) -> bool {
+        self.freeze_authority == Some(self.mint_config_address) && self.mint_config.is_some()
+    }
+
+    pub fn is_default_frozen(&self) -> bool {
+        self.default_account_state == Some(AccountState::Frozen)
+    }
+
+    /// Whether the `token_acl` metadata key matches the config's gating program. SPL Token
+    /// mints have no metadata and always match.
+    pub fn is_metadata_consistent(&self) -> bool {
+        if self.token_program != SPL_TOKEN_2022_ID {
+            return true;
+        }
+
+        match &self.mint_config {
+            Some(mint_config) if mint_config.gating_program != Pubkey::default() => {
+                self.metadata_gating_program == Some(mint_config.gating_program)
+            }
+            _ => self.metadata_gating_program.is_none(),
+        }
+    }
+
+    /// Whether every gate used by permissionless thaw has its extra account metas PDA.
+    pub fn has_thaw_extra_metas(&self) -> bool {
+        self.thaw_extra_metas.iter().all(|metas| metas.exists)
+    }
+
+    /// Whether every gate used by permissionless freeze has its extra account metas PDA.
+    pub fn has_freeze_extra_metas(&self) -> bool {
+        self.freeze_extra_metas.iter().all(|metas| metas.exists)
+    }
+}
+
+impl TokenAclClient {
+    /// Reads the mint, its config and its gates' extra account metas to tell whether the
+    /// mint is managed by Token ACL and whether its setup is consistent.
+    pub async fn inspect_mint(&self, mint: &Pubkey) -> Result<TokenAclStatus, TokenAclClientError> {
+        let mint_account = self.get_account(mint).await?;
+        let mint_state = StateWithExtensions::<Mint>::unpack(&mint_account.data)
+            .map_err(|_| TokenAclClientError::InvalidAccountData(*mint))?;
+
+        let freeze_authority = match mint_state.base.freeze_authority {
+            COption::Some(freeze_authority) => Some(freeze_authority),
+            COption::None => None,
+        };
+        let default_account_state = mint_state
+            .get_extension::<DefaultAccountState>()
+            .ok()
+            .and_then(|extension| AccountState::try_from(extension.state).ok());
+        let metadata_gating_program =
+            crate::get_gating_program_from_mint_data(&mint_account.data).ok();
+
+        let mint_config_address = MintConfig::find_pda(mint).0;
+        let mint_config = self
+            .fetch_account(&mint_config_address)
+            .await?
+            .filter(|account| account.owner == crate::TOKEN_ACL_ID)
+            .and_then(|account| MintConfig::from_account_data(&account.data).ok())
+            .filter(|mint_config| mint_config.mint == *mint);
+
+        let (thaw_gating_programs, freeze_gating_programs) = match &mint_config {
+            Some(mint_config) => (
+                mint_config.thaw_gating_programs(),
+                mint_config.freeze_gating_programs(),
+            ),
+            None => (vec![], vec![]),
+        };
+
+        let mut thaw_extra_metas = Vec::with_capacity(thaw_gating_programs.len());
+        for gating_program in thaw_gating_programs
+            .into_iter()
+            .filter(|gating_program| *gating_program != Pubkey::default())
+        {
+            let address = get_thaw_extra_account_metas_address(mint, &gating_program);
+            thaw_extra_metas.push(
+                self.gate_extra_metas_status(gating_program, address)
+                    .await?,
+            );
+        }
+
+        let mut freeze_extra_metas = Vec::with_capacity(freeze_gating_programs.len());
+        for gating_program in freeze_gating_programs
+            .into_iter()
+            .filter(|gating_program| *gating_program != Pubkey::default())
+        {
+            let address = get_freeze_extra_account_metas_address(mint, &gating_program);
+            freeze_extra_metas.push(
+                self.gate_extra_metas_status(gating_program, address)
+                    .await?,
+            );
+        }
+
+        Ok(TokenAclStatus {
+            mint: *mint,
+            token_program: mint_account.owner,
+            freeze_authority,
+            default_account_state,
+            mint_config_address,
+            mint_config,
+            metadata_gating_program,
+            thaw_extra_metas,
+            freeze_extra_metas,
+        })
+    }
+
+    async fn gate_extra_metas_status(
+        &self,
+        gating_program: Pubkey,
+        address: Pubkey,
+    ) -> Result<GateExtraMetasStatus, TokenAclClientError> {
+        let exists = self
+            .fetch_account(&address)
+            .await?
+            .is_some_and(|account| account.owner == gating_program);
+
+        Ok(GateExtraMetasStatus {
+            gating_program,
+            address,
+            exists,
+        })
+    }
+}
@@ -0,0 +1,46 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+
+use crate::accounts::{MintConfig, ThawExpiry};
+
+impl ThawExpiry {
+    /// Whether the thaw has expired at `unix_timestamp`, from then on anyone can freeze
+    /// the token account permissionlessly without the gate's approval.
+    pub fn is_expired(&self, unix_timestamp: i64) -> bool {
+        self.expires_at != 0 && unix_timestamp >= self.expires_at
+    }
+}
+
+/// Thaw expiry expected by the permissionless instructions and `Thaw` when the config has a
+/// `thaw_ttl`, `None` otherwise, see [`crate::permissionless_account_metas`].
+pub fn thaw_expiry_account_meta(
+    mint_config: &MintConfig,
+    token_account: &Pubkey,
+) -> Option<AccountMeta> {
+    (mint_config.thaw_ttl > 0)
+        .then(|| AccountMeta::new(ThawExpiry::find_pda(token_account).0, false))
+}
+
+/// Builds a `SetThawTtl` instruction, a `thaw_ttl` of 0 stops permissionless thaws from
+/// expiring.
+pub fn create_set_thaw_ttl_instruction(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    thaw_ttl: i64,
+) -> Instruction {
+    crate::instructions::SetThawTtlBuilder::new()
+        .authority(*authority)
+        .mint_config(MintConfig::find_pda(mint).0)
+        .thaw_ttl(thaw_ttl)
+        .instruction()
+}
+
+/// Builds a `CloseThawExpiry` instruction for a token account that was frozen again or
+/// closed, `payer` is the payer recorded in the thaw expiry and receives its rent back.
+pub fn create_close_thaw_expiry_instruction(token_account: &Pubkey, payer: &Pubkey) -> Instruction {
+    crate::instructions::CloseThawExpiryBuilder::new()
+        .token_account(*token_account)
+        .thaw_expiry(ThawExpiry::find_pda(token_account).0)
+        .payer(*payer)
+        .instruction()
+}
@@ -0,0 +1,89 @@
+use solana_instruction::Instruction;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+
+use crate::{
+    accounts::{MintConfig, PendingGatingProgram},
+    GateMode, MAX_ADDITIONAL_GATING_PROGRAMS,
+};
+
+impl PendingGatingProgram {
+    /// Whether the queued change can be finalized at `unix_timestamp`.
+    pub fn is_active(&self, unix_timestamp: i64) -> bool {
+        unix_timestamp >= self.activation_timestamp
+    }
+}
+
+/// Builds a `QueueGatingProgram` instruction. The change can be finalized once the
+/// config's current `gating_program_delay` has passed.
+pub fn create_queue_gating_program_instruction(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    mint: &Pubkey,
+    new_gating_program: &Pubkey,
+    new_gating_program_delay: i64,
+) -> Instruction {
+    crate::instructions::QueueGatingProgramBuilder::new()
+        .authority(*authority)
+        .payer(*payer)
+        .mint_config(MintConfig::find_pda(mint).0)
+        .pending_gating_program(PendingGatingProgram::find_pda(mint).0)
+        .new_gating_program(*new_gating_program)
+        .new_gating_program_delay(new_gating_program_delay)
+        .instruction()
+}
+
+/// Builds a `QueueGatingChain` instruction, the timelocked counterpart of `SetGatingChain`
+/// and `SetFreezeGatingProgram`. Both are queued together, so pass the current values for
+/// the parts that shouldn't change.
+pub fn create_queue_gating_chain_instruction(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    mint: &Pubkey,
+    additional_gating_programs: &[Pubkey],
+    thaw_gate_mode: GateMode,
+    freeze_gate_mode: GateMode,
+    freeze_gating_program: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    if additional_gating_programs.len() > MAX_ADDITIONAL_GATING_PROGRAMS {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let mut programs = [Pubkey::default(); MAX_ADDITIONAL_GATING_PROGRAMS];
+    programs[..additional_gating_programs.len()].copy_from_slice(additional_gating_programs);
+
+    Ok(crate::instructions::QueueGatingChainBuilder::new()
+        .authority(*authority)
+        .payer(*payer)
+        .mint_config(MintConfig::find_pda(mint).0)
+        .pending_gating_program(PendingGatingProgram::find_pda(mint).0)
+        .new_additional_gating_programs(programs)
+        .new_thaw_gate_mode(thaw_gate_mode as u8)
+        .new_freeze_gate_mode(freeze_gate_mode as u8)
+        .new_freeze_gating_program(*freeze_gating_program)
+        .instruction())
+}
+
+/// Builds a `FinalizeGatingProgram` instruction, `payer` is the payer recorded in the
+/// pending change and receives its rent back.
+pub fn create_finalize_gating_program_instruction(mint: &Pubkey, payer: &Pubkey) -> Instruction {
+    crate::instructions::FinalizeGatingProgramBuilder::new()
+        .mint_config(MintConfig::find_pda(mint).0)
+        .pending_gating_program(PendingGatingProgram::find_pda(mint).0)
+        .payer(*payer)
+        .instruction()
+}
+
+/// Builds a `CancelGatingProgram` instruction, `payer` is the payer recorded in the
+/// pending change and receives its rent back.
+pub fn create_cancel_gating_program_instruction(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    crate::instructions::CancelGatingProgramBuilder::new()
+        .authority(*authority)
+        .mint_config(MintConfig::find_pda(mint).0)
+        .pending_gating_program(PendingGatingProgram::find_pda(mint).0)
+        .payer(*payer)
+        .instruction()
+}
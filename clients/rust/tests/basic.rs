@@ -1,13 +1,23 @@
 pub mod program_test;
 use solana_sdk::{
-    program_option::COption, signature::Keypair, signer::Signer, transaction::Transaction,
+    clock::Clock,
+    instruction::{AccountMeta, InstructionError},
+    program_option::COption,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
 };
 use spl_token_2022_interface::{
     extension::StateWithExtensions,
     state::{Account, AccountState, Mint},
     ID as TOKEN_PROGRAM_ID,
 };
-use token_acl_client::get_gating_program_from_mint_data;
+use token_acl_client::{
+    get_gating_program_from_mint_data, parse_token_acl_events, GateMode, ThawExpiryClosed,
+    TokenAclEvent,
+};
+use token_acl_interface::PermissionlessOperation;
 
 use crate::program_test::TestContext;
 
@@ -31,13 +41,31 @@ fn test_set_authority() {
         .mint_config(mint_cfg_pk)
         .instruction();
 
+    // the new authority has to sign, so that the config isn't handed to a key nobody holds
+    let mut unsigned_ix = ix.clone();
+    unsigned_ix.accounts[2].is_signer = false;
     let tx = Transaction::new_signed_with_payer(
-        &[ix],
+        &[unsigned_ix],
         Some(&tc.token.auth.pubkey()),
         &[tc.token.auth.insecure_clone()],
         tc.vm.latest_blockhash(),
     );
     let res = tc.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(0))
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[
+            tc.token.auth.insecure_clone(),
+            new_authority.insecure_clone(),
+        ],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
     assert!(res.is_ok());
 
     let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
@@ -48,22 +76,17 @@ fn test_set_authority() {
 }
 
 #[test]
-fn test_set_gating_program() {
+fn test_propose_and_accept_authority() {
     let mut tc = TestContext::new();
     let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
 
-    let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
-        tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
-    )
-    .unwrap();
-    assert_eq!(mint_config.gating_program, program_test::AA_ID);
-
-    let new_gating_program = Keypair::new();
-    let new_gating_program_pubkey = new_gating_program.pubkey();
+    let new_authority = Keypair::new();
+    let new_authority_pubkey = new_authority.pubkey();
+    tc.vm.airdrop(&new_authority_pubkey, 1_000_000_000).unwrap();
 
-    let ix = token_acl_client::instructions::SetGatingProgramBuilder::new()
+    let ix = token_acl_client::instructions::ProposeAuthorityBuilder::new()
         .authority(tc.token.auth.pubkey())
-        .new_gating_program(new_gating_program_pubkey)
+        .pending_authority(new_authority_pubkey)
         .mint_config(mint_cfg_pk)
         .instruction();
 
@@ -75,24 +98,17 @@ fn test_set_gating_program() {
     );
     let res = tc.vm.send_transaction(tx);
     assert!(res.is_ok());
-}
-
-#[test]
-fn test_toggle_permissionless_instructions() {
-    let mut tc = TestContext::new();
-    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
 
     let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
         tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
     )
     .unwrap();
-    assert!(!mint_config.enable_permissionless_freeze);
-    assert!(!mint_config.enable_permissionless_thaw);
+    assert_eq!(mint_config.freeze_authority, tc.token.auth.pubkey());
+    assert_eq!(mint_config.pending_authority, new_authority_pubkey);
 
-    let ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
-        .authority(tc.token.auth.pubkey())
-        .freeze_enabled(true)
-        .thaw_enabled(false)
+    // only the pending authority can accept
+    let ix = token_acl_client::instructions::AcceptAuthorityBuilder::new()
+        .pending_authority(tc.token.auth.pubkey())
         .mint_config(mint_cfg_pk)
         .instruction();
 
@@ -103,19 +119,56 @@ fn test_toggle_permissionless_instructions() {
         tc.vm.latest_blockhash(),
     );
     let res = tc.vm.send_transaction(tx);
+    assert!(res.is_err());
+
+    let ix = token_acl_client::instructions::AcceptAuthorityBuilder::new()
+        .pending_authority(new_authority_pubkey)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&new_authority_pubkey),
+        &[new_authority.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
     assert!(res.is_ok());
 
     let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
         tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
     )
     .unwrap();
-    assert!(mint_config.enable_permissionless_freeze);
-    assert!(!mint_config.enable_permissionless_thaw);
+    assert_eq!(mint_config.freeze_authority, new_authority_pubkey);
+    assert_eq!(mint_config.pending_authority, Pubkey::default());
+}
 
-    let ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
+#[test]
+fn test_cancel_authority_transfer() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    let new_authority = Keypair::new();
+    let new_authority_pubkey = new_authority.pubkey();
+    tc.vm.airdrop(&new_authority_pubkey, 1_000_000_000).unwrap();
+
+    let ix = token_acl_client::instructions::ProposeAuthorityBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .pending_authority(new_authority_pubkey)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let ix = token_acl_client::instructions::CancelAuthorityTransferBuilder::new()
         .authority(tc.token.auth.pubkey())
-        .freeze_enabled(false)
-        .thaw_enabled(true)
         .mint_config(mint_cfg_pk)
         .instruction();
 
@@ -132,46 +185,66 @@ fn test_toggle_permissionless_instructions() {
         tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
     )
     .unwrap();
-    assert!(!mint_config.enable_permissionless_freeze);
-    assert!(mint_config.enable_permissionless_thaw);
+    assert_eq!(mint_config.pending_authority, Pubkey::default());
 
-    let ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
-        .authority(tc.token.auth.pubkey())
-        .freeze_enabled(true)
-        .thaw_enabled(true)
+    let ix = token_acl_client::instructions::AcceptAuthorityBuilder::new()
+        .pending_authority(new_authority_pubkey)
         .mint_config(mint_cfg_pk)
         .instruction();
 
     let tx = Transaction::new_signed_with_payer(
         &[ix],
-        Some(&tc.token.auth.pubkey()),
-        &[tc.token.auth.insecure_clone()],
+        Some(&new_authority_pubkey),
+        &[new_authority.insecure_clone()],
         tc.vm.latest_blockhash(),
     );
     let res = tc.vm.send_transaction(tx);
-    assert!(res.is_ok());
+    assert!(res.is_err());
 
     let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
         tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
     )
     .unwrap();
-    assert!(mint_config.enable_permissionless_freeze);
-    assert!(mint_config.enable_permissionless_thaw);
+    assert_eq!(mint_config.freeze_authority, tc.token.auth.pubkey());
 }
 
 #[test]
-fn test_thaw_permissioned() {
+fn test_freeze_operator_role() {
     let mut tc = TestContext::new();
     let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
 
+    let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
+        tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert_eq!(mint_config.freeze_operator, tc.token.auth.pubkey());
+    assert_eq!(mint_config.thaw_operator, tc.token.auth.pubkey());
+
+    let operator = Keypair::new();
+    let operator_pubkey = operator.pubkey();
+    tc.vm.airdrop(&operator_pubkey, 1_000_000_000).unwrap();
+
+    let ix = token_acl_client::instructions::SetFreezeOperatorBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .new_freeze_operator(operator_pubkey)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
     let user_kp = Keypair::new();
     let user_ata = tc.create_token_account(&user_kp);
+    tc.thaw(&user_ata);
 
-    let user_ta = tc.vm.get_account(&user_ata).unwrap();
-    let account = StateWithExtensions::<Account>::unpack(user_ta.data.as_ref()).unwrap();
-    assert_eq!(account.base.state, AccountState::Frozen);
-
-    let ix = token_acl_client::instructions::ThawBuilder::new()
+    // the admin no longer holds the freeze role
+    let ix = token_acl_client::instructions::FreezeBuilder::new()
         .authority(tc.token.auth.pubkey())
         .mint(tc.token.mint)
         .mint_config(mint_cfg_pk)
@@ -186,27 +259,59 @@ fn test_thaw_permissioned() {
         tc.vm.latest_blockhash(),
     );
     let res = tc.vm.send_transaction(tx);
+    assert!(res.is_err());
+
+    let ix = token_acl_client::instructions::FreezeBuilder::new()
+        .authority(operator_pubkey)
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .token_account(user_ata)
+        .token_program(TOKEN_PROGRAM_ID)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&operator_pubkey),
+        &[operator.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
     assert!(res.is_ok());
 
     let user_ta = tc.vm.get_account(&user_ata).unwrap();
     let account = StateWithExtensions::<Account>::unpack(user_ta.data.as_ref()).unwrap();
-    assert_eq!(account.base.state, AccountState::Initialized);
+    assert_eq!(account.base.state, AccountState::Frozen);
+
+    // the freeze operator can't manage the config
+    let ix = token_acl_client::instructions::SetGatingProgramBuilder::new()
+        .authority(operator_pubkey)
+        .new_gating_program(Pubkey::default())
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&operator_pubkey),
+        &[operator.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_err());
 }
 
 #[test]
-fn test_freeze_permissioned() {
+fn test_thaw_operator_role() {
     let mut tc = TestContext::new();
     let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
 
-    let user_kp = Keypair::new();
-    let user_ata = tc.create_token_account(&user_kp);
+    let operator = Keypair::new();
+    let operator_pubkey = operator.pubkey();
+    tc.vm.airdrop(&operator_pubkey, 1_000_000_000).unwrap();
 
-    let ix = token_acl_client::instructions::ThawBuilder::new()
+    let ix = token_acl_client::instructions::SetThawOperatorBuilder::new()
         .authority(tc.token.auth.pubkey())
-        .mint(tc.token.mint)
+        .new_thaw_operator(operator_pubkey)
         .mint_config(mint_cfg_pk)
-        .token_account(user_ata)
-        .token_program(TOKEN_PROGRAM_ID)
         .instruction();
 
     let tx = Transaction::new_signed_with_payer(
@@ -218,12 +323,11 @@ fn test_freeze_permissioned() {
     let res = tc.vm.send_transaction(tx);
     assert!(res.is_ok());
 
-    let user_ta = tc.vm.get_account(&user_ata).unwrap();
-    let account = StateWithExtensions::<Account>::unpack(user_ta.data.as_ref()).unwrap();
-    assert_eq!(account.base.state, AccountState::Initialized);
+    let user_kp = Keypair::new();
+    let user_ata = tc.create_token_account(&user_kp);
 
-    let ix = token_acl_client::instructions::FreezeBuilder::new()
-        .authority(tc.token.auth.pubkey())
+    let ix = token_acl_client::instructions::ThawBuilder::new()
+        .authority(operator_pubkey)
         .mint(tc.token.mint)
         .mint_config(mint_cfg_pk)
         .token_account(user_ata)
@@ -232,8 +336,8 @@ fn test_freeze_permissioned() {
 
     let tx = Transaction::new_signed_with_payer(
         &[ix],
-        Some(&tc.token.auth.pubkey()),
-        &[tc.token.auth.insecure_clone()],
+        Some(&operator_pubkey),
+        &[operator.insecure_clone()],
         tc.vm.latest_blockhash(),
     );
     let res = tc.vm.send_transaction(tx);
@@ -241,27 +345,43 @@ fn test_freeze_permissioned() {
 
     let user_ta = tc.vm.get_account(&user_ata).unwrap();
     let account = StateWithExtensions::<Account>::unpack(user_ta.data.as_ref()).unwrap();
-    assert_eq!(account.base.state, AccountState::Frozen);
+    assert_eq!(account.base.state, AccountState::Initialized);
+
+    // the thaw operator can't change roles
+    let ix = token_acl_client::instructions::SetThawOperatorBuilder::new()
+        .authority(operator_pubkey)
+        .new_thaw_operator(operator_pubkey)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&operator_pubkey),
+        &[operator.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_err());
 }
 
 #[test]
-fn test_delete_config() {
+fn test_set_gating_program() {
     let mut tc = TestContext::new();
     let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
 
-    let mint = tc.vm.get_account(&tc.token.mint).unwrap();
-    let mint = StateWithExtensions::<Mint>::unpack(mint.data.as_ref()).unwrap();
-    assert_eq!(mint.base.freeze_authority, COption::Some(mint_cfg_pk));
+    let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
+        tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert_eq!(mint_config.gating_program, program_test::AA_ID);
 
-    let new_freeze_authority = Keypair::new();
-    let new_freeze_authority_pubkey = new_freeze_authority.pubkey();
+    let new_gating_program = Keypair::new();
+    let new_gating_program_pubkey = new_gating_program.pubkey();
 
-    let ix = token_acl_client::instructions::DeleteConfigBuilder::new()
+    let ix = token_acl_client::instructions::SetGatingProgramBuilder::new()
         .authority(tc.token.auth.pubkey())
-        .receiver(tc.token.auth.pubkey())
-        .mint(tc.token.mint)
+        .new_gating_program(new_gating_program_pubkey)
         .mint_config(mint_cfg_pk)
-        .new_freeze_authority(new_freeze_authority_pubkey)
         .instruction();
 
     let tx = Transaction::new_signed_with_payer(
@@ -271,61 +391,1808 @@ fn test_delete_config() {
         tc.vm.latest_blockhash(),
     );
     let res = tc.vm.send_transaction(tx);
-    println!("res: {:?}", res);
     assert!(res.is_ok());
-
-    let mint = tc.vm.get_account(&tc.token.mint).unwrap();
-    let mint = StateWithExtensions::<Mint>::unpack(mint.data.as_ref()).unwrap();
-    assert_eq!(
-        mint.base.freeze_authority,
-        COption::Some(new_freeze_authority_pubkey)
-    );
 }
 
 #[test]
-fn test_delete_config_after_close() {
+fn test_gating_program_timelock() {
     let mut tc = TestContext::new();
     let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+    let auth = tc.token.auth.pubkey();
 
-    let mint = tc.vm.get_account(&tc.token.mint).unwrap();
-    let mint = StateWithExtensions::<Mint>::unpack(mint.data.as_ref()).unwrap();
-    assert_eq!(mint.base.freeze_authority, COption::Some(mint_cfg_pk));
+    // without a delay the change can be finalized in the same transaction
+    let queue_ix = token_acl_client::create_queue_gating_program_instruction(
+        &auth,
+        &auth,
+        &tc.token.mint,
+        &program_test::AA_ID,
+        100,
+    );
+    let finalize_ix =
+        token_acl_client::create_finalize_gating_program_instruction(&tc.token.mint, &auth);
 
-    let new_freeze_authority = Keypair::new();
-    let new_freeze_authority_pubkey = new_freeze_authority.pubkey();
+    let tx = Transaction::new_signed_with_payer(
+        &[queue_ix, finalize_ix],
+        Some(&auth),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
 
-    tc.close_mint();
+    let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
+        tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert_eq!(mint_config.gating_program_delay, 100);
 
-    let ix = token_acl_client::instructions::DeleteConfigBuilder::new()
-        .authority(tc.token.auth.pubkey())
-        .receiver(tc.token.auth.pubkey())
-        .mint(tc.token.mint)
+    let ix = token_acl_client::instructions::SetGatingProgramBuilder::new()
+        .authority(auth)
+        .new_gating_program(program_test::AB_ID)
         .mint_config(mint_cfg_pk)
-        .new_freeze_authority(new_freeze_authority_pubkey)
         .instruction();
 
     let tx = Transaction::new_signed_with_payer(
         &[ix],
-        Some(&tc.token.auth.pubkey()),
+        Some(&auth),
         &[tc.token.auth.insecure_clone()],
         tc.vm.latest_blockhash(),
     );
     let res = tc.vm.send_transaction(tx);
-    println!("res: {:?}", res);
-    assert!(res.is_ok());
+    assert_eq!(
+        res.err().unwrap().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(14))
+    );
 
-    let mint_cfg = tc.vm.get_account(&mint_cfg_pk);
-    println!("mint_cfg: {:?}", mint_cfg);
-    assert!(mint_cfg.is_none());
-}
+    let queue_ix = token_acl_client::create_queue_gating_program_instruction(
+        &auth,
+        &auth,
+        &tc.token.mint,
+        &program_test::AB_ID,
+        100,
+    );
+    let finalize_ix =
+        token_acl_client::create_finalize_gating_program_instruction(&tc.token.mint, &auth);
 
-#[test]
-fn test_metadata() {
-    let mut tc = TestContext::new();
-    let _ = tc.setup_token_acl(&program_test::AA_ID);
+    let tx = Transaction::new_signed_with_payer(
+        &[queue_ix, finalize_ix.clone()],
+        Some(&auth),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert_eq!(
+        res.err().unwrap().err,
+        TransactionError::InstructionError(1, InstructionError::Custom(15))
+    );
 
-    let mint = tc.vm.get_account(&tc.token.mint).unwrap();
+    let queue_ix = token_acl_client::create_queue_gating_program_instruction(
+        &auth,
+        &auth,
+        &tc.token.mint,
+        &program_test::AB_ID,
+        100,
+    );
 
-    let gating_program = get_gating_program_from_mint_data(mint.data.as_ref()).unwrap();
-    assert_eq!(gating_program, program_test::AA_ID);
+    let tx = Transaction::new_signed_with_payer(
+        &[queue_ix],
+        Some(&auth),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    // the old gating program stays in place until the change is finalized
+    let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
+        tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert_eq!(mint_config.gating_program, program_test::AA_ID);
+
+    let mut clock = tc.vm.get_sysvar::<Clock>();
+    clock.unix_timestamp += 100;
+    tc.vm.set_sysvar::<Clock>(&clock);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[finalize_ix],
+        Some(&auth),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
+        tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert_eq!(mint_config.gating_program, program_test::AB_ID);
+    assert!(tc
+        .vm
+        .get_account(&token_acl_client::accounts::PendingGatingProgram::find_pda(&tc.token.mint).0)
+        .is_none_or(|account| account.lamports == 0));
+}
+
+#[test]
+fn test_gating_chain_timelock() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+    let auth = tc.token.auth.pubkey();
+
+    let queue_ix = token_acl_client::create_queue_gating_program_instruction(
+        &auth,
+        &auth,
+        &tc.token.mint,
+        &program_test::AA_ID,
+        100,
+    );
+    let finalize_ix =
+        token_acl_client::create_finalize_gating_program_instruction(&tc.token.mint, &auth);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[queue_ix, finalize_ix.clone()],
+        Some(&auth),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    // the immediate gate changes are rejected once the config is timelocked
+    let set_chain_ix = token_acl_client::create_set_gating_chain_instruction(
+        &auth,
+        &tc.token.mint,
+        &[program_test::AB_ID],
+        GateMode::Any,
+        GateMode::All,
+    )
+    .unwrap();
+    let set_freeze_gating_program_ix =
+        token_acl_client::instructions::SetFreezeGatingProgramBuilder::new()
+            .authority(auth)
+            .mint_config(mint_cfg_pk)
+            .new_freeze_gating_program(program_test::AB_ID)
+            .instruction();
+
+    for ix in [set_chain_ix, set_freeze_gating_program_ix] {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&auth),
+            &[tc.token.auth.insecure_clone()],
+            tc.vm.latest_blockhash(),
+        );
+        let res = tc.vm.send_transaction(tx);
+        assert_eq!(
+            res.err().unwrap().err,
+            TransactionError::InstructionError(0, InstructionError::Custom(14))
+        );
+    }
+
+    let queue_ix = token_acl_client::create_queue_gating_chain_instruction(
+        &auth,
+        &auth,
+        &tc.token.mint,
+        &[program_test::AB_ID],
+        GateMode::Any,
+        GateMode::All,
+        &program_test::AB_ID,
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[queue_ix, finalize_ix.clone()],
+        Some(&auth),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert_eq!(
+        res.err().unwrap().err,
+        TransactionError::InstructionError(1, InstructionError::Custom(15))
+    );
+
+    let queue_ix = token_acl_client::create_queue_gating_chain_instruction(
+        &auth,
+        &auth,
+        &tc.token.mint,
+        &[program_test::AB_ID],
+        GateMode::Any,
+        GateMode::All,
+        &program_test::AB_ID,
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[queue_ix],
+        Some(&auth),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let mut clock = tc.vm.get_sysvar::<Clock>();
+    clock.unix_timestamp += 100;
+    tc.vm.set_sysvar::<Clock>(&clock);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[finalize_ix],
+        Some(&auth),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    // only the queued chain is applied, the gating program and delay are kept
+    let mint_config = tc.mint_config();
+    assert_eq!(mint_config.gating_program, program_test::AA_ID);
+    assert_eq!(mint_config.gating_program_delay, 100);
+    assert_eq!(
+        mint_config.additional_gating_programs,
+        [program_test::AB_ID, Pubkey::default(), Pubkey::default()]
+    );
+    assert_eq!(mint_config.thaw_gate_mode, GateMode::Any as u8);
+    assert_eq!(mint_config.freeze_gate_mode, GateMode::All as u8);
+    assert_eq!(mint_config.freeze_gating_program, program_test::AB_ID);
+}
+
+#[test]
+fn test_finalize_gating_program_metadata() {
+    let mut tc = TestContext::new();
+    let _ = tc.setup_token_acl(&program_test::AA_ID);
+    let auth = tc.token.auth.pubkey();
+
+    let queue_ix = token_acl_client::create_queue_gating_program_instruction(
+        &auth,
+        &auth,
+        &tc.token.mint,
+        &program_test::AA_WD_ID,
+        0,
+    );
+    let mut finalize_ix =
+        token_acl_client::create_finalize_gating_program_instruction(&tc.token.mint, &auth);
+    finalize_ix
+        .accounts
+        .extend(token_acl_client::set_gating_program_metadata_account_metas(
+            &tc.token.mint,
+            &auth,
+        ));
+
+    let tx = Transaction::new_signed_with_payer(
+        &[queue_ix, finalize_ix],
+        Some(&auth),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let mint = tc.vm.get_account(&tc.token.mint).unwrap();
+    let gating_program = get_gating_program_from_mint_data(mint.data.as_ref()).unwrap();
+    assert_eq!(gating_program, program_test::AA_WD_ID);
+}
+
+#[test]
+fn test_delete_config_closes_pending_gating_program() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+    let auth = tc.token.auth.pubkey();
+    let pending_pk = token_acl_client::accounts::PendingGatingProgram::find_pda(&tc.token.mint).0;
+
+    let queue_ix = token_acl_client::create_queue_gating_program_instruction(
+        &auth,
+        &auth,
+        &tc.token.mint,
+        &program_test::AB_ID,
+        0,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[queue_ix],
+        Some(&auth),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+    assert!(tc.vm.get_account(&pending_pk).is_some());
+
+    let ix = token_acl_client::instructions::DeleteConfigBuilder::new()
+        .authority(auth)
+        .receiver(auth)
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .pending_gating_program(pending_pk)
+        .pending_payer(auth)
+        .new_freeze_authority(auth)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&auth),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    assert!(tc
+        .vm
+        .get_account(&pending_pk)
+        .is_none_or(|account| account.lamports == 0));
+
+    // the queued change can't be finalized into a config created later for the mint
+    tc.vm.expire_blockhash();
+    tc.setup_token_acl(&program_test::AA_ID);
+
+    let finalize_ix =
+        token_acl_client::create_finalize_gating_program_instruction(&tc.token.mint, &auth);
+    let tx = Transaction::new_signed_with_payer(
+        &[finalize_ix],
+        Some(&auth),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_err());
+    assert_eq!(tc.mint_config().gating_program, program_test::AA_ID);
+}
+
+#[test]
+fn test_toggle_permissionless_instructions() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
+        tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert!(!mint_config.enable_permissionless_freeze);
+    assert!(!mint_config.enable_permissionless_thaw);
+
+    let ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .freeze_enabled(true)
+        .thaw_enabled(false)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
+        tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert!(mint_config.enable_permissionless_freeze);
+    assert!(!mint_config.enable_permissionless_thaw);
+
+    let ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .freeze_enabled(false)
+        .thaw_enabled(true)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
+        tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert!(!mint_config.enable_permissionless_freeze);
+    assert!(mint_config.enable_permissionless_thaw);
+
+    let ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .freeze_enabled(true)
+        .thaw_enabled(true)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
+        tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert!(mint_config.enable_permissionless_freeze);
+    assert!(mint_config.enable_permissionless_thaw);
+}
+
+#[test]
+fn test_pause() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+    let guardian = Keypair::new();
+    tc.vm.airdrop(&guardian.pubkey(), 1_000_000_000).unwrap();
+
+    let user = Keypair::new();
+    let user_token_account = tc.create_token_account(&user);
+
+    let toggle_ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .freeze_enabled(false)
+        .thaw_enabled(true)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    let set_guardian_ix = token_acl_client::instructions::SetPauseGuardianBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .mint_config(mint_cfg_pk)
+        .new_pause_guardian(guardian.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[toggle_ix, set_guardian_ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    // the guardian can pause
+    let ix = token_acl_client::instructions::SetPausedBuilder::new()
+        .authority(guardian.pubkey())
+        .mint_config(mint_cfg_pk)
+        .paused(true)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&guardian.pubkey()),
+        &[guardian.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
+        tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert!(mint_config.paused);
+    assert!(mint_config.enable_permissionless_thaw);
+    assert!(!mint_config.enable_permissionless_freeze);
+
+    let flag_account = token_acl_client::accounts::FlagAccount::find_pda(&user_token_account).0;
+    let ix = token_acl_client::instructions::ThawPermissionlessBuilder::new()
+        .authority(user.pubkey())
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .token_account(user_token_account)
+        .token_account_owner(user.pubkey())
+        .gating_program(program_test::AA_ID)
+        .flag_account(flag_account)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&user.pubkey()),
+        &[user.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert_eq!(
+        res.err().unwrap().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(13))
+    );
+
+    // only the admin can unpause
+    let ix = token_acl_client::instructions::SetPausedBuilder::new()
+        .authority(guardian.pubkey())
+        .mint_config(mint_cfg_pk)
+        .paused(false)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&guardian.pubkey()),
+        &[guardian.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert_eq!(
+        res.err().unwrap().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(0))
+    );
+
+    let ix = token_acl_client::instructions::SetPausedBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .mint_config(mint_cfg_pk)
+        .paused(false)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
+        tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert!(!mint_config.paused);
+    assert!(mint_config.enable_permissionless_thaw);
+}
+
+#[test]
+fn test_thaw_permissioned() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    let user_kp = Keypair::new();
+    let user_ata = tc.create_token_account(&user_kp);
+
+    let user_ta = tc.vm.get_account(&user_ata).unwrap();
+    let account = StateWithExtensions::<Account>::unpack(user_ta.data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Frozen);
+
+    let ix = token_acl_client::instructions::ThawBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .token_account(user_ata)
+        .token_program(TOKEN_PROGRAM_ID)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let user_ta = tc.vm.get_account(&user_ata).unwrap();
+    let account = StateWithExtensions::<Account>::unpack(user_ta.data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Initialized);
+}
+
+#[test]
+fn test_freeze_permissioned() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    let user_kp = Keypair::new();
+    let user_ata = tc.create_token_account(&user_kp);
+
+    let ix = token_acl_client::instructions::ThawBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .token_account(user_ata)
+        .token_program(TOKEN_PROGRAM_ID)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let user_ta = tc.vm.get_account(&user_ata).unwrap();
+    let account = StateWithExtensions::<Account>::unpack(user_ta.data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Initialized);
+
+    let ix = token_acl_client::instructions::FreezeBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .token_account(user_ata)
+        .token_program(TOKEN_PROGRAM_ID)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let user_ta = tc.vm.get_account(&user_ata).unwrap();
+    let account = StateWithExtensions::<Account>::unpack(user_ta.data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Frozen);
+}
+
+#[test]
+fn test_freeze_thaw_spl_token() {
+    let mut tc = TestContext::new_spl_token();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    let mint = tc.vm.get_account(&tc.token.mint).unwrap();
+    assert_eq!(mint.owner, tc.token.token_program);
+    let mint = StateWithExtensions::<Mint>::unpack(mint.data.as_ref()).unwrap();
+    assert_eq!(mint.base.freeze_authority, COption::Some(mint_cfg_pk));
+
+    // no DefaultAccountState on SPL Token, accounts start out initialized
+    let user_kp = Keypair::new();
+    let user_ata = tc.create_token_account(&user_kp);
+
+    let user_ta = tc.vm.get_account(&user_ata).unwrap();
+    let account = StateWithExtensions::<Account>::unpack(user_ta.data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Initialized);
+
+    tc.freeze(&user_ata);
+
+    let user_ta = tc.vm.get_account(&user_ata).unwrap();
+    let account = StateWithExtensions::<Account>::unpack(user_ta.data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Frozen);
+
+    tc.thaw(&user_ata);
+
+    let user_ta = tc.vm.get_account(&user_ata).unwrap();
+    let account = StateWithExtensions::<Account>::unpack(user_ta.data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Initialized);
+
+    let ix = token_acl_client::instructions::DeleteConfigBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .receiver(tc.token.auth.pubkey())
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .pending_gating_program(
+            token_acl_client::accounts::PendingGatingProgram::find_pda(&tc.token.mint).0,
+        )
+        .pending_payer(tc.token.auth.pubkey())
+        .token_program(tc.token.token_program)
+        .new_freeze_authority(tc.token.auth.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let mint = tc.vm.get_account(&tc.token.mint).unwrap();
+    let mint = StateWithExtensions::<Mint>::unpack(mint.data.as_ref()).unwrap();
+    assert_eq!(
+        mint.base.freeze_authority,
+        COption::Some(tc.token.auth.pubkey())
+    );
+}
+
+#[test]
+fn test_freeze_thaw_batch() {
+    let mut tc = TestContext::new();
+    tc.setup_token_acl(&program_test::AA_ID);
+
+    let token_accounts = (0..token_acl_client::MAX_BATCH_TOKEN_ACCOUNTS + 6)
+        .map(|_| tc.create_token_account(&Keypair::new()))
+        .collect::<Vec<_>>();
+
+    // thaw a couple of accounts up front so the batch has to skip them
+    tc.thaw(&token_accounts[0]);
+    tc.thaw(&token_accounts[1]);
+
+    let ixs = token_acl_client::create_thaw_batch_instructions(
+        &tc.token.auth.pubkey(),
+        &tc.mint_config(),
+        &token_accounts,
+        false,
+    );
+    assert_eq!(ixs.len(), 2);
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs[..1],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_err());
+
+    let ixs = token_acl_client::create_thaw_batch_instructions(
+        &tc.token.auth.pubkey(),
+        &tc.mint_config(),
+        &token_accounts,
+        true,
+    );
+    for ix in ixs {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&tc.token.auth.pubkey()),
+            &[tc.token.auth.insecure_clone()],
+            tc.vm.latest_blockhash(),
+        );
+        let res = tc.vm.send_transaction(tx);
+        assert!(res.is_ok());
+    }
+
+    for token_account in &token_accounts {
+        let ta = tc.vm.get_account(token_account).unwrap();
+        let account = StateWithExtensions::<Account>::unpack(ta.data.as_ref()).unwrap();
+        assert_eq!(account.base.state, AccountState::Initialized);
+    }
+
+    tc.freeze(&token_accounts[0]);
+
+    let ixs = token_acl_client::create_freeze_batch_instructions(
+        &tc.token.auth.pubkey(),
+        &tc.token.mint,
+        &token_accounts,
+        true,
+    );
+    for ix in ixs {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&tc.token.auth.pubkey()),
+            &[tc.token.auth.insecure_clone()],
+            tc.vm.latest_blockhash(),
+        );
+        let res = tc.vm.send_transaction(tx);
+        assert!(res.is_ok());
+    }
+
+    for token_account in &token_accounts {
+        let ta = tc.vm.get_account(token_account).unwrap();
+        let account = StateWithExtensions::<Account>::unpack(ta.data.as_ref()).unwrap();
+        assert_eq!(account.base.state, AccountState::Frozen);
+    }
+}
+
+#[test]
+fn test_freeze_reason() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    let user = Keypair::new();
+    let token_account = tc.create_token_account(&user);
+    tc.thaw(&token_account);
+
+    let freeze_reason_pk = token_acl_client::accounts::FreezeReason::find_pda(&token_account).0;
+
+    let ix = token_acl_client::instructions::FreezeWithReasonBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .payer(tc.token.auth.pubkey())
+        .mint(tc.token.mint)
+        .token_account(token_account)
+        .mint_config(mint_cfg_pk)
+        .freeze_reason(freeze_reason_pk)
+        .token_program(tc.token.token_program)
+        .reason_code(42)
+        .memo_hash([7; 32])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let ta = tc.vm.get_account(&token_account).unwrap();
+    let account = StateWithExtensions::<Account>::unpack(ta.data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Frozen);
+
+    let freeze_reason = tc.vm.get_account(&freeze_reason_pk).unwrap();
+    let freeze_reason =
+        token_acl_client::accounts::FreezeReason::from_bytes(&freeze_reason.data).unwrap();
+    assert_eq!(freeze_reason.mint, tc.token.mint);
+    assert_eq!(freeze_reason.token_account, token_account);
+    assert_eq!(freeze_reason.authority, tc.token.auth.pubkey());
+    assert_eq!(freeze_reason.reason_code, 42);
+    assert_eq!(freeze_reason.memo_hash, [7; 32]);
+    assert_eq!(freeze_reason.payer, tc.token.auth.pubkey());
+
+    // the reason can't be closed while the account is still frozen
+    let ix = token_acl_client::create_close_freeze_reason_instruction(
+        &token_account,
+        &tc.token.auth.pubkey(),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(19))
+    );
+
+    let ix = token_acl_client::create_thaw_instruction(
+        &tc.token.auth.pubkey(),
+        &tc.mint_config(),
+        &token_account,
+        &tc.token.token_program,
+        Some(&tc.token.auth.pubkey()),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let ta = tc.vm.get_account(&token_account).unwrap();
+    let account = StateWithExtensions::<Account>::unpack(ta.data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Initialized);
+
+    let freeze_reason = tc.vm.get_account(&freeze_reason_pk);
+    assert!(freeze_reason.is_none());
+}
+
+#[test]
+fn test_freeze_reason_default_frozen() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    // the ATA starts frozen by the default account state
+    let user = Keypair::new();
+    let token_account = tc.create_token_account(&user);
+
+    let freeze_reason_pk = token_acl_client::accounts::FreezeReason::find_pda(&token_account).0;
+
+    let ix = token_acl_client::instructions::FreezeWithReasonBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .payer(tc.token.auth.pubkey())
+        .mint(tc.token.mint)
+        .token_account(token_account)
+        .mint_config(mint_cfg_pk)
+        .freeze_reason(freeze_reason_pk)
+        .token_program(tc.token.token_program)
+        .reason_code(3)
+        .memo_hash([1; 32])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let ta = tc.vm.get_account(&token_account).unwrap();
+    let account = StateWithExtensions::<Account>::unpack(ta.data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Frozen);
+
+    let freeze_reason = tc.vm.get_account(&freeze_reason_pk).unwrap();
+    let freeze_reason =
+        token_acl_client::accounts::FreezeReason::from_bytes(&freeze_reason.data).unwrap();
+    assert_eq!(freeze_reason.token_account, token_account);
+    assert_eq!(freeze_reason.reason_code, 3);
+    assert_eq!(freeze_reason.memo_hash, [1; 32]);
+}
+
+#[test]
+fn test_sticky_freeze() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    let user = Keypair::new();
+    let token_account = tc.create_token_account(&user);
+    tc.thaw(&token_account);
+
+    let toggle_ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .freeze_enabled(false)
+        .thaw_enabled(true)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    let sticky_ix = token_acl_client::instructions::SetStickyFreezeBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .mint_config(mint_cfg_pk)
+        .sticky_freeze(true)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[toggle_ix, sticky_ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
+        tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert!(mint_config.sticky_freeze);
+
+    // freezes without a reason are refused
+    let ix = token_acl_client::instructions::FreezeBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .token_account(token_account)
+        .token_program(tc.token.token_program)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(20))
+    );
+
+    let freeze_reason_pk = token_acl_client::accounts::FreezeReason::find_pda(&token_account).0;
+
+    let ix = token_acl_client::instructions::FreezeWithReasonBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .payer(tc.token.auth.pubkey())
+        .mint(tc.token.mint)
+        .token_account(token_account)
+        .mint_config(mint_cfg_pk)
+        .freeze_reason(freeze_reason_pk)
+        .token_program(tc.token.token_program)
+        .reason_code(1)
+        .memo_hash([0; 32])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    // the gate allows everyone, but the freeze reason marks the account
+    let flag_account = token_acl_client::accounts::FlagAccount::find_pda(&token_account).0;
+    let ix = token_acl_client::instructions::ThawPermissionlessBuilder::new()
+        .authority(user.pubkey())
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .token_account(token_account)
+        .token_account_owner(user.pubkey())
+        .gating_program(program_test::AA_ID)
+        .flag_account(flag_account)
+        .add_remaining_account(AccountMeta::new_readonly(freeze_reason_pk, false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&user.pubkey()),
+        &[user.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(21))
+    );
+
+    // a permissioned thaw has to clear the mark
+    tc.vm.expire_blockhash();
+    let ix = token_acl_client::instructions::ThawBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .token_account(token_account)
+        .token_program(tc.token.token_program)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(20))
+    );
+
+    let ix = token_acl_client::create_thaw_instruction(
+        &tc.token.auth.pubkey(),
+        &tc.mint_config(),
+        &token_account,
+        &tc.token.token_program,
+        Some(&tc.token.auth.pubkey()),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+    assert!(tc.vm.get_account(&freeze_reason_pk).is_none());
+}
+
+#[test]
+fn test_sticky_freeze_thaw_batch() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    let token_accounts = (0..token_acl_client::MAX_BATCH_WITH_FREEZE_REASON_TOKEN_ACCOUNTS + 1)
+        .map(|_| tc.create_token_account(&Keypair::new()))
+        .collect::<Vec<_>>();
+
+    // the first two get a freeze reason, the others stay frozen by the default account state
+    for token_account in &token_accounts[..2] {
+        tc.thaw(token_account);
+    }
+
+    let sticky_ix = token_acl_client::instructions::SetStickyFreezeBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .mint_config(mint_cfg_pk)
+        .sticky_freeze(true)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[sticky_ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let ixs = token_acl_client::create_freeze_batch_with_reason_instructions(
+        &tc.token.auth.pubkey(),
+        &tc.token.auth.pubkey(),
+        &tc.token.mint,
+        &token_accounts[..2],
+        false,
+        1,
+        [0; 32],
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let freeze_reasons = token_accounts
+        .iter()
+        .map(|token_account| token_acl_client::accounts::FreezeReason::find_pda(token_account).0)
+        .collect::<Vec<_>>();
+    assert!(tc.vm.get_account(&freeze_reasons[0]).is_some());
+    assert!(tc.vm.get_account(&freeze_reasons[1]).is_some());
+
+    // without the freeze reasons the batch is refused
+    let ixs = token_acl_client::create_thaw_batch_instructions(
+        &tc.token.auth.pubkey(),
+        &tc.mint_config(),
+        &token_accounts,
+        true,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(20))
+    );
+
+    // accounts without a freeze reason take any receiver
+    let pairs = token_accounts
+        .iter()
+        .enumerate()
+        .map(|(i, token_account)| {
+            let receiver = if i < 2 {
+                tc.token.auth.pubkey()
+            } else {
+                Pubkey::new_unique()
+            };
+            (*token_account, receiver)
+        })
+        .collect::<Vec<_>>();
+    let ixs = token_acl_client::create_thaw_batch_with_reason_instructions(
+        &tc.token.auth.pubkey(),
+        &tc.mint_config(),
+        &pairs,
+        true,
+    );
+    assert_eq!(ixs.len(), 2);
+    for ix in ixs {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&tc.token.auth.pubkey()),
+            &[tc.token.auth.insecure_clone()],
+            tc.vm.latest_blockhash(),
+        );
+        let res = tc.vm.send_transaction(tx);
+        assert!(res.is_ok());
+    }
+
+    for (token_account, freeze_reason) in token_accounts.iter().zip(&freeze_reasons) {
+        let ta = tc.vm.get_account(token_account).unwrap();
+        let account = StateWithExtensions::<Account>::unpack(ta.data.as_ref()).unwrap();
+        assert_eq!(account.base.state, AccountState::Initialized);
+        assert!(tc.vm.get_account(freeze_reason).is_none());
+    }
+}
+
+#[test]
+fn test_permissionless_fee() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+    let fee_vault = token_acl_client::find_fee_vault_pda(&tc.token.mint).0;
+
+    let user = Keypair::new();
+    let token_account = tc.create_token_account(&user);
+
+    let toggle_ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .freeze_enabled(false)
+        .thaw_enabled(true)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    let fee_ix = token_acl_client::create_set_permissionless_fee_instruction(
+        &tc.token.auth.pubkey(),
+        &tc.token.auth.pubkey(),
+        &tc.token.mint,
+        5_000,
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[toggle_ix, fee_ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let rent_exempt_minimum = tc.vm.minimum_balance_for_rent_exemption(0);
+    assert_eq!(
+        tc.vm.get_account(&fee_vault).unwrap().lamports,
+        rent_exempt_minimum
+    );
+
+    let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
+        tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert_eq!(mint_config.permissionless_fee, 5_000);
+
+    // the fee vault is required once the config charges a fee
+    let flag_account = token_acl_client::accounts::FlagAccount::find_pda(&token_account).0;
+    let ix = token_acl_client::instructions::ThawPermissionlessBuilder::new()
+        .authority(user.pubkey())
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .token_account(token_account)
+        .token_account_owner(user.pubkey())
+        .gating_program(program_test::AA_ID)
+        .flag_account(flag_account)
+        .add_remaining_account(AccountMeta::new(Pubkey::new_unique(), false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&user.pubkey()),
+        &[user.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(22))
+    );
+
+    let ix = token_acl_client::instructions::ThawPermissionlessBuilder::new()
+        .authority(user.pubkey())
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .token_account(token_account)
+        .token_account_owner(user.pubkey())
+        .gating_program(program_test::AA_ID)
+        .flag_account(flag_account)
+        .add_remaining_account(
+            token_acl_client::permissionless_fee_account_meta(&mint_config).unwrap(),
+        )
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&user.pubkey()),
+        &[user.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let ta = tc.vm.get_account(&token_account).unwrap();
+    let account = StateWithExtensions::<Account>::unpack(ta.data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Initialized);
+    assert_eq!(
+        tc.vm.get_account(&fee_vault).unwrap().lamports,
+        rent_exempt_minimum + 5_000
+    );
+
+    // only the collected fees can be withdrawn
+    let receiver = Pubkey::new_unique();
+    let ix = token_acl_client::create_withdraw_fees_instruction(
+        &tc.token.auth.pubkey(),
+        &tc.token.mint,
+        &receiver,
+        5_001,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(23))
+    );
+
+    let ix = token_acl_client::create_withdraw_fees_instruction(
+        &tc.token.auth.pubkey(),
+        &tc.token.mint,
+        &tc.token.auth.pubkey(),
+        5_000,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+    assert_eq!(
+        tc.vm.get_account(&fee_vault).unwrap().lamports,
+        rent_exempt_minimum
+    );
+}
+
+#[test]
+fn test_delete_config() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    let mint = tc.vm.get_account(&tc.token.mint).unwrap();
+    let mint = StateWithExtensions::<Mint>::unpack(mint.data.as_ref()).unwrap();
+    assert_eq!(mint.base.freeze_authority, COption::Some(mint_cfg_pk));
+
+    let new_freeze_authority = Keypair::new();
+    let new_freeze_authority_pubkey = new_freeze_authority.pubkey();
+
+    let ix = token_acl_client::instructions::DeleteConfigBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .receiver(tc.token.auth.pubkey())
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .pending_gating_program(
+            token_acl_client::accounts::PendingGatingProgram::find_pda(&tc.token.mint).0,
+        )
+        .pending_payer(tc.token.auth.pubkey())
+        .new_freeze_authority(new_freeze_authority_pubkey)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    println!("res: {:?}", res);
+    assert!(res.is_ok());
+
+    let mint = tc.vm.get_account(&tc.token.mint).unwrap();
+    let mint = StateWithExtensions::<Mint>::unpack(mint.data.as_ref()).unwrap();
+    assert_eq!(
+        mint.base.freeze_authority,
+        COption::Some(new_freeze_authority_pubkey)
+    );
+}
+
+#[test]
+fn test_delete_config_after_close() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    let mint = tc.vm.get_account(&tc.token.mint).unwrap();
+    let mint = StateWithExtensions::<Mint>::unpack(mint.data.as_ref()).unwrap();
+    assert_eq!(mint.base.freeze_authority, COption::Some(mint_cfg_pk));
+
+    let new_freeze_authority = Keypair::new();
+    let new_freeze_authority_pubkey = new_freeze_authority.pubkey();
+
+    tc.close_mint();
+
+    let ix = token_acl_client::instructions::DeleteConfigBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .receiver(tc.token.auth.pubkey())
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .pending_gating_program(
+            token_acl_client::accounts::PendingGatingProgram::find_pda(&tc.token.mint).0,
+        )
+        .pending_payer(tc.token.auth.pubkey())
+        .new_freeze_authority(new_freeze_authority_pubkey)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    println!("res: {:?}", res);
+    assert!(res.is_ok());
+
+    let mint_cfg = tc.vm.get_account(&mint_cfg_pk);
+    println!("mint_cfg: {:?}", mint_cfg);
+    assert!(mint_cfg.is_none());
+}
+
+#[test]
+fn test_metadata() {
+    let mut tc = TestContext::new();
+    let _ = tc.setup_token_acl(&program_test::AA_ID);
+
+    let mint = tc.vm.get_account(&tc.token.mint).unwrap();
+
+    let gating_program = get_gating_program_from_mint_data(mint.data.as_ref()).unwrap();
+    assert_eq!(gating_program, program_test::AA_ID);
+}
+
+#[test]
+fn test_set_gating_program_metadata() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    let ix = token_acl_client::instructions::SetGatingProgramBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .new_gating_program(program_test::AA_WD_ID)
+        .mint_config(mint_cfg_pk)
+        .add_remaining_accounts(
+            &token_acl_client::set_gating_program_metadata_account_metas(
+                &tc.token.mint,
+                &tc.token.auth.pubkey(),
+            ),
+        )
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let mint = tc.vm.get_account(&tc.token.mint).unwrap();
+    let gating_program = get_gating_program_from_mint_data(mint.data.as_ref()).unwrap();
+    assert_eq!(gating_program, program_test::AA_WD_ID);
+
+    // the metadata update authority has to sign
+    let other = Keypair::new();
+    tc.vm.airdrop(&other.pubkey(), 1_000_000_000).unwrap();
+
+    let ix = token_acl_client::instructions::SetGatingProgramBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .new_gating_program(program_test::AA_ID)
+        .mint_config(mint_cfg_pk)
+        .add_remaining_accounts(
+            &token_acl_client::set_gating_program_metadata_account_metas(
+                &tc.token.mint,
+                &other.pubkey(),
+            ),
+        )
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone(), other.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_set_gating_program_external_metadata() {
+    let mut tc = TestContext::new();
+    tc.token = TestContext::create_token_with_external_metadata(&mut tc.vm, &Pubkey::new_unique());
+    let (mint_cfg_pk, _) = token_acl_client::accounts::MintConfig::find_pda(&tc.token.mint);
+
+    // metadata in another account is left to its own program
+    let create_config_ix = |metadata_accounts: &[AccountMeta]| {
+        token_acl_client::instructions::CreateConfigBuilder::new()
+            .authority(tc.token.auth.pubkey())
+            .gating_program(program_test::AA_ID)
+            .mint(tc.token.mint)
+            .mint_config(mint_cfg_pk)
+            .payer(tc.token.auth.pubkey())
+            .system_program(solana_system_interface::program::ID)
+            .token_program(tc.token.token_program)
+            .add_remaining_accounts(metadata_accounts)
+            .instruction()
+    };
+    let ix = create_config_ix(&token_acl_client::create_config_metadata_account_metas(
+        &tc.token.auth.pubkey(),
+    ));
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(26))
+    );
+
+    let ix = create_config_ix(&[]);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let ix = token_acl_client::instructions::SetGatingProgramBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .new_gating_program(program_test::AA_WD_ID)
+        .mint_config(mint_cfg_pk)
+        .add_remaining_accounts(
+            &token_acl_client::set_gating_program_metadata_account_metas(
+                &tc.token.mint,
+                &tc.token.auth.pubkey(),
+            ),
+        )
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(26))
+    );
+
+    assert_eq!(tc.mint_config().gating_program, program_test::AA_ID);
+}
+
+#[test]
+fn test_thaw_expiry() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    let user = Keypair::new();
+    let token_account = tc.create_token_account(&user);
+    let thaw_expiry = token_acl_client::accounts::ThawExpiry::find_pda(&token_account).0;
+
+    let toggle_ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .freeze_enabled(false)
+        .thaw_enabled(true)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    let ttl_ix = token_acl_client::create_set_thaw_ttl_instruction(
+        &tc.token.auth.pubkey(),
+        &tc.token.mint,
+        100,
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[toggle_ix, ttl_ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
+        tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert_eq!(mint_config.thaw_ttl, 100);
+
+    let flag_account = token_acl_client::accounts::FlagAccount::find_pda(&token_account).0;
+    let ix = token_acl_client::instructions::ThawPermissionlessBuilder::new()
+        .authority(user.pubkey())
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .token_account(token_account)
+        .token_account_owner(user.pubkey())
+        .gating_program(program_test::AA_ID)
+        .flag_account(flag_account)
+        .add_remaining_account(
+            token_acl_client::thaw_expiry_account_meta(&mint_config, &token_account).unwrap(),
+        )
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&user.pubkey()),
+        &[user.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let now = tc.vm.get_sysvar::<Clock>().unix_timestamp;
+    let expiry = token_acl_client::accounts::ThawExpiry::from_bytes(
+        tc.vm.get_account(&thaw_expiry).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert_eq!(expiry.expires_at, now + 100);
+    assert_eq!(expiry.payer, user.pubkey());
+    assert!(!expiry.is_expired(now));
+
+    // permissionless freeze stays disabled until the thaw has expired
+    let freeze_ix = token_acl_client::instructions::FreezePermissionlessBuilder::new()
+        .authority(user.pubkey())
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .token_account(token_account)
+        .token_account_owner(user.pubkey())
+        .gating_program(program_test::AA_ID)
+        .flag_account(flag_account)
+        .add_remaining_account(AccountMeta::new(thaw_expiry, false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        std::slice::from_ref(&freeze_ix),
+        Some(&user.pubkey()),
+        &[user.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(7))
+    );
+
+    // the expiry can't be closed while the account is still thawed
+    let close_ix =
+        token_acl_client::create_close_thaw_expiry_instruction(&token_account, &user.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        std::slice::from_ref(&close_ix),
+        Some(&user.pubkey()),
+        &[user.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(25))
+    );
+
+    let mut clock = tc.vm.get_sysvar::<Clock>();
+    clock.unix_timestamp += 100;
+    tc.vm.set_sysvar::<Clock>(&clock);
+    tc.vm.expire_blockhash();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[freeze_ix],
+        Some(&user.pubkey()),
+        &[user.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let ta = tc.vm.get_account(&token_account).unwrap();
+    let account = StateWithExtensions::<Account>::unpack(ta.data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Frozen);
+
+    let expiry = token_acl_client::accounts::ThawExpiry::from_bytes(
+        tc.vm.get_account(&thaw_expiry).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert_eq!(expiry.expires_at, 0);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&user.pubkey()),
+        &[user.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+    assert_eq!(
+        parse_token_acl_events(&res.unwrap().logs).unwrap(),
+        vec![TokenAclEvent::ThawExpiryClosed(ThawExpiryClosed {
+            mint: tc.token.mint,
+            token_account,
+            receiver: user.pubkey(),
+        })]
+    );
+    assert!(tc
+        .vm
+        .get_account(&thaw_expiry)
+        .is_none_or(|account| account.lamports == 0));
+}
+
+#[test]
+fn test_thaw_resets_thaw_expiry() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    let user = Keypair::new();
+    let token_account = tc.create_token_account(&user);
+    let batch_user = Keypair::new();
+    let batch_token_account = tc.create_token_account(&batch_user);
+
+    let toggle_ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .freeze_enabled(false)
+        .thaw_enabled(true)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    let ttl_ix = token_acl_client::create_set_thaw_ttl_instruction(
+        &tc.token.auth.pubkey(),
+        &tc.token.mint,
+        100,
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[toggle_ix, ttl_ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let mint_config = tc.mint_config();
+    let permissionless_ixs = |owner: &Keypair, token_account: &Pubkey| {
+        let flag_account = token_acl_client::accounts::FlagAccount::find_pda(token_account).0;
+        let thaw_ix = token_acl_client::instructions::ThawPermissionlessBuilder::new()
+            .authority(owner.pubkey())
+            .mint(tc.token.mint)
+            .mint_config(mint_cfg_pk)
+            .token_account(*token_account)
+            .token_account_owner(owner.pubkey())
+            .gating_program(program_test::AA_ID)
+            .flag_account(flag_account)
+            .add_remaining_accounts(&token_acl_client::permissionless_account_metas(
+                &mint_config,
+                token_account,
+                PermissionlessOperation::Thaw,
+            ))
+            .instruction();
+        let freeze_ix = token_acl_client::instructions::FreezePermissionlessBuilder::new()
+            .authority(owner.pubkey())
+            .mint(tc.token.mint)
+            .mint_config(mint_cfg_pk)
+            .token_account(*token_account)
+            .token_account_owner(owner.pubkey())
+            .gating_program(program_test::AA_ID)
+            .flag_account(flag_account)
+            .add_remaining_accounts(&token_acl_client::permissionless_account_metas(
+                &mint_config,
+                token_account,
+                PermissionlessOperation::Freeze,
+            ))
+            .instruction();
+        (thaw_ix, freeze_ix)
+    };
+    let (thaw_ix, freeze_ix) = permissionless_ixs(&user, &token_account);
+    let (batch_thaw_ix, batch_freeze_ix) = permissionless_ixs(&batch_user, &batch_token_account);
+
+    for (owner, thaw_ix) in [(&user, thaw_ix), (&batch_user, batch_thaw_ix)] {
+        let tx = Transaction::new_signed_with_payer(
+            &[thaw_ix],
+            Some(&owner.pubkey()),
+            &[owner.insecure_clone()],
+            tc.vm.latest_blockhash(),
+        );
+        let res = tc.vm.send_transaction(tx);
+        assert!(res.is_ok());
+    }
+
+    // the freeze operator takes over, and its thaws don't expire
+    tc.freeze(&token_account);
+    tc.freeze(&batch_token_account);
+    tc.thaw(&token_account);
+
+    let ixs = token_acl_client::create_thaw_batch_instructions(
+        &tc.token.auth.pubkey(),
+        &mint_config,
+        &[batch_token_account],
+        false,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    for token_account in [token_account, batch_token_account] {
+        let thaw_expiry = token_acl_client::accounts::ThawExpiry::find_pda(&token_account).0;
+        let expiry = token_acl_client::accounts::ThawExpiry::from_bytes(
+            tc.vm.get_account(&thaw_expiry).unwrap().data.as_ref(),
+        )
+        .unwrap();
+        assert_eq!(expiry.expires_at, 0);
+    }
+
+    let mut clock = tc.vm.get_sysvar::<Clock>();
+    clock.unix_timestamp += 100;
+    tc.vm.set_sysvar::<Clock>(&clock);
+    tc.vm.expire_blockhash();
+
+    // so the gate still decides, and permissionless freeze is disabled
+    for (owner, freeze_ix) in [(&user, freeze_ix), (&batch_user, batch_freeze_ix)] {
+        let tx = Transaction::new_signed_with_payer(
+            &[freeze_ix],
+            Some(&owner.pubkey()),
+            &[owner.insecure_clone()],
+            tc.vm.latest_blockhash(),
+        );
+        let res = tc.vm.send_transaction(tx);
+        assert_eq!(
+            res.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::Custom(7))
+        );
+    }
 }
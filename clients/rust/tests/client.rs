@@ -0,0 +1,325 @@
+pub mod program_test;
+use std::{collections::HashMap, sync::Arc};
+
+use solana_sdk::{
+    account::Account as SolanaAccount,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use spl_token_2022_interface::{
+    extension::StateWithExtensions,
+    state::{Account, AccountState},
+};
+use token_acl_client::{
+    accounts::FreezeReason, MintConfigFilter, TokenAclClient, TokenAclClientError,
+};
+
+use crate::program_test::TestContext;
+
+fn snapshot(tc: &TestContext, pubkeys: &[Pubkey]) -> Arc<HashMap<Pubkey, SolanaAccount>> {
+    Arc::new(
+        pubkeys
+            .iter()
+            .filter_map(|pubkey| tc.vm.get_account(pubkey).map(|account| (*pubkey, account)))
+            .collect(),
+    )
+}
+
+#[tokio::test]
+async fn test_client_thaw_and_freeze() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    let user_kp = Keypair::new();
+    let user_ata = tc.create_token_account(&user_kp);
+
+    let payer = Keypair::new();
+    tc.vm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+
+    let fetcher = snapshot(&tc, &[tc.token.mint, mint_cfg_pk, user_ata]);
+    let client = TokenAclClient::new(fetcher, Arc::new(payer))
+        .with_authority(Arc::new(tc.token.auth.insecure_clone()));
+
+    let mint_config = client.get_mint_config(&tc.token.mint).await.unwrap();
+    assert_eq!(mint_config.gating_program, program_test::AA_ID);
+
+    let instructions = client.thaw(&user_ata).await.unwrap();
+    let tx = client
+        .transaction_with_blockhash(&instructions, tc.vm.latest_blockhash())
+        .unwrap();
+    assert_eq!(tx.signatures.len(), 2);
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let user_ta = tc.vm.get_account(&user_ata).unwrap();
+    let account = StateWithExtensions::<Account>::unpack(user_ta.data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Initialized);
+
+    let instructions = client.freeze(&user_ata).await.unwrap();
+    let tx = client
+        .transaction_with_blockhash(&instructions, tc.vm.latest_blockhash())
+        .unwrap();
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let user_ta = tc.vm.get_account(&user_ata).unwrap();
+    let account = StateWithExtensions::<Account>::unpack(user_ta.data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Frozen);
+
+    // nothing to send the transactions with
+    let res = client.send(&instructions).await;
+    assert!(matches!(res, Err(TokenAclClientError::NoTransactionSender)));
+
+    let res = client.thaw(&Pubkey::new_unique()).await;
+    assert!(matches!(res, Err(TokenAclClientError::AccountNotFound(_))));
+}
+
+#[tokio::test]
+async fn test_client_sticky_freeze() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+    let auth = Arc::new(tc.token.auth.insecure_clone());
+
+    let user_kp = Keypair::new();
+    let user_ata = tc.create_token_account(&user_kp);
+    tc.thaw(&user_ata);
+
+    let sticky_ix = token_acl_client::instructions::SetStickyFreezeBuilder::new()
+        .authority(auth.pubkey())
+        .mint_config(mint_cfg_pk)
+        .sticky_freeze(true)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[sticky_ix],
+        Some(&auth.pubkey()),
+        &[auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    assert!(tc.vm.send_transaction(tx).is_ok());
+
+    let payer = Keypair::new();
+    let payer_pk = payer.pubkey();
+    tc.vm.airdrop(&payer_pk, 1_000_000_000).unwrap();
+
+    let fetcher = snapshot(&tc, &[tc.token.mint, mint_cfg_pk, user_ata]);
+    let client = TokenAclClient::new(fetcher, Arc::new(payer)).with_authority(auth);
+
+    // a plain freeze is refused under sticky freeze
+    let instructions = client.freeze(&user_ata).await.unwrap();
+    let tx = client
+        .transaction_with_blockhash(&instructions, tc.vm.latest_blockhash())
+        .unwrap();
+    assert_eq!(
+        tc.vm.send_transaction(tx).err().unwrap().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(20))
+    );
+
+    let instructions = client
+        .freeze_with_reason(&user_ata, 7, [1; 32])
+        .await
+        .unwrap();
+    let tx = client
+        .transaction_with_blockhash(&instructions, tc.vm.latest_blockhash())
+        .unwrap();
+    assert!(tc.vm.send_transaction(tx).is_ok());
+
+    let freeze_reason_pk = FreezeReason::find_pda(&user_ata).0;
+    let freeze_reason =
+        FreezeReason::from_bytes(&tc.vm.get_account(&freeze_reason_pk).unwrap().data).unwrap();
+    assert_eq!(freeze_reason.reason_code, 7);
+    assert_eq!(freeze_reason.payer, payer_pk);
+
+    // the thaw passes the freeze reason and returns its rent to the payer that funded it
+    let fetcher = snapshot(
+        &tc,
+        &[tc.token.mint, mint_cfg_pk, user_ata, freeze_reason_pk],
+    );
+    let client = TokenAclClient::new(fetcher, Arc::new(Keypair::new()))
+        .with_authority(Arc::new(tc.token.auth.insecure_clone()));
+    let instructions = client.thaw(&user_ata).await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let payer_lamports = tc.vm.get_account(&payer_pk).unwrap().lamports;
+    assert!(tc.vm.send_transaction(tx).is_ok());
+
+    let user_ta = tc.vm.get_account(&user_ata).unwrap();
+    let account = StateWithExtensions::<Account>::unpack(user_ta.data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Initialized);
+    assert!(tc
+        .vm
+        .get_account(&freeze_reason_pk)
+        .is_none_or(|account| account.lamports == 0));
+    assert!(tc.vm.get_account(&payer_pk).unwrap().lamports > payer_lamports);
+}
+
+#[test]
+fn test_client_is_send() {
+    fn assert_send<T: Send>(_: &T) {}
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    // services hold the client across threads and spawn its futures on multi-threaded runtimes
+    assert_send_sync::<TokenAclClient>();
+
+    let client = TokenAclClient::new(
+        Arc::new(HashMap::<Pubkey, SolanaAccount>::new()),
+        Arc::new(Keypair::new()),
+    );
+    let pubkey = Pubkey::new_unique();
+    assert_send(&client.thaw(&pubkey));
+    assert_send(&client.freeze_with_reason(&pubkey, 0, [0; 32]));
+    assert_send(&client.thaw_permissionless(&pubkey, &pubkey, false));
+    assert_send(&client.send(&[]));
+}
+
+#[tokio::test]
+async fn test_client_inspect_mint() {
+    let mut tc = TestContext::new();
+    let payer = Arc::new(tc.token.auth.insecure_clone());
+
+    let fetcher = snapshot(&tc, &[tc.token.mint]);
+    let client = TokenAclClient::new(fetcher, payer.clone());
+    let status = client.inspect_mint(&tc.token.mint).await.unwrap();
+    assert!(!status.is_managed());
+    assert!(status.is_default_frozen());
+    assert!(status.mint_config.is_none());
+    assert!(status.thaw_extra_metas.is_empty());
+
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+    let thaw_extra_metas = token_acl_interface::get_thaw_extra_account_metas_address(
+        &tc.token.mint,
+        &program_test::AA_ID,
+    );
+    let freeze_extra_metas = token_acl_interface::get_freeze_extra_account_metas_address(
+        &tc.token.mint,
+        &program_test::AA_ID,
+    );
+
+    let fetcher = snapshot(&tc, &[tc.token.mint, mint_cfg_pk]);
+    let client = TokenAclClient::new(fetcher, payer.clone());
+    let status = client.inspect_mint(&tc.token.mint).await.unwrap();
+    assert!(status.is_managed());
+    assert!(status.is_metadata_consistent());
+    assert_eq!(status.metadata_gating_program, Some(program_test::AA_ID));
+    assert_eq!(status.thaw_extra_metas.len(), 1);
+    assert_eq!(status.thaw_extra_metas[0].address, thaw_extra_metas);
+    assert!(!status.has_thaw_extra_metas());
+    assert!(!status.has_freeze_extra_metas());
+
+    tc.setup_aa_gate_extra_metas();
+
+    let fetcher = snapshot(
+        &tc,
+        &[
+            tc.token.mint,
+            mint_cfg_pk,
+            thaw_extra_metas,
+            freeze_extra_metas,
+        ],
+    );
+    let client = TokenAclClient::new(fetcher, payer);
+    let status = client.inspect_mint(&tc.token.mint).await.unwrap();
+    assert!(status.has_thaw_extra_metas());
+    assert!(status.has_freeze_extra_metas());
+}
+
+#[tokio::test]
+async fn test_client_create_config_metadata() {
+    let tc = TestContext::new();
+    let auth = tc.token.auth.pubkey();
+
+    let fetcher = snapshot(&tc, &[tc.token.mint]);
+    let client = TokenAclClient::new(fetcher, Arc::new(tc.token.auth.insecure_clone()));
+
+    // the authority updates the metadata when it's also the metadata update authority
+    let instructions = client
+        .create_config(&tc.token.mint, Some(program_test::AA_ID))
+        .await
+        .unwrap();
+    assert_eq!(instructions[0].accounts.len(), 7);
+    assert_eq!(instructions[0].accounts[6].pubkey, auth);
+    assert!(instructions[0].accounts[6].is_signer);
+
+    let instructions = client.create_config(&tc.token.mint, None).await.unwrap();
+    assert_eq!(instructions[0].accounts.len(), 6);
+
+    let instructions = client
+        .set_gating_program(&tc.token.mint, &program_test::AA_WD_ID)
+        .await
+        .unwrap();
+    assert_eq!(instructions[0].accounts.len(), 5);
+
+    let fetcher = snapshot(&tc, &[tc.token.mint]);
+    let client = TokenAclClient::new(fetcher, Arc::new(Keypair::new()));
+    let instructions = client
+        .set_gating_program(&tc.token.mint, &program_test::AA_WD_ID)
+        .await
+        .unwrap();
+    assert_eq!(instructions[0].accounts.len(), 2);
+}
+
+#[tokio::test]
+async fn test_mint_config_filter() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    let ix = token_acl_client::instructions::SetFreezeGatingProgramBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .mint_config(mint_cfg_pk)
+        .new_freeze_gating_program(program_test::AB_ID)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    assert!(tc.vm.send_transaction(tx).is_ok());
+
+    let data = tc.vm.get_account(&mint_cfg_pk).unwrap().data;
+    let config = token_acl_client::accounts::MintConfig::from_bytes(&data).unwrap();
+
+    let matches = |filter: &MintConfigFilter| {
+        filter
+            .memcmp_filters()
+            .iter()
+            .all(|(offset, bytes)| data[*offset..].starts_with(bytes))
+    };
+
+    let filter = MintConfigFilter {
+        authority: Some(config.freeze_authority),
+        gating_program: Some(program_test::AA_ID),
+        freeze_gating_program: Some(program_test::AB_ID),
+        enable_permissionless_thaw: Some(config.enable_permissionless_thaw),
+        enable_permissionless_freeze: Some(config.enable_permissionless_freeze),
+    };
+    assert!(matches(&filter));
+    assert!(matches(&MintConfigFilter::default()));
+
+    assert!(!matches(&MintConfigFilter {
+        authority: Some(Pubkey::new_unique()),
+        ..filter.clone()
+    }));
+    assert!(!matches(&MintConfigFilter {
+        gating_program: Some(Pubkey::new_unique()),
+        ..filter.clone()
+    }));
+    assert!(!matches(&MintConfigFilter {
+        freeze_gating_program: Some(program_test::AA_ID),
+        ..filter.clone()
+    }));
+    assert!(!matches(&MintConfigFilter {
+        enable_permissionless_thaw: Some(!config.enable_permissionless_thaw),
+        ..filter.clone()
+    }));
+    assert!(!matches(&MintConfigFilter {
+        enable_permissionless_freeze: Some(!config.enable_permissionless_freeze),
+        ..filter
+    }));
+}
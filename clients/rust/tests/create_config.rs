@@ -10,6 +10,11 @@ use spl_token_2022_interface::ID as TOKEN_PROGRAM_ID;
 
 use crate::program_test::TestContext;
 
+/// The forbid-additional-mints fixture is built with
+/// `TOKEN_ACL_ALLOWED_MINTS=GmaDrppBC7P5ARKV8g3djiwP89vz1jLK23V2GBjuAEGB`, the address of this
+/// keypair.
+const ALLOWED_MINT_SECRET: [u8; 32] = [7; 32];
+
 #[test]
 fn test_create_mint_config() {
     let mut tc = TestContext::new();
@@ -216,3 +221,164 @@ fn test_create_mint_config_with_existing_config() {
         )
     );
 }
+
+#[test]
+fn test_create_mint_config_forbid_additional_mints() {
+    // built with `forbid-additional-mints` and an allowlist that doesn't include the test mint
+    let mut tc = TestContext::new_with_token_acl_fixture("token_acl_forbid_additional_mints.so");
+
+    let (mint_cfg_pk, _bump) = token_acl_client::accounts::MintConfig::find_pda(&tc.token.mint);
+
+    let ix = token_acl_client::instructions::CreateConfigBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .gating_program(program_test::AA_ID)
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .payer(tc.token.auth.pubkey())
+        .system_program(SYSTEM_PROGRAM_ID)
+        .token_program(TOKEN_PROGRAM_ID)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_err());
+
+    let res_err = res.err().unwrap();
+    assert_eq!(
+        res_err.err,
+        TransactionError::InstructionError(
+            0x00,
+            InstructionError::Custom(
+                token_acl_client::errors::TokenAclError::MintNotAllowed as u32
+            )
+        )
+    );
+
+    let acc = tc.vm.get_account(&mint_cfg_pk);
+
+    assert!(acc.is_none());
+}
+
+#[test]
+fn test_create_mint_config_forbid_additional_mints_allowed_mint() {
+    let mut tc = TestContext::new_with_token_acl_fixture("token_acl_forbid_additional_mints.so");
+    tc.token = TestContext::create_token_with_mint_keypair(
+        &mut tc.vm,
+        Keypair::new_from_array(ALLOWED_MINT_SECRET),
+    );
+
+    let (mint_cfg_pk, _bump) = token_acl_client::accounts::MintConfig::find_pda(&tc.token.mint);
+
+    let ix = token_acl_client::instructions::CreateConfigBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .gating_program(program_test::AA_ID)
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .payer(tc.token.auth.pubkey())
+        .system_program(SYSTEM_PROGRAM_ID)
+        .token_program(TOKEN_PROGRAM_ID)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let mint_config = token_acl_client::accounts::MintConfig::from_bytes(
+        tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert_eq!(mint_config.mint, tc.token.mint);
+}
+
+#[test]
+fn test_migrate_v1_config() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    // rewrite the config with the original, unversioned layout
+    let mut account = tc.vm.get_account(&mint_cfg_pk).unwrap();
+    account
+        .data
+        .truncate(token_acl_client::accounts::MintConfig::V1_LEN);
+    account.lamports = tc.vm.minimum_balance_for_rent_exemption(account.data.len());
+    tc.vm.set_account(mint_cfg_pk, account).unwrap();
+
+    let cfg = token_acl_client::accounts::MintConfig::from_account_data(
+        tc.vm.get_account(&mint_cfg_pk).unwrap().data.as_ref(),
+    )
+    .unwrap();
+    assert_eq!(cfg.version, 1);
+    assert_eq!(cfg.freeze_operator, tc.token.auth.pubkey());
+    assert_eq!(cfg.thaw_operator, tc.token.auth.pubkey());
+
+    // freeze/thaw keep working on unmigrated configs
+    let user = Keypair::new();
+    let user_token_account = tc.create_token_account(&user);
+    tc.thaw(&user_token_account);
+
+    let set_operator_ix = token_acl_client::instructions::SetFreezeOperatorBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .new_freeze_operator(user.pubkey())
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        std::slice::from_ref(&set_operator_ix),
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_err());
+
+    let res_err = res.err().unwrap();
+    assert_eq!(
+        res_err.err,
+        TransactionError::InstructionError(
+            0x00,
+            InstructionError::Custom(
+                token_acl_client::errors::TokenAclError::MintConfigMigrationRequired as u32
+            )
+        )
+    );
+
+    let migrate_ix = token_acl_client::instructions::MigrateConfigBuilder::new()
+        .payer(tc.token.auth.pubkey())
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[migrate_ix, set_operator_ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let account = tc.vm.get_account(&mint_cfg_pk).unwrap();
+    assert_eq!(
+        account.lamports,
+        tc.vm.minimum_balance_for_rent_exemption(account.data.len())
+    );
+
+    let cfg = token_acl_client::accounts::MintConfig::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(cfg.version, 2);
+    assert_eq!(cfg.mint, tc.token.mint);
+    assert_eq!(cfg.freeze_authority, tc.token.auth.pubkey());
+    assert_eq!(cfg.gating_program, program_test::AA_ID);
+    assert_eq!(cfg.freeze_operator, user.pubkey());
+    assert_eq!(cfg.thaw_operator, tc.token.auth.pubkey());
+}
@@ -0,0 +1,195 @@
+pub mod program_test;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+use spl_token_2022_interface::ID as TOKEN_PROGRAM_ID;
+use token_acl_client::{
+    parse_token_acl_events, ConfigCreated, FreezeReasonClosed, GatingProgramChanged,
+    TokenAccountFrozen, TokenAccountThawed, TokenAclEvent,
+};
+
+use crate::program_test::TestContext;
+
+#[test]
+fn test_config_events() {
+    let mut tc = TestContext::new();
+
+    let (mint_cfg_pk, _) = token_acl_client::accounts::MintConfig::find_pda(&tc.token.mint);
+
+    let ix = token_acl_client::instructions::CreateConfigBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .gating_program(program_test::AA_ID)
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .payer(tc.token.auth.pubkey())
+        .system_program(SYSTEM_PROGRAM_ID)
+        .token_program(TOKEN_PROGRAM_ID)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let events = parse_token_acl_events(&res.unwrap().logs).unwrap();
+    assert_eq!(
+        events,
+        vec![TokenAclEvent::ConfigCreated(ConfigCreated {
+            mint: tc.token.mint,
+            authority: tc.token.auth.pubkey(),
+            gating_program: program_test::AA_ID,
+        })]
+    );
+
+    let new_gating_program = Keypair::new().pubkey();
+
+    let ix = token_acl_client::instructions::SetGatingProgramBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .new_gating_program(new_gating_program)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let events = parse_token_acl_events(&res.unwrap().logs).unwrap();
+    assert_eq!(
+        events,
+        vec![TokenAclEvent::GatingProgramChanged(GatingProgramChanged {
+            mint: tc.token.mint,
+            authority: tc.token.auth.pubkey(),
+            old_gating_program: program_test::AA_ID,
+            new_gating_program,
+        })]
+    );
+}
+
+#[test]
+fn test_freeze_thaw_events() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    let user = Keypair::new();
+    let user_token_account = tc.create_token_account(&user);
+
+    let thaw_ix = token_acl_client::instructions::ThawBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .token_account(user_token_account)
+        .token_program(TOKEN_PROGRAM_ID)
+        .instruction();
+
+    let freeze_ix = token_acl_client::instructions::FreezeBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .mint(tc.token.mint)
+        .mint_config(mint_cfg_pk)
+        .token_account(user_token_account)
+        .token_program(TOKEN_PROGRAM_ID)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[thaw_ix, freeze_ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let events = parse_token_acl_events(&res.unwrap().logs).unwrap();
+    assert_eq!(
+        events,
+        vec![
+            TokenAclEvent::TokenAccountThawed(TokenAccountThawed {
+                mint: tc.token.mint,
+                token_account: user_token_account,
+                caller: tc.token.auth.pubkey(),
+                permissionless: false,
+            }),
+            TokenAclEvent::TokenAccountFrozen(TokenAccountFrozen {
+                mint: tc.token.mint,
+                token_account: user_token_account,
+                caller: tc.token.auth.pubkey(),
+                permissionless: false,
+            }),
+        ]
+    );
+}
+
+#[test]
+fn test_freeze_reason_closed_event() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    let user = Keypair::new();
+    let user_token_account = tc.create_token_account(&user);
+    tc.thaw(&user_token_account);
+
+    let freeze_reason_pk =
+        token_acl_client::accounts::FreezeReason::find_pda(&user_token_account).0;
+
+    let ix = token_acl_client::instructions::FreezeWithReasonBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .payer(tc.token.auth.pubkey())
+        .mint(tc.token.mint)
+        .token_account(user_token_account)
+        .mint_config(mint_cfg_pk)
+        .freeze_reason(freeze_reason_pk)
+        .token_program(TOKEN_PROGRAM_ID)
+        .reason_code(1)
+        .memo_hash([0; 32])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let ix = token_acl_client::create_thaw_instruction(
+        &tc.token.auth.pubkey(),
+        &tc.mint_config(),
+        &user_token_account,
+        &TOKEN_PROGRAM_ID,
+        Some(&tc.token.auth.pubkey()),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let events = parse_token_acl_events(&res.unwrap().logs).unwrap();
+    assert_eq!(
+        events,
+        vec![
+            TokenAclEvent::TokenAccountThawed(TokenAccountThawed {
+                mint: tc.token.mint,
+                token_account: user_token_account,
+                caller: tc.token.auth.pubkey(),
+                permissionless: false,
+            }),
+            TokenAclEvent::FreezeReasonClosed(FreezeReasonClosed {
+                mint: tc.token.mint,
+                token_account: user_token_account,
+                receiver: tc.token.auth.pubkey(),
+            }),
+        ]
+    );
+}
@@ -1,5 +1,6 @@
 pub mod program_test;
 use solana_instruction::AccountMeta;
+use solana_program_error::ProgramError;
 use solana_sdk::{
     instruction::InstructionError,
     signature::Keypair,
@@ -272,7 +273,10 @@ async fn test_freeze_permissionless_always_allow_with_deps() {
     let err = res.err().unwrap();
     assert_eq!(
         err.err,
-        TransactionError::InstructionError(0x00, InstructionError::NotEnoughAccountKeys)
+        TransactionError::InstructionError(
+            0x00,
+            InstructionError::from(u64::from(ProgramError::NotEnoughAccountKeys))
+        )
     );
 
     let ix = token_acl_client::instructions::FreezePermissionlessBuilder::new()
@@ -372,3 +376,81 @@ async fn test_freeze_permissionless_always_allow_with_deps() {
     //println!("account: {:?}", account);
     assert_eq!(account.base.state, AccountState::Frozen);
 }
+
+#[tokio::test]
+async fn test_freeze_permissionless_freeze_gating_program() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AB_ID);
+
+    tc.setup_ab_gate_extra_metas();
+    tc.setup_aa_gate_extra_metas();
+
+    let user = Keypair::new();
+    let user_pubkey = user.pubkey();
+    let user_token_account = tc.create_token_account(&user);
+
+    tc.thaw(&user_token_account);
+
+    let toggle_ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .freeze_enabled(true)
+        .thaw_enabled(false)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    // always-block keeps gating thaw, always-allow gates freeze
+    let set_freeze_gating_program_ix =
+        token_acl_client::instructions::SetFreezeGatingProgramBuilder::new()
+            .authority(tc.token.auth.pubkey())
+            .mint_config(mint_cfg_pk)
+            .new_freeze_gating_program(program_test::AA_ID)
+            .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[toggle_ix, set_freeze_gating_program_ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let cfg = tc.vm.get_account(&mint_cfg_pk).unwrap();
+    let cfg = token_acl_client::accounts::MintConfig::from_bytes(&cfg.data).unwrap();
+    assert_eq!(cfg.gating_program, program_test::AB_ID);
+    assert_eq!(cfg.freeze_gating_program, program_test::AA_ID);
+
+    let ix = token_acl_client::create_freeze_permissionless_instruction_with_extra_metas(
+        &user_pubkey,
+        &user_token_account,
+        &tc.token.mint,
+        &mint_cfg_pk,
+        &TOKEN_PROGRAM_ID,
+        &user_pubkey,
+        false,
+        |pubkey| {
+            let acc = tc.vm.get_account(&pubkey);
+            async move {
+                match acc {
+                    Some(a) => Ok(Some(a.data)),
+                    None => Ok(None),
+                }
+            }
+        },
+    )
+    .await
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&user_pubkey),
+        &[user.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let token_account_data = tc.vm.get_account(&user_token_account).unwrap().data;
+    let account = StateWithExtensions::<Account>::unpack(token_account_data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Frozen);
+}
@@ -0,0 +1,187 @@
+pub mod program_test;
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use spl_tlv_account_resolution::account::ExtraAccountMeta;
+use token_acl_interface::{
+    get_thaw_extra_account_metas_address,
+    instruction::{initialize_extra_account_metas, update_extra_account_metas},
+    PermissionlessOperation,
+};
+
+use crate::program_test::TestContext;
+
+fn send(
+    tc: &mut TestContext,
+    ix: solana_instruction::Instruction,
+    signer: &Keypair,
+) -> Result<(), TransactionError> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    tc.vm
+        .send_transaction(tx)
+        .map(|_| ())
+        .map_err(|err| err.err)
+}
+
+#[test]
+fn test_gate_initialize_extra_metas_requires_mint_authority() {
+    let mut tc = TestContext::new();
+
+    let other = Keypair::new();
+    tc.vm.airdrop(&other.pubkey(), 1_000_000_000).unwrap();
+
+    let ix = tc.get_setup_extra_metas_ix(&other.pubkey(), &program_test::AA_WD_ID);
+    assert_eq!(
+        send(&mut tc, ix, &other),
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::InvalidAccountData
+        ))
+    );
+
+    let auth = tc.token.auth.insecure_clone();
+    let ix = tc.get_setup_extra_metas_ix(&auth.pubkey(), &program_test::AA_WD_ID);
+    assert_eq!(send(&mut tc, ix, &auth), Ok(()));
+
+    let thaw_extra_metas =
+        get_thaw_extra_account_metas_address(&tc.token.mint, &program_test::AA_WD_ID);
+    let account = tc.vm.get_account(&thaw_extra_metas).unwrap();
+    assert_eq!(account.owner, program_test::AA_WD_ID);
+}
+
+#[test]
+fn test_gate_initialize_extra_metas_spl_token() {
+    let mut tc = TestContext::new_spl_token();
+
+    let auth = tc.token.auth.insecure_clone();
+    let ix = tc.get_setup_extra_metas_ix(&auth.pubkey(), &program_test::AA_ID);
+    assert_eq!(send(&mut tc, ix, &auth), Ok(()));
+}
+
+#[test]
+fn test_gate_initialize_extra_metas_invalid_mint() {
+    let mut tc = TestContext::new();
+    tc.token.mint = Pubkey::new_unique();
+
+    let auth = tc.token.auth.insecure_clone();
+    let ix = tc.get_setup_extra_metas_ix(&auth.pubkey(), &program_test::AA_ID);
+    assert_eq!(
+        send(&mut tc, ix, &auth),
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::IncorrectProgramId
+        ))
+    );
+}
+
+#[test]
+fn test_gate_set_extra_account_metas() {
+    let mut tc = TestContext::new();
+    let auth = tc.token.auth.insecure_clone();
+    let mint = tc.token.mint;
+
+    let metas = [ExtraAccountMeta::new_with_pubkey(&Pubkey::new_unique(), false, false).unwrap()];
+
+    let other = Keypair::new();
+    tc.vm.airdrop(&other.pubkey(), 1_000_000_000).unwrap();
+    let ix = initialize_extra_account_metas(
+        &program_test::AA_ID,
+        &mint,
+        &other.pubkey(),
+        &other.pubkey(),
+        PermissionlessOperation::Thaw,
+        &metas,
+    );
+    assert_eq!(
+        send(&mut tc, ix, &other),
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::InvalidAccountData
+        ))
+    );
+
+    let ix = initialize_extra_account_metas(
+        &program_test::AA_ID,
+        &mint,
+        &auth.pubkey(),
+        &auth.pubkey(),
+        PermissionlessOperation::Thaw,
+        &metas,
+    );
+    assert_eq!(send(&mut tc, ix, &auth), Ok(()));
+
+    let thaw_extra_metas = get_thaw_extra_account_metas_address(&mint, &program_test::AA_ID);
+    let len = tc.vm.get_account(&thaw_extra_metas).unwrap().data.len();
+
+    let metas = [
+        metas[0],
+        ExtraAccountMeta::new_with_pubkey(&Pubkey::new_unique(), false, false).unwrap(),
+    ];
+    let ix = update_extra_account_metas(
+        &program_test::AA_ID,
+        &mint,
+        &auth.pubkey(),
+        &auth.pubkey(),
+        PermissionlessOperation::Thaw,
+        &metas,
+    );
+    assert_eq!(send(&mut tc, ix, &auth), Ok(()));
+
+    let account = tc.vm.get_account(&thaw_extra_metas).unwrap();
+    assert_eq!(
+        account.data.len(),
+        len + std::mem::size_of::<ExtraAccountMeta>()
+    );
+}
+
+#[test]
+fn test_gate_rejects_invalid_flag_account() {
+    let mut tc = TestContext::new();
+    tc.setup_token_acl(&program_test::AA_ID);
+
+    let user = Keypair::new();
+    let user_token_account = tc.create_token_account(&user);
+
+    // the gate is called directly, the flag account was never set by Token ACL
+    let flag_account = token_acl_client::accounts::FlagAccount::find_pda(&user_token_account).0;
+    let ix = token_acl_interface::instruction::can_thaw_permissionless(
+        &program_test::AA_ID,
+        &user.pubkey(),
+        &user_token_account,
+        &tc.token.mint,
+        &user.pubkey(),
+        &flag_account,
+    );
+    assert_eq!(
+        send(&mut tc, ix, &user),
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::IllegalOwner
+        ))
+    );
+
+    let ix = token_acl_interface::instruction::can_thaw_permissionless(
+        &program_test::AA_ID,
+        &user.pubkey(),
+        &user_token_account,
+        &tc.token.mint,
+        &user.pubkey(),
+        &Pubkey::new_unique(),
+    );
+    assert_eq!(
+        send(&mut tc, ix, &user),
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::InvalidSeeds
+        ))
+    );
+}
@@ -1,5 +1,6 @@
 use litesvm::LiteSVM;
 use solana_instruction::{AccountMeta, Instruction};
+use solana_program_pack::Pack;
 use solana_pubkey::Pubkey;
 use solana_sdk::transaction::Transaction;
 use solana_sdk::{signature::Keypair, signer::Signer};
@@ -12,6 +13,7 @@ use spl_token_2022_interface::{
         default_account_state::instruction::initialize_default_account_state,
         metadata_pointer::instruction::initialize, ExtensionType,
     },
+    inline_spl_token::ID as SPL_TOKEN_PROGRAM_ID,
     instruction::{initialize_mint2, initialize_mint_close_authority},
     state::{AccountState, Mint},
     ID as TOKEN_PROGRAM_ID,
@@ -31,6 +33,7 @@ pub struct TestContext {
 pub struct TokenContext {
     pub mint: Pubkey,
     pub auth: Keypair,
+    pub token_program: Pubkey,
 }
 
 impl Default for TestContext {
@@ -41,6 +44,18 @@ impl Default for TestContext {
 
 impl TestContext {
     pub fn new() -> Self {
+        Self::new_with_token_acl_fixture("token_acl.so")
+    }
+
+    /// Same as `new`, with a SPL Token mint instead of a Token-2022 one.
+    pub fn new_spl_token() -> Self {
+        let mut tc = Self::new();
+        tc.token = Self::create_spl_token(&mut tc.vm);
+        tc
+    }
+
+    /// Same as `new`, loading the Token ACL program from another build in `tests/fixtures`.
+    pub fn new_with_token_acl_fixture(fixture: &str) -> Self {
         let mut vm = LiteSVM::new();
 
         // current path
@@ -48,7 +63,7 @@ impl TestContext {
 
         let res = vm.add_program_from_file(
             token_acl_client::programs::TOKEN_ACL_ID,
-            current_dir.join("tests/fixtures/token_acl.so"),
+            current_dir.join("tests/fixtures").join(fixture),
         );
         assert!(res.is_ok());
 
@@ -80,6 +95,11 @@ impl TestContext {
     }
 
     pub fn create_token(vm: &mut LiteSVM) -> TokenContext {
+        Self::create_token_with_mint_keypair(vm, Keypair::new())
+    }
+
+    /// Same as `create_token`, at the address of `mint_kp`.
+    pub fn create_token_with_mint_keypair(vm: &mut LiteSVM, mint_kp: Keypair) -> TokenContext {
         let auth = Keypair::new();
         let auth_pubkey = auth.pubkey();
 
@@ -92,7 +112,6 @@ impl TestContext {
             ExtensionType::MetadataPointer,
         ])
         .unwrap();
-        let mint_kp = Keypair::new();
         let mint_pk = mint_kp.pubkey();
         let token_program_id = &TOKEN_PROGRAM_ID;
         let payer_pk = auth.pubkey();
@@ -148,15 +167,126 @@ impl TestContext {
         TokenContext {
             mint: mint_pk,
             auth,
+            token_program: TOKEN_PROGRAM_ID,
+        }
+    }
+
+    /// Token-2022 mint whose metadata pointer points to `metadata`, without metadata in the
+    /// mint itself.
+    pub fn create_token_with_external_metadata(
+        vm: &mut LiteSVM,
+        metadata: &Pubkey,
+    ) -> TokenContext {
+        let auth = Keypair::new();
+        let auth_pubkey = auth.pubkey();
+
+        let res = vm.airdrop(&auth_pubkey, 1_000_000_000_000);
+        assert!(res.is_ok());
+
+        let mint_size = ExtensionType::try_calculate_account_len::<Mint>(&[
+            ExtensionType::DefaultAccountState,
+            ExtensionType::MetadataPointer,
+        ])
+        .unwrap();
+        let mint_kp = Keypair::new();
+        let mint_pk = mint_kp.pubkey();
+        let token_program_id = &TOKEN_PROGRAM_ID;
+
+        let ix1 = create_account(
+            &auth_pubkey,
+            &mint_pk,
+            vm.minimum_balance_for_rent_exemption(mint_size),
+            mint_size as u64,
+            token_program_id,
+        );
+
+        let ix2 =
+            initialize_default_account_state(token_program_id, &mint_pk, &AccountState::Frozen)
+                .unwrap();
+
+        let ix3 = initialize(
+            token_program_id,
+            &mint_pk,
+            Some(auth_pubkey),
+            Some(*metadata),
+        )
+        .unwrap();
+
+        let ix4 = initialize_mint2(
+            token_program_id,
+            &mint_pk,
+            &auth_pubkey,
+            Some(&auth_pubkey),
+            6,
+        )
+        .unwrap();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix1, ix2, ix3, ix4],
+            Some(&auth_pubkey),
+            &[auth.insecure_clone(), mint_kp],
+            vm.latest_blockhash(),
+        );
+        let res = vm.send_transaction(tx);
+        assert!(res.is_ok());
+
+        TokenContext {
+            mint: mint_pk,
+            auth,
+            token_program: *token_program_id,
+        }
+    }
+
+    pub fn create_spl_token(vm: &mut LiteSVM) -> TokenContext {
+        let auth = Keypair::new();
+        let auth_pubkey = auth.pubkey();
+
+        let res = vm.airdrop(&auth_pubkey, 1_000_000_000_000);
+        assert!(res.is_ok());
+
+        let mint_kp = Keypair::new();
+        let mint_pk = mint_kp.pubkey();
+        let token_program_id = &SPL_TOKEN_PROGRAM_ID;
+
+        let ix1 = create_account(
+            &auth_pubkey,
+            &mint_pk,
+            vm.minimum_balance_for_rent_exemption(Mint::LEN),
+            Mint::LEN as u64,
+            token_program_id,
+        );
+
+        let ix2 = initialize_mint2(
+            token_program_id,
+            &mint_pk,
+            &auth_pubkey,
+            Some(&auth_pubkey),
+            6,
+        )
+        .unwrap();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix1, ix2],
+            Some(&auth_pubkey),
+            &[auth.insecure_clone(), mint_kp],
+            vm.latest_blockhash(),
+        );
+        let res = vm.send_transaction(tx);
+        assert!(res.is_ok());
+
+        TokenContext {
+            mint: mint_pk,
+            auth,
+            token_program: *token_program_id,
         }
     }
 
     fn create_token_account_with_params(
         vm: &mut LiteSVM,
         mint: &Pubkey,
+        token_program_id: &Pubkey,
         owner: &Keypair,
     ) -> Pubkey {
-        let token_program_id = &TOKEN_PROGRAM_ID;
         let payer_pk = owner.pubkey();
 
         let res = vm.airdrop(&payer_pk, 1_000_000_000);
@@ -182,7 +312,12 @@ impl TestContext {
     }
 
     pub fn create_token_account(&mut self, owner: &Keypair) -> Pubkey {
-        Self::create_token_account_with_params(&mut self.vm, &self.token.mint, owner)
+        Self::create_token_account_with_params(
+            &mut self.vm,
+            &self.token.mint,
+            &self.token.token_program,
+            owner,
+        )
     }
 
     pub fn get_setup_extra_metas_ix(&self, payer: &Pubkey, gating_program: &Pubkey) -> Instruction {
@@ -221,14 +356,21 @@ impl TestContext {
             .mint_config(mint_cfg_pk)
             .payer(self.token.auth.pubkey())
             .system_program(ID)
-            .token_program(TOKEN_PROGRAM_ID)
+            .token_program(self.token.token_program)
             .instruction();
 
-        let set_metadata_ix =
-            set_mint_tacl_metadata_ix(&self.token.mint, &self.token.auth.pubkey(), gating_program);
+        let mut instructions = vec![ix];
+        // SPL Token mints have no metadata
+        if self.token.token_program == TOKEN_PROGRAM_ID {
+            instructions.push(set_mint_tacl_metadata_ix(
+                &self.token.mint,
+                &self.token.auth.pubkey(),
+                gating_program,
+            ));
+        }
 
         let tx = Transaction::new_signed_with_payer(
-            &[ix, set_metadata_ix],
+            &instructions,
             Some(&self.token.auth.pubkey()),
             &[self.token.auth.insecure_clone()],
             self.vm.latest_blockhash(),
@@ -242,7 +384,7 @@ impl TestContext {
 
     pub fn close_mint(&mut self) {
         let ix = spl_token_2022_interface::instruction::close_account(
-            &TOKEN_PROGRAM_ID,
+            &self.token.token_program,
             &self.token.mint,
             &self.token.auth.pubkey(),
             &self.token.auth.pubkey(),
@@ -304,14 +446,22 @@ impl TestContext {
         assert!(res.is_ok());
     }
 
+    pub fn mint_config(&self) -> token_acl_client::accounts::MintConfig {
+        let mint_config_pk = token_acl_client::accounts::MintConfig::find_pda(&self.token.mint).0;
+        token_acl_client::accounts::MintConfig::from_account_data(
+            self.vm.get_account(&mint_config_pk).unwrap().data.as_ref(),
+        )
+        .unwrap()
+    }
+
     pub fn thaw(&mut self, token_account: &Pubkey) {
-        let ix = token_acl_client::instructions::ThawBuilder::new()
-            .authority(self.token.auth.pubkey())
-            .mint(self.token.mint)
-            .mint_config(token_acl_client::accounts::MintConfig::find_pda(&self.token.mint).0)
-            .token_account(*token_account)
-            .token_program(TOKEN_PROGRAM_ID)
-            .instruction();
+        let ix = token_acl_client::create_thaw_instruction(
+            &self.token.auth.pubkey(),
+            &self.mint_config(),
+            token_account,
+            &self.token.token_program,
+            None,
+        );
 
         let tx = Transaction::new_signed_with_payer(
             &[ix],
@@ -329,7 +479,7 @@ impl TestContext {
             .mint(self.token.mint)
             .mint_config(token_acl_client::accounts::MintConfig::find_pda(&self.token.mint).0)
             .token_account(*token_account)
-            .token_program(TOKEN_PROGRAM_ID)
+            .token_program(self.token.token_program)
             .instruction();
 
         let tx = Transaction::new_signed_with_payer(
@@ -1,5 +1,7 @@
 pub mod program_test;
-use solana_instruction::AccountMeta;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
 use solana_sdk::{
     instruction::InstructionError,
     program_option::COption,
@@ -19,6 +21,8 @@ use spl_token_2022_interface::{
     ID as TOKEN_PROGRAM_ID,
 };
 
+use token_acl_client::SimulationOutcome;
+
 use crate::program_test::TestContext;
 
 #[test]
@@ -308,6 +312,80 @@ async fn test_create_ata_and_thaw_permissionless() {
     assert!(res.is_ok());
 }
 
+#[tokio::test]
+async fn test_create_ata_and_thaw_permissionless_single_instruction() {
+    let mut tc = TestContext::new();
+
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_WD_ID);
+
+    tc.setup_aa_wd_gate_extra_metas();
+
+    let ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .freeze_enabled(false)
+        .thaw_enabled(true)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let user = Keypair::new();
+    let user_pubkey = user.pubkey();
+
+    let res = tc.vm.airdrop(&user.pubkey(), 1_000_000_000);
+    assert!(res.is_ok());
+
+    let ix = token_acl_client::create_ata_and_thaw_permissionless_instruction_with_extra_metas(
+        &user_pubkey,
+        &tc.token.mint,
+        &TOKEN_PROGRAM_ID,
+        &user_pubkey,
+        &|pubkey| {
+            let data = tc.vm.get_account(&pubkey);
+            async move { Ok(data.map(|a| a.data.clone())) }
+        },
+    )
+    .await
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        std::slice::from_ref(&ix),
+        Some(&user_pubkey),
+        &[user.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let token_account = get_associated_token_address_with_program_id(
+        &user_pubkey,
+        &tc.token.mint,
+        &TOKEN_PROGRAM_ID,
+    );
+    let token_account_data = tc.vm.get_account(&token_account).unwrap().data;
+    let account = StateWithExtensions::<Account>::unpack(token_account_data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Initialized);
+
+    // the account exists and is thawed, so it's a no-op
+    tc.vm.expire_blockhash();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&user_pubkey),
+        &[user.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+}
+
 #[tokio::test]
 async fn test_thaw_permissionless_always_block() {
     let mut tc = TestContext::new();
@@ -458,7 +536,10 @@ async fn test_thaw_permissionless_always_allow_with_deps() {
     let err = res.err().unwrap();
     assert_eq!(
         err.err,
-        TransactionError::InstructionError(0x00, InstructionError::NotEnoughAccountKeys)
+        TransactionError::InstructionError(
+            0x00,
+            InstructionError::from(u64::from(ProgramError::NotEnoughAccountKeys))
+        )
     );
 
     let ix = token_acl_client::instructions::ThawPermissionlessBuilder::new()
@@ -561,3 +642,433 @@ async fn test_thaw_permissionless_always_allow_with_deps() {
     //println!("account: {:?}", account);
     assert_eq!(account.base.state, AccountState::Initialized);
 }
+
+fn simulate(tc: &TestContext, payer: &Keypair, ix: Instruction) -> SimulationOutcome {
+    let cb = solana_compute_budget_interface::ComputeBudgetInstruction::set_compute_unit_limit(
+        1_400_000,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[cb, ix],
+        Some(&payer.pubkey()),
+        &[payer.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    match tc.vm.simulate_transaction(tx) {
+        Ok(_) => SimulationOutcome::Success,
+        Err(failed) => SimulationOutcome::from_failed_simulation(
+            failed.err,
+            Some((
+                &failed.meta.return_data.program_id,
+                &failed.meta.return_data.data,
+            )),
+        ),
+    }
+}
+
+#[tokio::test]
+async fn test_thaw_permissionless_with_simulation() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_WD_ID);
+
+    tc.setup_aa_wd_gate_extra_metas();
+
+    let user = Keypair::new();
+    let user_pubkey = user.pubkey();
+    let user_token_account = tc.create_token_account(&user);
+
+    let toggle_ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .freeze_enabled(false)
+        .thaw_enabled(true)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+    let fee_ix = token_acl_client::create_set_permissionless_fee_instruction(
+        &tc.token.auth.pubkey(),
+        &tc.token.auth.pubkey(),
+        &tc.token.mint,
+        1_000,
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[toggle_ix, fee_ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let cb = solana_compute_budget_interface::ComputeBudgetInstruction::set_compute_unit_limit(
+        1_400_000,
+    );
+
+    // the gate reads the owner from the token account, which can't be fetched yet
+    let fetch_account_data_fn = |pubkey: Pubkey| {
+        let acc = tc.vm.get_account(&pubkey);
+        async move { Ok(acc.filter(|_| pubkey != user_token_account).map(|a| a.data)) }
+    };
+
+    let res = token_acl_client::create_thaw_permissionless_instruction_with_extra_metas(
+        &user_pubkey,
+        &user_token_account,
+        &tc.token.mint,
+        &mint_cfg_pk,
+        &TOKEN_PROGRAM_ID,
+        &user_pubkey,
+        false,
+        fetch_account_data_fn,
+    )
+    .await;
+    assert!(res.is_err());
+
+    let ix = token_acl_client::create_thaw_permissionless_instruction_with_simulation(
+        &user_pubkey,
+        &user_token_account,
+        &tc.token.mint,
+        &mint_cfg_pk,
+        &TOKEN_PROGRAM_ID,
+        &user_pubkey,
+        false,
+        fetch_account_data_fn,
+        |ix| {
+            let outcome = simulate(&tc, &user, ix);
+            async move { outcome }
+        },
+    )
+    .await
+    .unwrap();
+
+    // the simulated accounts end up where the off-chain resolver puts them, ahead of the
+    // fee vault
+    let expected = token_acl_client::create_thaw_permissionless_instruction_with_extra_metas(
+        &user_pubkey,
+        &user_token_account,
+        &tc.token.mint,
+        &mint_cfg_pk,
+        &TOKEN_PROGRAM_ID,
+        &user_pubkey,
+        false,
+        |pubkey| {
+            let acc = tc.vm.get_account(&pubkey);
+            async move { Ok(acc.map(|a| a.data)) }
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(ix.accounts, expected.accounts);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[cb, ix],
+        Some(&user_pubkey),
+        &[user.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let token_account_data = tc.vm.get_account(&user_token_account).unwrap().data;
+    let account = StateWithExtensions::<Account>::unpack(token_account_data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Initialized);
+}
+
+#[tokio::test]
+async fn test_thaw_permissionless_program_accounts_after_gate_accounts() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_WD_ID);
+
+    tc.setup_aa_wd_gate_extra_metas();
+
+    let user = Keypair::new();
+    let user_pubkey = user.pubkey();
+    let user_token_account = tc.create_token_account(&user);
+
+    let toggle_ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .freeze_enabled(false)
+        .thaw_enabled(true)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+    let sticky_ix = token_acl_client::instructions::SetStickyFreezeBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .mint_config(mint_cfg_pk)
+        .sticky_freeze(true)
+        .instruction();
+    let fee_ix = token_acl_client::create_set_permissionless_fee_instruction(
+        &tc.token.auth.pubkey(),
+        &tc.token.auth.pubkey(),
+        &tc.token.mint,
+        1_000,
+    );
+    let ttl_ix = token_acl_client::create_set_thaw_ttl_instruction(
+        &tc.token.auth.pubkey(),
+        &tc.token.mint,
+        100,
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[toggle_ix, sticky_ix, fee_ix, ttl_ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let cb = solana_compute_budget_interface::ComputeBudgetInstruction::set_compute_unit_limit(
+        1_400_000,
+    );
+    let ix = token_acl_client::create_thaw_permissionless_instruction_with_extra_metas(
+        &user_pubkey,
+        &user_token_account,
+        &tc.token.mint,
+        &mint_cfg_pk,
+        &TOKEN_PROGRAM_ID,
+        &user_pubkey,
+        false,
+        |pubkey| {
+            let acc = tc.vm.get_account(&pubkey);
+            async move { Ok(acc.map(|a| a.data)) }
+        },
+    )
+    .await
+    .unwrap();
+
+    // the gate accounts stay right after the gating program, where sRFC-37 resolvers put
+    // them, and Token ACL's own accounts follow them
+    assert_eq!(
+        ix.accounts[9].pubkey,
+        token_acl_interface::get_thaw_extra_account_metas_address(
+            &tc.token.mint,
+            &program_test::AA_WD_ID,
+        )
+    );
+    let tail: Vec<_> = ix.accounts[ix.accounts.len() - 3..]
+        .iter()
+        .map(|meta| meta.pubkey)
+        .collect();
+    assert_eq!(
+        tail,
+        vec![
+            token_acl_client::find_fee_vault_pda(&tc.token.mint).0,
+            token_acl_client::accounts::ThawExpiry::find_pda(&user_token_account).0,
+            token_acl_client::accounts::FreezeReason::find_pda(&user_token_account).0,
+        ]
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[cb, ix],
+        Some(&user_pubkey),
+        &[user.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let token_account_data = tc.vm.get_account(&user_token_account).unwrap().data;
+    let account = StateWithExtensions::<Account>::unpack(token_account_data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Initialized);
+}
+
+#[tokio::test]
+async fn test_thaw_permissionless_gating_chain() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_ID);
+
+    tc.setup_ab_gate_extra_metas();
+    tc.setup_aa_gate_extra_metas();
+
+    let user = Keypair::new();
+    let user_pubkey = user.pubkey();
+    let user_token_account = tc.create_token_account(&user);
+
+    let toggle_ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .freeze_enabled(false)
+        .thaw_enabled(true)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+
+    // always-allow AND always-block
+    let chain_ix = token_acl_client::create_set_gating_chain_instruction(
+        &tc.token.auth.pubkey(),
+        &tc.token.mint,
+        &[program_test::AB_ID],
+        token_acl_client::GateMode::All,
+        token_acl_client::GateMode::All,
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[toggle_ix, chain_ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let ix = token_acl_client::create_thaw_permissionless_instruction_with_extra_metas(
+        &user_pubkey,
+        &user_token_account,
+        &tc.token.mint,
+        &mint_cfg_pk,
+        &TOKEN_PROGRAM_ID,
+        &user_pubkey,
+        false,
+        |pubkey| {
+            let acc = tc.vm.get_account(&pubkey);
+            async move {
+                match acc {
+                    Some(a) => Ok(Some(a.data)),
+                    None => Ok(None),
+                }
+            }
+        },
+    )
+    .await
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&user_pubkey),
+        &[user.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_err());
+    let err = res.err().unwrap();
+    assert_eq!(
+        err.err,
+        TransactionError::InstructionError(0x00, InstructionError::Custom(999999999))
+    );
+
+    // always-allow OR always-block
+    let chain_ix = token_acl_client::create_set_gating_chain_instruction(
+        &tc.token.auth.pubkey(),
+        &tc.token.mint,
+        &[program_test::AB_ID],
+        token_acl_client::GateMode::Any,
+        token_acl_client::GateMode::All,
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[chain_ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let ix = token_acl_client::create_thaw_permissionless_instruction_with_extra_metas(
+        &user_pubkey,
+        &user_token_account,
+        &tc.token.mint,
+        &mint_cfg_pk,
+        &TOKEN_PROGRAM_ID,
+        &user_pubkey,
+        false,
+        |pubkey| {
+            let acc = tc.vm.get_account(&pubkey);
+            async move {
+                match acc {
+                    Some(a) => Ok(Some(a.data)),
+                    None => Ok(None),
+                }
+            }
+        },
+    )
+    .await
+    .unwrap();
+
+    tc.vm.expire_blockhash();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&user_pubkey),
+        &[user.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let token_account_data = tc.vm.get_account(&user_token_account).unwrap().data;
+    let account = StateWithExtensions::<Account>::unpack(token_account_data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Initialized);
+}
+
+#[tokio::test]
+async fn test_thaw_permissionless_gating_chain_with_simulation() {
+    let mut tc = TestContext::new();
+    let mint_cfg_pk = tc.setup_token_acl(&program_test::AA_WD_ID);
+
+    tc.setup_aa_wd_gate_extra_metas();
+    tc.setup_aa_gate_extra_metas();
+
+    let user = Keypair::new();
+    let user_pubkey = user.pubkey();
+    let user_token_account = tc.create_token_account(&user);
+
+    let toggle_ix = token_acl_client::instructions::TogglePermissionlessInstructionsBuilder::new()
+        .authority(tc.token.auth.pubkey())
+        .freeze_enabled(false)
+        .thaw_enabled(true)
+        .mint_config(mint_cfg_pk)
+        .instruction();
+    // the gate resolved by simulation comes first, its accounts have to be counted right for
+    // the next gate to find its own
+    let chain_ix = token_acl_client::create_set_gating_chain_instruction(
+        &tc.token.auth.pubkey(),
+        &tc.token.mint,
+        &[program_test::AA_ID],
+        token_acl_client::GateMode::All,
+        token_acl_client::GateMode::All,
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[toggle_ix, chain_ix],
+        Some(&tc.token.auth.pubkey()),
+        &[tc.token.auth.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let ix = token_acl_client::create_thaw_permissionless_instruction_with_simulation(
+        &user_pubkey,
+        &user_token_account,
+        &tc.token.mint,
+        &mint_cfg_pk,
+        &TOKEN_PROGRAM_ID,
+        &user_pubkey,
+        false,
+        |pubkey: Pubkey| {
+            let acc = tc.vm.get_account(&pubkey);
+            async move { Ok(acc.filter(|_| pubkey != user_token_account).map(|a| a.data)) }
+        },
+        |ix| {
+            let outcome = simulate(&tc, &user, ix);
+            async move { outcome }
+        },
+    )
+    .await
+    .unwrap();
+
+    let cb = solana_compute_budget_interface::ComputeBudgetInstruction::set_compute_unit_limit(
+        1_400_000,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[cb, ix],
+        Some(&user_pubkey),
+        &[user.insecure_clone()],
+        tc.vm.latest_blockhash(),
+    );
+    let res = tc.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let token_account_data = tc.vm.get_account(&user_token_account).unwrap().data;
+    let account = StateWithExtensions::<Account>::unpack(token_account_data.as_ref()).unwrap();
+    assert_eq!(account.base.state, AccountState::Initialized);
+}
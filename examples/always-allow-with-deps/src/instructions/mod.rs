@@ -1,5 +0,0 @@
-mod can_thaw_freeze_permissionless;
-mod initialize_extra_metas;
-
-pub use can_thaw_freeze_permissionless::*;
-pub use initialize_extra_metas::*;
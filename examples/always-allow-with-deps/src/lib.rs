@@ -1,47 +1,107 @@
-use solana_program::{
-    account_info::AccountInfo, declare_id, entrypoint, entrypoint::ProgramResult, pubkey::Pubkey,
-};
+use solana_program::{declare_id, entrypoint::ProgramResult, pubkey::Pubkey};
 use solana_program_error::ProgramError;
-use spl_discriminator::{ArrayDiscriminator, SplDiscriminate};
-use token_acl_interface::instruction::{
-    CanFreezePermissionlessInstruction, CanThawPermissionlessInstruction,
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, pubkey_data::PubkeyData, seeds::Seed};
+use token_acl_interface::{
+    gate::{GateAccounts, GateProgram},
+    FREEZE_EXTRA_ACCOUNT_METAS_SEED, THAW_EXTRA_ACCOUNT_METAS_SEED,
 };
 
-pub mod instructions;
-pub use instructions::*;
-
 declare_id!("Eba1ts11111111111111111111111111111111111114");
 
-entrypoint!(process_instruction);
-fn process_instruction<'a>(
-    _program_id: &Pubkey,
-    accounts: &'a [AccountInfo<'a>],
-    instruction_data: &'a [u8],
-) -> ProgramResult {
-    let (discriminator, _remaining) = instruction_data.split_at(ArrayDiscriminator::LENGTH);
-
-    match discriminator {
-        InitializeExtraMetas::DISCRIMINATOR_SLICE => {
-            InitializeExtraMetas::try_from(accounts)?.process()
-        }
-        CanThawPermissionlessInstruction::SPL_DISCRIMINATOR_SLICE => {
-            CanThawFreezePermissionless::try_from(accounts)?.process()
-        }
-        CanFreezePermissionlessInstruction::SPL_DISCRIMINATOR_SLICE => {
-            CanThawFreezePermissionless::try_from(accounts)?.process()
-        }
-        _ => Err(CustomErrors::InvalidInstruction.into()),
+/// Allows every permissionless thaw and freeze, but only after checking a set of extra
+/// accounts resolved from its extra account metas.
+pub struct AlwaysAllowWithDeps;
+
+impl GateProgram for AlwaysAllowWithDeps {
+    fn thaw_extra_account_metas(_mint: &Pubkey) -> Result<Vec<ExtraAccountMeta>, ProgramError> {
+        extra_account_metas(THAW_EXTRA_ACCOUNT_METAS_SEED)
+    }
+
+    fn freeze_extra_account_metas(_mint: &Pubkey) -> Result<Vec<ExtraAccountMeta>, ProgramError> {
+        extra_account_metas(FREEZE_EXTRA_ACCOUNT_METAS_SEED)
+    }
+
+    fn can_thaw_permissionless(accounts: &GateAccounts) -> ProgramResult {
+        check_extra_accounts(accounts)
+    }
+
+    fn can_freeze_permissionless(accounts: &GateAccounts) -> ProgramResult {
+        check_extra_accounts(accounts)
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum CustomErrors {
-    InvalidInstruction,
-    UnsupportedInstruction,
+token_acl_interface::gate_entrypoint!(AlwaysAllowWithDeps);
+
+fn extra_account_metas(extra_metas_seed: &[u8]) -> Result<Vec<ExtraAccountMeta>, ProgramError> {
+    Ok(vec![
+        // [6] associated token program
+        ExtraAccountMeta::new_with_pubkey(
+            &spl_associated_token_account_interface::program::ID,
+            false,
+            false,
+        )?,
+        // [7] token program
+        ExtraAccountMeta::new_with_pubkey(&spl_token_2022_interface::ID, false, false)?,
+        // [8] token account owner
+        ExtraAccountMeta::new_with_pubkey_data(
+            &PubkeyData::AccountData {
+                account_index: 1,
+                data_index: 32,
+            },
+            false,
+            false,
+        )?,
+        // [9] ata
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            6,
+            &[
+                Seed::AccountKey { index: 3 }, // owner
+                Seed::AccountKey { index: 7 }, // token program
+                Seed::AccountKey { index: 2 }, // mint
+            ],
+            false,
+            false,
+        )?,
+        // [10] extra metas account
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: extra_metas_seed.to_vec(),
+                },
+                Seed::AccountKey { index: 2 }, // mint
+            ],
+            false,
+            false,
+        )?,
+    ])
 }
 
-impl From<CustomErrors> for ProgramError {
-    fn from(e: CustomErrors) -> Self {
-        ProgramError::Custom(e as u32)
+fn check_extra_accounts(accounts: &GateAccounts) -> ProgramResult {
+    let [extra_metas, associated_token_program, token_program, token_account_owner, ata, extra_metas_again] =
+        accounts.extra_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if ata.key != accounts.token_account.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if extra_metas.key != extra_metas_again.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if token_program.key != &spl_token_2022_interface::ID {
+        return Err(ProgramError::IncorrectProgramId);
     }
+
+    if associated_token_program.key != &spl_associated_token_account_interface::program::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if token_account_owner.key != accounts.token_account_owner.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
 }
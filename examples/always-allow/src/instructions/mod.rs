@@ -1,3 +0,0 @@
-mod initialize_extra_metas;
-
-pub use initialize_extra_metas::*;
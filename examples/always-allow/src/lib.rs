@@ -1,43 +1,18 @@
-use solana_program::{
-    account_info::AccountInfo, declare_id, entrypoint, entrypoint::ProgramResult, pubkey::Pubkey,
-};
-use solana_program_error::ProgramError;
-use spl_discriminator::{ArrayDiscriminator, SplDiscriminate};
-use token_acl_interface::instruction::{
-    CanFreezePermissionlessInstruction, CanThawPermissionlessInstruction,
-};
-
-pub mod instructions;
-pub use instructions::*;
+use solana_program::{declare_id, entrypoint::ProgramResult};
+use token_acl_interface::gate::{GateAccounts, GateProgram};
 
 declare_id!("Eba1ts11111111111111111111111111111111111112");
 
-entrypoint!(process_instruction);
-fn process_instruction<'a>(
-    _program_id: &Pubkey,
-    accounts: &'a [AccountInfo<'a>],
-    instruction_data: &'a [u8],
-) -> ProgramResult {
-    let (discriminator, _remaining) = instruction_data.split_at(ArrayDiscriminator::LENGTH);
+pub struct AlwaysAllow;
 
-    match discriminator {
-        InitializeExtraMetas::DISCRIMINATOR_SLICE => {
-            InitializeExtraMetas::try_from(accounts)?.process()
-        }
-        CanThawPermissionlessInstruction::SPL_DISCRIMINATOR_SLICE => Ok(()),
-        CanFreezePermissionlessInstruction::SPL_DISCRIMINATOR_SLICE => Ok(()),
-        _ => Err(CustomErrors::InvalidInstruction.into()),
+impl GateProgram for AlwaysAllow {
+    fn can_thaw_permissionless(_accounts: &GateAccounts) -> ProgramResult {
+        Ok(())
     }
-}
 
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum CustomErrors {
-    InvalidInstruction,
-    UnsupportedInstruction,
-}
-
-impl From<CustomErrors> for ProgramError {
-    fn from(e: CustomErrors) -> Self {
-        ProgramError::Custom(e as u32)
+    fn can_freeze_permissionless(_accounts: &GateAccounts) -> ProgramResult {
+        Ok(())
     }
 }
+
+token_acl_interface::gate_entrypoint!(AlwaysAllow);
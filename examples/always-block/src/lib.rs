@@ -1,42 +1,25 @@
-use solana_program::{
-    account_info::AccountInfo, declare_id, entrypoint, entrypoint::ProgramResult, pubkey::Pubkey,
-};
+use solana_program::{declare_id, entrypoint::ProgramResult};
 use solana_program_error::ProgramError;
-use spl_discriminator::{ArrayDiscriminator, SplDiscriminate};
-use token_acl_interface::instruction::{
-    CanFreezePermissionlessInstruction, CanThawPermissionlessInstruction,
-};
-
-pub mod instructions;
-pub use instructions::*;
+use token_acl_interface::gate::{GateAccounts, GateProgram};
 
 declare_id!("Eba1ts11111111111111111111111111111111111113");
 
-entrypoint!(process_instruction);
-fn process_instruction<'a>(
-    _program_id: &Pubkey,
-    accounts: &'a [AccountInfo<'a>],
-    instruction_data: &'a [u8],
-) -> ProgramResult {
-    let (discriminator, _remaining) = instruction_data.split_at(ArrayDiscriminator::LENGTH);
+pub struct AlwaysBlock;
+
+impl GateProgram for AlwaysBlock {
+    fn can_thaw_permissionless(_accounts: &GateAccounts) -> ProgramResult {
+        Err(CustomErrors::UnsupportedInstruction.into())
+    }
 
-    match discriminator {
-        InitializeExtraMetas::DISCRIMINATOR_SLICE => {
-            InitializeExtraMetas::try_from(accounts)?.process()
-        }
-        CanThawPermissionlessInstruction::SPL_DISCRIMINATOR_SLICE => {
-            Err(CustomErrors::UnsupportedInstruction.into())
-        }
-        CanFreezePermissionlessInstruction::SPL_DISCRIMINATOR_SLICE => {
-            Err(CustomErrors::UnsupportedInstruction.into())
-        }
-        _ => Err(CustomErrors::InvalidInstruction.into()),
+    fn can_freeze_permissionless(_accounts: &GateAccounts) -> ProgramResult {
+        Err(CustomErrors::UnsupportedInstruction.into())
     }
 }
 
+token_acl_interface::gate_entrypoint!(AlwaysBlock);
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CustomErrors {
-    InvalidInstruction,
     UnsupportedInstruction = 999999999,
 }
 
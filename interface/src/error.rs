@@ -20,6 +20,9 @@ pub enum ThawFreezeGateError {
     ProgramError(ProgramError),
 
     InvalidTokenMint,
+
+    /// Simulation failed without reporting a missing gate account
+    SimulationFailed(String),
 }
 
 impl std::fmt::Display for ThawFreezeGateError {
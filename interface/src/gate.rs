@@ -0,0 +1,328 @@
+//! Building blocks for gating programs.
+//!
+//! A gating program implements [`GateProgram`] and hands its entrypoint to
+//! [`process_instruction`], usually through [`gate_entrypoint!`](crate::gate_entrypoint).
+//! The framework takes care of instruction dispatch, parsing the accounts passed by Token ACL,
+//! validating the flag account and the standard instructions managing the extra account metas
+//! PDAs.
+
+use solana_account_info::AccountInfo;
+use solana_program_error::{ProgramError, ProgramResult};
+use solana_pubkey::Pubkey;
+use spl_discriminator::{ArrayDiscriminator, SplDiscriminate};
+use spl_tlv_account_resolution::account::ExtraAccountMeta;
+use spl_token_2022_interface::{
+    extension::PodStateWithExtensions,
+    pod::{PodCOption, PodMint},
+};
+
+use crate::{
+    instruction::{
+        unpack_extra_account_metas_data, CanFreezePermissionlessInstruction,
+        CanThawPermissionlessInstruction, InitializeExtraAccountMetasInstruction,
+        UpdateExtraAccountMetasInstruction,
+    },
+    onchain::{initialize_extra_account_metas, update_extra_account_metas, verify_flag_account},
+    PermissionlessOperation,
+};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use solana_account_info::AccountInfo;
+    pub use solana_program_entrypoint::entrypoint;
+    pub use solana_program_error::ProgramResult;
+    pub use solana_pubkey::Pubkey;
+}
+
+/// Creates the thaw and freeze extra account metas PDAs of a mint with the metas declared by
+/// the gate. The payer signs as the authority, checked with
+/// [`GateProgram::authorize_extra_account_metas`].
+///
+/// Accounts: payer, mint, thaw extra metas, freeze extra metas, system program.
+pub const INITIALIZE_EXTRA_METAS_DISCRIMINATOR: [u8; 8] = [1; 8];
+
+/// Accounts Token ACL passes to `CanThawPermissionless` and `CanFreezePermissionless`.
+pub struct GateAccounts<'a> {
+    pub authority: &'a AccountInfo<'a>,
+    pub token_account: &'a AccountInfo<'a>,
+    pub mint: &'a AccountInfo<'a>,
+    pub token_account_owner: &'a AccountInfo<'a>,
+    pub flag_account: &'a AccountInfo<'a>,
+    /// The extra account metas PDA followed by the accounts resolved from it, empty when the
+    /// gate has no extra account metas for the mint.
+    pub extra_accounts: &'a [AccountInfo<'a>],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for GateAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [authority, token_account, mint, token_account_owner, flag_account, extra_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        Ok(Self {
+            authority,
+            token_account,
+            mint,
+            token_account_owner,
+            flag_account,
+            extra_accounts,
+        })
+    }
+}
+
+/// Accounts of the instruction creating the extra account metas PDAs declared by the gate.
+pub struct ExtraMetasAccounts<'a> {
+    pub payer: &'a AccountInfo<'a>,
+    pub mint: &'a AccountInfo<'a>,
+    pub thaw_extra_metas: &'a AccountInfo<'a>,
+    pub freeze_extra_metas: &'a AccountInfo<'a>,
+    pub system_program: &'a AccountInfo<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for ExtraMetasAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [payer, mint, thaw_extra_metas, freeze_extra_metas, system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !solana_system_interface::program::check_id(system_program.key) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        Ok(Self {
+            payer,
+            mint,
+            thaw_extra_metas,
+            freeze_extra_metas,
+            system_program,
+        })
+    }
+}
+
+/// Accounts of the standard [`InitializeExtraAccountMetasInstruction`] and
+/// [`UpdateExtraAccountMetasInstruction`].
+pub struct ExtraAccountMetasAccounts<'a> {
+    pub extra_metas: &'a AccountInfo<'a>,
+    pub mint: &'a AccountInfo<'a>,
+    pub authority: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    pub system_program: &'a AccountInfo<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for ExtraAccountMetasAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [extra_metas, mint, authority, payer, system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !solana_system_interface::program::check_id(system_program.key) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        Ok(Self {
+            extra_metas,
+            mint,
+            authority,
+            payer,
+            system_program,
+        })
+    }
+}
+
+/// A gating program, deciding whether a permissionless thaw or freeze may go through.
+pub trait GateProgram {
+    /// Extra accounts `CanThawPermissionless` needs for `mint`, stored in its thaw extra
+    /// account metas PDA. The indexes of the metas start at 5, after the accounts passed by
+    /// Token ACL.
+    fn thaw_extra_account_metas(_mint: &Pubkey) -> Result<Vec<ExtraAccountMeta>, ProgramError> {
+        Ok(Vec::new())
+    }
+
+    /// Extra accounts `CanFreezePermissionless` needs for `mint`, stored in its freeze extra
+    /// account metas PDA.
+    fn freeze_extra_account_metas(_mint: &Pubkey) -> Result<Vec<ExtraAccountMeta>, ProgramError> {
+        Ok(Vec::new())
+    }
+
+    /// Authorizes `authority`, already checked to be a signer, to set the extra account metas
+    /// of `mint`, through the standard instructions and the [`INITIALIZE_EXTRA_METAS_DISCRIMINATOR`]
+    /// one. Defaults to the mint authority.
+    fn authorize_extra_account_metas(mint: &AccountInfo, authority: &AccountInfo) -> ProgramResult {
+        verify_mint_authority(mint, authority.key)
+    }
+
+    /// Fails to refuse the permissionless thaw of `accounts.token_account`.
+    fn can_thaw_permissionless(accounts: &GateAccounts) -> ProgramResult;
+
+    /// Fails to refuse the permissionless freeze of `accounts.token_account`.
+    fn can_freeze_permissionless(accounts: &GateAccounts) -> ProgramResult;
+
+    /// Handles the instructions the gate defines on top of the standard ones.
+    fn process_other_instruction<'a>(
+        _program_id: &Pubkey,
+        _accounts: &'a [AccountInfo<'a>],
+        _instruction_data: &[u8],
+    ) -> ProgramResult {
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+/// Dispatches an instruction of the gating program `G`.
+pub fn process_instruction<'a, G: GateProgram>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let discriminator = instruction_data
+        .get(..ArrayDiscriminator::LENGTH)
+        .unwrap_or_default();
+
+    match discriminator {
+        CanThawPermissionlessInstruction::SPL_DISCRIMINATOR_SLICE => {
+            let accounts = GateAccounts::try_from(accounts)?;
+            verify_flag_account(
+                accounts.flag_account,
+                accounts.token_account.key,
+                PermissionlessOperation::Thaw,
+            )?;
+            G::can_thaw_permissionless(&accounts)
+        }
+        CanFreezePermissionlessInstruction::SPL_DISCRIMINATOR_SLICE => {
+            let accounts = GateAccounts::try_from(accounts)?;
+            verify_flag_account(
+                accounts.flag_account,
+                accounts.token_account.key,
+                PermissionlessOperation::Freeze,
+            )?;
+            G::can_freeze_permissionless(&accounts)
+        }
+        InitializeExtraAccountMetasInstruction::SPL_DISCRIMINATOR_SLICE => {
+            let accounts = ExtraAccountMetasAccounts::try_from(accounts)?;
+            let data = &instruction_data[ArrayDiscriminator::LENGTH..];
+            process_set_extra_account_metas::<G>(program_id, &accounts, data, false)
+        }
+        UpdateExtraAccountMetasInstruction::SPL_DISCRIMINATOR_SLICE => {
+            let accounts = ExtraAccountMetasAccounts::try_from(accounts)?;
+            let data = &instruction_data[ArrayDiscriminator::LENGTH..];
+            process_set_extra_account_metas::<G>(program_id, &accounts, data, true)
+        }
+        d if d == INITIALIZE_EXTRA_METAS_DISCRIMINATOR => {
+            let accounts = ExtraMetasAccounts::try_from(accounts)?;
+            process_initialize_extra_metas::<G>(program_id, &accounts)
+        }
+        _ => G::process_other_instruction(program_id, accounts, instruction_data),
+    }
+}
+
+fn process_initialize_extra_metas<G: GateProgram>(
+    program_id: &Pubkey,
+    accounts: &ExtraMetasAccounts,
+) -> ProgramResult {
+    authorize::<G>(accounts.mint, accounts.payer)?;
+    let mint = accounts.mint.key;
+
+    initialize_extra_account_metas(
+        program_id,
+        accounts.payer,
+        accounts.thaw_extra_metas,
+        mint,
+        PermissionlessOperation::Thaw,
+        &G::thaw_extra_account_metas(mint)?,
+    )?;
+    initialize_extra_account_metas(
+        program_id,
+        accounts.payer,
+        accounts.freeze_extra_metas,
+        mint,
+        PermissionlessOperation::Freeze,
+        &G::freeze_extra_account_metas(mint)?,
+    )
+}
+
+fn process_set_extra_account_metas<G: GateProgram>(
+    program_id: &Pubkey,
+    accounts: &ExtraAccountMetasAccounts,
+    data: &[u8],
+    is_update: bool,
+) -> ProgramResult {
+    authorize::<G>(accounts.mint, accounts.authority)?;
+
+    let (operation, extra_account_metas) = unpack_extra_account_metas_data(data)?;
+    let set_extra_account_metas = if is_update {
+        update_extra_account_metas
+    } else {
+        initialize_extra_account_metas
+    };
+
+    set_extra_account_metas(
+        program_id,
+        accounts.payer,
+        accounts.extra_metas,
+        accounts.mint.key,
+        operation,
+        extra_account_metas,
+    )
+}
+
+fn authorize<G: GateProgram>(mint: &AccountInfo, authority: &AccountInfo) -> ProgramResult {
+    if !authority.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    G::authorize_extra_account_metas(mint, authority)
+}
+
+/// Checks that `mint` is a Token or Token-2022 mint with `authority` as its mint authority.
+pub fn verify_mint_authority(mint: &AccountInfo, authority: &Pubkey) -> ProgramResult {
+    if !spl_token_interface::check_id(mint.owner) && !spl_token_2022_interface::check_id(mint.owner)
+    {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // a Token mint is a Token-2022 mint without extensions
+    let data = mint.try_borrow_data()?;
+    let mint = PodStateWithExtensions::<PodMint>::unpack(&data)?;
+    if mint.base.mint_authority != PodCOption::some(*authority) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+/// Declares the program entrypoint of a [`GateProgram`](crate::gate::GateProgram).
+///
+/// ```ignore
+/// struct AlwaysAllow;
+///
+/// impl GateProgram for AlwaysAllow {
+///     fn can_thaw_permissionless(_accounts: &GateAccounts) -> ProgramResult {
+///         Ok(())
+///     }
+///
+///     fn can_freeze_permissionless(_accounts: &GateAccounts) -> ProgramResult {
+///         Ok(())
+///     }
+/// }
+///
+/// token_acl_interface::gate_entrypoint!(AlwaysAllow);
+/// ```
+#[macro_export]
+macro_rules! gate_entrypoint {
+    ($gate:ty) => {
+        $crate::gate::__private::entrypoint!(process_instruction);
+        fn process_instruction<'a>(
+            program_id: &$crate::gate::__private::Pubkey,
+            accounts: &'a [$crate::gate::__private::AccountInfo<'a>],
+            instruction_data: &[u8],
+        ) -> $crate::gate::__private::ProgramResult {
+            $crate::gate::process_instruction::<$gate>(program_id, accounts, instruction_data)
+        }
+    };
+}
@@ -2,6 +2,13 @@ use solana_instruction::{AccountMeta, Instruction};
 use solana_program_error::ProgramError;
 use solana_pubkey::Pubkey;
 use spl_discriminator::{ArrayDiscriminator, SplDiscriminate};
+use spl_pod::bytemuck::{pod_slice_from_bytes, pod_slice_to_bytes};
+use spl_tlv_account_resolution::account::ExtraAccountMeta;
+
+use crate::{
+    get_freeze_extra_account_metas_address, get_thaw_extra_account_metas_address,
+    PermissionlessOperation,
+};
 
 pub enum EfficientBlockAllowInstruction {
     CanThawPermissionless,
@@ -16,6 +23,24 @@ pub struct CanThawPermissionlessInstruction;
 #[discriminator_hash_input("efficient-allow-block-list-standard:can-freeze-permissionless")]
 pub struct CanFreezePermissionlessInstruction;
 
+/// Optional instruction creating the thaw or freeze extra account metas PDA of a mint.
+///
+/// Accounts: [extra account metas (writable), mint, authority (signer), payer (writable, signer),
+/// system program]
+///
+/// Data: discriminator, [`PermissionlessOperation`] as `u8`, followed by the extra account metas.
+#[derive(SplDiscriminate)]
+#[discriminator_hash_input("efficient-allow-block-list-standard:initialize-extra-account-metas")]
+pub struct InitializeExtraAccountMetasInstruction;
+
+/// Optional instruction replacing the extra account metas stored in the thaw or freeze extra
+/// account metas PDA of a mint, resizing it as needed.
+///
+/// Takes the same accounts and data as [`InitializeExtraAccountMetasInstruction`].
+#[derive(SplDiscriminate)]
+#[discriminator_hash_input("efficient-allow-block-list-standard:update-extra-account-metas")]
+pub struct UpdateExtraAccountMetasInstruction;
+
 impl EfficientBlockAllowInstruction {
     pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
         if data.len() < ArrayDiscriminator::LENGTH {
@@ -92,3 +117,85 @@ pub fn can_freeze_permissionless(
         data,
     }
 }
+
+pub fn initialize_extra_account_metas(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    operation: PermissionlessOperation,
+    extra_account_metas: &[ExtraAccountMeta],
+) -> Instruction {
+    extra_account_metas_instruction::<InitializeExtraAccountMetasInstruction>(
+        program_id,
+        mint,
+        authority,
+        payer,
+        operation,
+        extra_account_metas,
+    )
+}
+
+pub fn update_extra_account_metas(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    operation: PermissionlessOperation,
+    extra_account_metas: &[ExtraAccountMeta],
+) -> Instruction {
+    extra_account_metas_instruction::<UpdateExtraAccountMetasInstruction>(
+        program_id,
+        mint,
+        authority,
+        payer,
+        operation,
+        extra_account_metas,
+    )
+}
+
+/// Splits the data of an initialize or update extra account metas instruction, without its
+/// discriminator, into the operation and the extra account metas.
+pub fn unpack_extra_account_metas_data(
+    data: &[u8],
+) -> Result<(PermissionlessOperation, &[ExtraAccountMeta]), ProgramError> {
+    let [operation, metas @ ..] = data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+    let operation = PermissionlessOperation::try_from(*operation)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    Ok((operation, pod_slice_from_bytes(metas)?))
+}
+
+fn extra_account_metas_instruction<T: SplDiscriminate>(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    operation: PermissionlessOperation,
+    extra_account_metas: &[ExtraAccountMeta],
+) -> Instruction {
+    let extra_metas = match operation {
+        PermissionlessOperation::Thaw => get_thaw_extra_account_metas_address(mint, program_id),
+        PermissionlessOperation::Freeze => get_freeze_extra_account_metas_address(mint, program_id),
+    };
+
+    let mut data = T::SPL_DISCRIMINATOR_SLICE.to_vec();
+    data.push(operation as u8);
+    data.extend_from_slice(pod_slice_to_bytes(extra_account_metas));
+
+    let accounts = vec![
+        AccountMeta::new(extra_metas, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+    ];
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    }
+}
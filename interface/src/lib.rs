@@ -1,6 +1,8 @@
+use solana_program_error::ProgramError;
 use solana_pubkey::{pubkey, Pubkey};
 
 pub mod error;
+pub mod gate;
 pub mod instruction;
 pub mod offchain;
 pub mod onchain;
@@ -11,6 +13,27 @@ pub const FLAG_ACCOUNT_SEED: &[u8] = b"FLAG_ACCOUNT";
 
 pub const TOKEN_ACL_ID: Pubkey = pubkey!("TACLkU6CiCdkQN2MjoyDkVg2yAH9zkxiHDsiztQ52TP");
 
+/// Permissionless operation in flight, stored in the flag account while Token ACL
+/// invokes the gating program.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PermissionlessOperation {
+    Thaw = 1,
+    Freeze = 2,
+}
+
+impl TryFrom<u8> for PermissionlessOperation {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Thaw),
+            2 => Ok(Self::Freeze),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
 pub fn collect_thaw_extra_account_metas(mint: &Pubkey) -> [&[u8]; 2] {
     [THAW_EXTRA_ACCOUNT_METAS_SEED, mint.as_ref()]
 }
@@ -4,6 +4,8 @@ pub use spl_tlv_account_resolution::state::{AccountDataResult, AccountFetchError
 use crate::{
     get_freeze_extra_account_metas_address,
     instruction::{can_freeze_permissionless, CanFreezePermissionlessInstruction},
+    onchain::MissingGateAccount,
+    PermissionlessOperation, TOKEN_ACL_ID,
 };
 
 use {
@@ -15,7 +17,8 @@ use {
     solana_instruction::{AccountMeta, Instruction},
     solana_pubkey::Pubkey,
     spl_tlv_account_resolution::state::ExtraAccountMetaList,
-    std::future::Future,
+    spl_type_length_value::state::TlvStateBorrowed,
+    std::{future::Future, ops::Range},
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -43,7 +46,7 @@ where
         mint_pubkey,
         token_account_owner,
         &extra_metas_pubkey,
-        &flag_account_pubkey,
+        flag_account_pubkey,
         fetch_account_data_fn,
         |program_id,
          signer_pubkey,
@@ -57,7 +60,7 @@ where
                 token_account_pubkey,
                 mint_pubkey,
                 token_account_owner,
-                &flag_account_pubkey,
+                flag_account_pubkey,
             )
         },
     )
@@ -89,7 +92,7 @@ where
         mint_pubkey,
         token_account_owner,
         &extra_metas_pubkey,
-        &flag_account_pubkey,
+        flag_account_pubkey,
         fetch_account_data_fn,
         |program_id,
          signer_pubkey,
@@ -110,6 +113,362 @@ where
     .await
 }
 
+/// Resolves the extra accounts of a chain of gating programs for a thaw instruction.
+///
+/// The first gating program has to be part of the instruction already. Every following
+/// program is appended ahead of its own extra accounts, which is the layout expected by
+/// [`crate::onchain::split_thaw_gate_accounts`].
+#[allow(clippy::too_many_arguments)]
+pub async fn add_extra_account_metas_for_thaw_gates<F, Fut>(
+    instruction: &mut Instruction,
+    gating_programs: &[Pubkey],
+    signer_pubkey: &Pubkey,
+    token_account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    token_account_owner: &Pubkey,
+    flag_account_pubkey: &Pubkey,
+    fetch_account_data_fn: F,
+) -> Result<(), ThawFreezeGateError>
+where
+    F: Fn(Pubkey) -> Fut,
+    Fut: Future<Output = AccountDataResult>,
+{
+    for (i, program_id) in gating_programs.iter().enumerate() {
+        if i > 0 {
+            instruction
+                .accounts
+                .push(AccountMeta::new_readonly(*program_id, false));
+        }
+        add_extra_account_metas_for_thaw(
+            instruction,
+            program_id,
+            signer_pubkey,
+            token_account_pubkey,
+            mint_pubkey,
+            token_account_owner,
+            flag_account_pubkey,
+            &fetch_account_data_fn,
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// Resolves the extra accounts of a chain of gating programs for a freeze instruction.
+///
+/// Uses the same layout as [`add_extra_account_metas_for_thaw_gates`], expected by
+/// [`crate::onchain::split_freeze_gate_accounts`].
+#[allow(clippy::too_many_arguments)]
+pub async fn add_extra_account_metas_for_freeze_gates<F, Fut>(
+    instruction: &mut Instruction,
+    gating_programs: &[Pubkey],
+    signer_pubkey: &Pubkey,
+    token_account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    token_account_owner: &Pubkey,
+    flag_account_pubkey: &Pubkey,
+    fetch_account_data_fn: F,
+) -> Result<(), ThawFreezeGateError>
+where
+    F: Fn(Pubkey) -> Fut,
+    Fut: Future<Output = AccountDataResult>,
+{
+    for (i, program_id) in gating_programs.iter().enumerate() {
+        if i > 0 {
+            instruction
+                .accounts
+                .push(AccountMeta::new_readonly(*program_id, false));
+        }
+        add_extra_account_metas_for_freeze(
+            instruction,
+            program_id,
+            signer_pubkey,
+            token_account_pubkey,
+            mint_pubkey,
+            token_account_owner,
+            flag_account_pubkey,
+            &fetch_account_data_fn,
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// Rounds of simulation [`resolve_missing_gate_accounts_with_simulation`] runs before giving up.
+pub const MAX_SIMULATION_ROUNDS: usize = 16;
+
+/// Outcome of simulating a transaction made of the instruction being resolved.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SimulationOutcome {
+    /// The instruction went through, no gate account is missing.
+    Success,
+    /// Token ACL failed because this extra account of a gate wasn't passed to it.
+    MissingGateAccount(MissingGateAccount),
+    /// The instruction failed for another reason.
+    Failure(String),
+}
+
+impl SimulationOutcome {
+    /// Builds the outcome of a failed simulation from the `(program_id, data)` return data it
+    /// left behind, where Token ACL reports the missing gate account.
+    pub fn from_failed_simulation(
+        err: impl ToString,
+        return_data: Option<(&Pubkey, &[u8])>,
+    ) -> Self {
+        return_data
+            .filter(|(program_id, _)| **program_id == TOKEN_ACL_ID)
+            .and_then(|(_, data)| MissingGateAccount::unpack(data))
+            .map_or_else(|| Self::Failure(err.to_string()), Self::MissingGateAccount)
+    }
+}
+
+/// Completes the gate accounts of a permissionless `instruction` by simulating it with
+/// `simulate_fn`, an RPC node or a local bank, and passing the extra accounts Token ACL
+/// reports missing until the simulation goes through.
+///
+/// This covers gates whose extra accounts can't be resolved off-chain, such as seeds taken
+/// from accounts that don't exist yet. `gate_accounts` holds every gating program of the
+/// instruction with the range of its extra accounts, right after its extra metas PDA. Each
+/// range should already hold one account per extra account meta, e.g. the extra metas PDA
+/// repeated: Token ACL looks the accounts up by address, but splits chained gates by count.
+/// The reported accounts replace the start of the range, which keeps its length unless more
+/// accounts are reported than it holds.
+pub async fn resolve_missing_gate_accounts_with_simulation<S, SFut>(
+    instruction: &mut Instruction,
+    gate_accounts: &mut [(Pubkey, Range<usize>)],
+    simulate_fn: S,
+) -> Result<(), ThawFreezeGateError>
+where
+    S: Fn(Instruction) -> SFut,
+    SFut: Future<Output = SimulationOutcome>,
+{
+    for _ in 0..MAX_SIMULATION_ROUNDS {
+        let missing = match simulate_fn(instruction.clone()).await {
+            SimulationOutcome::Success => return Ok(()),
+            SimulationOutcome::MissingGateAccount(missing) => missing,
+            SimulationOutcome::Failure(err) => {
+                return Err(ThawFreezeGateError::SimulationFailed(err))
+            }
+        };
+
+        let gate = gate_accounts
+            .iter()
+            .position(|(program_id, _)| *program_id == missing.gating_program)
+            .ok_or(ThawFreezeGateError::IncorrectAccount)?;
+        let range = gate_accounts[gate].1.clone();
+
+        // reported again once passed, passing it a second time won't help
+        if instruction.accounts[range.clone()].starts_with(&missing.accounts) {
+            return Err(ThawFreezeGateError::SimulationFailed(String::from(
+                "gate accounts are still missing",
+            )));
+        }
+
+        let replaced = range.start..range.end.min(range.start + missing.accounts.len());
+        let added = missing.accounts.len() - replaced.len();
+        instruction.accounts.splice(replaced, missing.accounts);
+        gate_accounts[gate].1.end += added;
+        for (_, later) in &mut gate_accounts[gate + 1..] {
+            *later = later.start + added..later.end + added;
+        }
+    }
+
+    Err(ThawFreezeGateError::SimulationFailed(String::from(
+        "too many missing gate accounts",
+    )))
+}
+
+/// Like [`add_extra_account_metas_for_thaw_gates`], but falls back to
+/// [`resolve_missing_gate_accounts_with_simulation`] for the gates whose extra accounts can't
+/// be resolved off-chain. `trailing_accounts` are the accounts Token ACL takes after the gate
+/// accounts, they're appended before simulating.
+#[allow(clippy::too_many_arguments)]
+pub async fn add_extra_account_metas_for_thaw_gates_with_simulation<F, Fut, S, SFut>(
+    instruction: &mut Instruction,
+    gating_programs: &[Pubkey],
+    signer_pubkey: &Pubkey,
+    token_account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    token_account_owner: &Pubkey,
+    flag_account_pubkey: &Pubkey,
+    trailing_accounts: &[AccountMeta],
+    fetch_account_data_fn: F,
+    simulate_fn: S,
+) -> Result<(), ThawFreezeGateError>
+where
+    F: Fn(Pubkey) -> Fut,
+    Fut: Future<Output = AccountDataResult>,
+    S: Fn(Instruction) -> SFut,
+    SFut: Future<Output = SimulationOutcome>,
+{
+    add_extra_account_metas_for_gates_with_simulation(
+        PermissionlessOperation::Thaw,
+        instruction,
+        gating_programs,
+        signer_pubkey,
+        token_account_pubkey,
+        mint_pubkey,
+        token_account_owner,
+        flag_account_pubkey,
+        trailing_accounts,
+        fetch_account_data_fn,
+        simulate_fn,
+    )
+    .await
+}
+
+/// Like [`add_extra_account_metas_for_freeze_gates`], with the simulation fallback of
+/// [`add_extra_account_metas_for_thaw_gates_with_simulation`].
+#[allow(clippy::too_many_arguments)]
+pub async fn add_extra_account_metas_for_freeze_gates_with_simulation<F, Fut, S, SFut>(
+    instruction: &mut Instruction,
+    gating_programs: &[Pubkey],
+    signer_pubkey: &Pubkey,
+    token_account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    token_account_owner: &Pubkey,
+    flag_account_pubkey: &Pubkey,
+    trailing_accounts: &[AccountMeta],
+    fetch_account_data_fn: F,
+    simulate_fn: S,
+) -> Result<(), ThawFreezeGateError>
+where
+    F: Fn(Pubkey) -> Fut,
+    Fut: Future<Output = AccountDataResult>,
+    S: Fn(Instruction) -> SFut,
+    SFut: Future<Output = SimulationOutcome>,
+{
+    add_extra_account_metas_for_gates_with_simulation(
+        PermissionlessOperation::Freeze,
+        instruction,
+        gating_programs,
+        signer_pubkey,
+        token_account_pubkey,
+        mint_pubkey,
+        token_account_owner,
+        flag_account_pubkey,
+        trailing_accounts,
+        fetch_account_data_fn,
+        simulate_fn,
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn add_extra_account_metas_for_gates_with_simulation<F, Fut, S, SFut>(
+    operation: PermissionlessOperation,
+    instruction: &mut Instruction,
+    gating_programs: &[Pubkey],
+    signer_pubkey: &Pubkey,
+    token_account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    token_account_owner: &Pubkey,
+    flag_account_pubkey: &Pubkey,
+    trailing_accounts: &[AccountMeta],
+    fetch_account_data_fn: F,
+    simulate_fn: S,
+) -> Result<(), ThawFreezeGateError>
+where
+    F: Fn(Pubkey) -> Fut,
+    Fut: Future<Output = AccountDataResult>,
+    S: Fn(Instruction) -> SFut,
+    SFut: Future<Output = SimulationOutcome>,
+{
+    let mut gate_accounts = Vec::with_capacity(gating_programs.len());
+    let mut unresolved = false;
+
+    for (i, program_id) in gating_programs.iter().enumerate() {
+        if i > 0 {
+            instruction
+                .accounts
+                .push(AccountMeta::new_readonly(*program_id, false));
+        }
+        let gate_start = instruction.accounts.len();
+
+        let (result, extra_metas_pubkey) = match operation {
+            PermissionlessOperation::Thaw => (
+                add_extra_account_metas_for_thaw(
+                    instruction,
+                    program_id,
+                    signer_pubkey,
+                    token_account_pubkey,
+                    mint_pubkey,
+                    token_account_owner,
+                    flag_account_pubkey,
+                    &fetch_account_data_fn,
+                )
+                .await,
+                get_thaw_extra_account_metas_address(mint_pubkey, program_id),
+            ),
+            PermissionlessOperation::Freeze => (
+                add_extra_account_metas_for_freeze(
+                    instruction,
+                    program_id,
+                    signer_pubkey,
+                    token_account_pubkey,
+                    mint_pubkey,
+                    token_account_owner,
+                    flag_account_pubkey,
+                    &fetch_account_data_fn,
+                )
+                .await,
+                get_freeze_extra_account_metas_address(mint_pubkey, program_id),
+            ),
+        };
+
+        match result {
+            Ok(()) => {}
+            // the extra metas PDA exists, but its accounts can't be resolved off-chain
+            Err(ThawFreezeGateError::ResolutionError(_))
+            | Err(ThawFreezeGateError::ProgramError(_)) => {
+                let data = fetch_account_data_fn(extra_metas_pubkey)
+                    .await
+                    .ok()
+                    .flatten()
+                    .ok_or(ThawFreezeGateError::MissingExtraAccountMeta)?;
+                let state = TlvStateBorrowed::unpack(&data)?;
+                let len = match operation {
+                    PermissionlessOperation::Thaw => ExtraAccountMetaList::unpack_with_tlv_state::<
+                        CanThawPermissionlessInstruction,
+                    >(&state)?
+                    .len(),
+                    PermissionlessOperation::Freeze => {
+                        ExtraAccountMetaList::unpack_with_tlv_state::<
+                            CanFreezePermissionlessInstruction,
+                        >(&state)?
+                        .len()
+                    }
+                };
+
+                // one placeholder per extra account, replaced as the simulation reports them
+                let extra_metas = AccountMeta::new_readonly(extra_metas_pubkey, false);
+                instruction
+                    .accounts
+                    .extend(std::iter::repeat_n(extra_metas, 1 + len));
+                unresolved = true;
+            }
+            Err(err) => return Err(err),
+        }
+
+        // the extra accounts follow the extra metas PDA, if the gate has one
+        let end = instruction.accounts.len();
+        let start = instruction.accounts[gate_start..end]
+            .iter()
+            .position(|meta| meta.pubkey == extra_metas_pubkey)
+            .map_or(end, |index| gate_start + index + 1);
+        gate_accounts.push((*program_id, start..end));
+    }
+
+    instruction.accounts.extend_from_slice(trailing_accounts);
+
+    if unresolved {
+        resolve_missing_gate_accounts_with_simulation(instruction, &mut gate_accounts, simulate_fn)
+            .await?;
+    }
+
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn add_extra_account_metas_for_permissionless_ix<F, Fut, T, F2>(
     instruction: &mut Instruction,
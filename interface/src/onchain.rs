@@ -1,12 +1,20 @@
 use solana_account_info::AccountInfo;
-use solana_cpi::invoke;
-use solana_instruction::AccountMeta;
-use solana_program_error::ProgramResult;
+use solana_cpi::{invoke, invoke_signed, set_return_data, MAX_RETURN_DATA};
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program_error::{ProgramError, ProgramResult};
 use solana_pubkey::Pubkey;
-use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use solana_rent::Rent;
+use solana_sysvar::Sysvar;
+use spl_discriminator::SplDiscriminate;
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, error::AccountResolutionError, state::ExtraAccountMetaList,
+};
+use spl_type_length_value::state::TlvStateBorrowed;
 
 use crate::{
+    collect_freeze_extra_account_metas, collect_thaw_extra_account_metas, get_flag_account_address,
     get_freeze_extra_account_metas_address, get_thaw_extra_account_metas_address, instruction,
+    PermissionlessOperation, TOKEN_ACL_ID,
 };
 
 pub fn invoke_can_thaw_permissionless<'a>(
@@ -46,9 +54,7 @@ pub fn invoke_can_thaw_permissionless<'a>(
             .push(AccountMeta::new_readonly(validation_pubkey, false));
         cpi_account_infos.push(validation_info.clone());
 
-        ExtraAccountMetaList::add_to_cpi_instruction::<
-            instruction::CanThawPermissionlessInstruction,
-        >(
+        add_gate_accounts_to_cpi_instruction::<instruction::CanThawPermissionlessInstruction>(
             &mut instruction,
             &mut cpi_account_infos,
             &validation_info.try_borrow_data()?,
@@ -95,9 +101,7 @@ pub fn invoke_can_freeze_permissionless<'a>(
             .push(AccountMeta::new_readonly(validation_pubkey, false));
         cpi_account_infos.push(validation_info.clone());
 
-        ExtraAccountMetaList::add_to_cpi_instruction::<
-            instruction::CanFreezePermissionlessInstruction,
-        >(
+        add_gate_accounts_to_cpi_instruction::<instruction::CanFreezePermissionlessInstruction>(
             &mut instruction,
             &mut cpi_account_infos,
             &validation_info.try_borrow_data()?,
@@ -107,3 +111,335 @@ pub fn invoke_can_freeze_permissionless<'a>(
 
     invoke(&instruction, &cpi_account_infos)
 }
+
+/// Extra accounts of a gating program resolved by Token ACL, up to the first one that wasn't
+/// passed to it.
+///
+/// The permissionless instructions set it as return data right before failing with
+/// `IncorrectAccount`, so that a simulation tells which accounts to pass, see
+/// [`crate::offchain::resolve_missing_gate_accounts_with_simulation`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MissingGateAccount {
+    pub gating_program: Pubkey,
+    /// Extra accounts of the gate in order, the last one is the missing one.
+    pub accounts: Vec<AccountMeta>,
+}
+
+impl MissingGateAccount {
+    const ACCOUNT_LEN: usize = 33;
+
+    pub fn pack(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(32 + self.accounts.len() * Self::ACCOUNT_LEN);
+        data.extend_from_slice(self.gating_program.as_ref());
+        for meta in &self.accounts {
+            data.extend_from_slice(meta.pubkey.as_ref());
+            data.push(meta.is_writable as u8);
+        }
+        data
+    }
+
+    pub fn unpack(data: &[u8]) -> Option<Self> {
+        let (gating_program, accounts) = data.split_first_chunk::<32>()?;
+        if accounts.is_empty() || accounts.len() % Self::ACCOUNT_LEN != 0 {
+            return None;
+        }
+
+        let accounts = accounts
+            .chunks_exact(Self::ACCOUNT_LEN)
+            .map(|account| {
+                let pubkey = Pubkey::new_from_array(account[..32].try_into().ok()?);
+                match account[32] {
+                    0 => Some(AccountMeta::new_readonly(pubkey, false)),
+                    1 => Some(AccountMeta::new(pubkey, false)),
+                    _ => None,
+                }
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self {
+            gating_program: Pubkey::new_from_array(*gating_program),
+            accounts,
+        })
+    }
+}
+
+/// Same as `ExtraAccountMetaList::add_to_cpi_instruction`, except that the first extra account
+/// missing from `account_infos` is reported as a [`MissingGateAccount`].
+fn add_gate_accounts_to_cpi_instruction<'a, T: SplDiscriminate>(
+    cpi_instruction: &mut Instruction,
+    cpi_account_infos: &mut Vec<AccountInfo<'a>>,
+    data: &[u8],
+    account_infos: &[AccountInfo<'a>],
+) -> ProgramResult {
+    let state = TlvStateBorrowed::unpack(data)?;
+    let extra_account_metas = ExtraAccountMetaList::unpack_with_tlv_state::<T>(&state)?;
+    let extra_accounts_start = cpi_instruction.accounts.len();
+
+    for extra_meta in extra_account_metas.iter() {
+        let mut meta = {
+            let account_key_data_refs = cpi_account_infos
+                .iter()
+                .map(|info| Ok((*info.key, info.try_borrow_data()?)))
+                .collect::<Result<Vec<_>, ProgramError>>()?;
+
+            extra_meta.resolve(
+                &cpi_instruction.data,
+                &cpi_instruction.program_id,
+                |index| {
+                    account_key_data_refs
+                        .get(index)
+                        .map(|(pubkey, data)| (pubkey, Some(data.as_ref())))
+                },
+            )?
+        };
+
+        // never more privileges than the account already has in the instruction, and never
+        // a signer
+        if let Some(is_writable) = cpi_instruction
+            .accounts
+            .iter()
+            .filter(|existing| existing.pubkey == meta.pubkey)
+            .map(|existing| existing.is_writable)
+            .reduce(|acc, is_writable| acc || is_writable)
+        {
+            meta.is_writable &= is_writable;
+        }
+        meta.is_signer = false;
+
+        let Some(account_info) = account_infos.iter().find(|info| *info.key == meta.pubkey) else {
+            let mut accounts = cpi_instruction.accounts[extra_accounts_start..].to_vec();
+            accounts.push(meta);
+            let report = MissingGateAccount {
+                gating_program: cpi_instruction.program_id,
+                accounts,
+            }
+            .pack();
+            if report.len() <= MAX_RETURN_DATA {
+                set_return_data(&report);
+            }
+            return Err(AccountResolutionError::IncorrectAccount.into());
+        };
+
+        cpi_instruction.accounts.push(meta);
+        cpi_account_infos.push(account_info.clone());
+    }
+
+    Ok(())
+}
+
+/// Splits the additional accounts of a `ThawPermissionless` instruction into one
+/// slice per chained gating program, following the layout produced by
+/// [`crate::offchain::add_extra_account_metas_for_thaw_gates`].
+pub fn split_thaw_gate_accounts<'b, 'a>(
+    mint: &Pubkey,
+    gating_programs: &[Pubkey],
+    additional_accounts: &'b [AccountInfo<'a>],
+) -> Result<Vec<&'b [AccountInfo<'a>]>, ProgramError> {
+    split_gate_accounts::<instruction::CanThawPermissionlessInstruction>(
+        gating_programs,
+        additional_accounts,
+        |program_id| get_thaw_extra_account_metas_address(mint, program_id),
+    )
+}
+
+/// Splits the additional accounts of a `FreezePermissionless` instruction into one
+/// slice per chained gating program, following the layout produced by
+/// [`crate::offchain::add_extra_account_metas_for_freeze_gates`].
+pub fn split_freeze_gate_accounts<'b, 'a>(
+    mint: &Pubkey,
+    gating_programs: &[Pubkey],
+    additional_accounts: &'b [AccountInfo<'a>],
+) -> Result<Vec<&'b [AccountInfo<'a>]>, ProgramError> {
+    split_gate_accounts::<instruction::CanFreezePermissionlessInstruction>(
+        gating_programs,
+        additional_accounts,
+        |program_id| get_freeze_extra_account_metas_address(mint, program_id),
+    )
+}
+
+/// Splits additional accounts laid out by the offchain resolver for a chain of gating programs.
+///
+/// The accounts of each gate are its extra account metas validation account followed by the
+/// resolved extra accounts, or nothing if the gate has no validation account. Every gate after
+/// the first is preceded by its program account, which is checked against `gating_programs`
+/// and left out of the returned slices.
+fn split_gate_accounts<'b, 'a, T: SplDiscriminate>(
+    gating_programs: &[Pubkey],
+    additional_accounts: &'b [AccountInfo<'a>],
+    validation_address: impl Fn(&Pubkey) -> Pubkey,
+) -> Result<Vec<&'b [AccountInfo<'a>]>, ProgramError> {
+    let mut slices = Vec::with_capacity(gating_programs.len());
+    let mut rest = additional_accounts;
+
+    for (i, program_id) in gating_programs.iter().enumerate() {
+        if i > 0 {
+            let [program_info, tail @ ..] = rest else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            if program_info.key != program_id {
+                return Err(AccountResolutionError::IncorrectAccount.into());
+            }
+            rest = tail;
+        }
+
+        let validation_pubkey = validation_address(program_id);
+        let len = match rest.first() {
+            Some(validation_info) if *validation_info.key == validation_pubkey => {
+                let data = validation_info.try_borrow_data()?;
+                let state = TlvStateBorrowed::unpack(&data)?;
+                1 + ExtraAccountMetaList::unpack_with_tlv_state::<T>(&state)?.len()
+            }
+            _ => 0,
+        };
+
+        if rest.len() < len {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        let (slice, tail) = rest.split_at(len);
+        slices.push(slice);
+        rest = tail;
+    }
+
+    Ok(slices)
+}
+
+/// Checks that `flag_account` is the Token ACL flag account of `token_account` and that it's
+/// set for `expected_op`, which is only the case while Token ACL is processing that
+/// permissionless operation on the account.
+pub fn verify_flag_account(
+    flag_account: &AccountInfo,
+    token_account: &Pubkey,
+    expected_op: PermissionlessOperation,
+) -> ProgramResult {
+    if *flag_account.key != get_flag_account_address(token_account, &TOKEN_ACL_ID) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if flag_account.owner != &TOKEN_ACL_ID {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    if *flag_account.try_borrow_data()? != [expected_op as u8] {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+/// Creates the thaw or freeze extra account metas PDA of `mint`, as selected by `operation`,
+/// and stores `extra_account_metas` in it. `payer` covers the rent.
+pub fn initialize_extra_account_metas<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    extra_metas: &AccountInfo<'a>,
+    mint: &Pubkey,
+    operation: PermissionlessOperation,
+    extra_account_metas: &[ExtraAccountMeta],
+) -> ProgramResult {
+    let seeds = extra_account_metas_seeds(mint, operation);
+    let (extra_metas_key, bump) = Pubkey::find_program_address(&seeds, program_id);
+    if extra_metas_key != *extra_metas.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let size = ExtraAccountMetaList::size_of(extra_account_metas.len())?;
+    top_up_rent(payer, extra_metas, size)?;
+
+    let bump_seed = [bump];
+    let signer_seeds = [seeds[0], seeds[1], &bump_seed];
+
+    let ix = solana_system_interface::instruction::allocate(extra_metas.key, size as u64);
+    invoke_signed(&ix, std::slice::from_ref(extra_metas), &[&signer_seeds])?;
+
+    let ix = solana_system_interface::instruction::assign(extra_metas.key, program_id);
+    invoke_signed(&ix, std::slice::from_ref(extra_metas), &[&signer_seeds])?;
+
+    let data = &mut extra_metas.try_borrow_mut_data()?;
+    match operation {
+        PermissionlessOperation::Thaw => ExtraAccountMetaList::init::<
+            instruction::CanThawPermissionlessInstruction,
+        >(data, extra_account_metas),
+        PermissionlessOperation::Freeze => ExtraAccountMetaList::init::<
+            instruction::CanFreezePermissionlessInstruction,
+        >(data, extra_account_metas),
+    }
+}
+
+/// Replaces the extra account metas stored in the thaw or freeze extra account metas PDA of
+/// `mint`, resizing it to fit. `payer` covers the rent when it grows and gets the excess back
+/// when it shrinks.
+pub fn update_extra_account_metas<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    extra_metas: &AccountInfo<'a>,
+    mint: &Pubkey,
+    operation: PermissionlessOperation,
+    extra_account_metas: &[ExtraAccountMeta],
+) -> ProgramResult {
+    let seeds = extra_account_metas_seeds(mint, operation);
+    let (extra_metas_key, _) = Pubkey::find_program_address(&seeds, program_id);
+    if extra_metas_key != *extra_metas.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if extra_metas.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let size = ExtraAccountMetaList::size_of(extra_account_metas.len())?;
+    top_up_rent(payer, extra_metas, size)?;
+
+    // grow before rewriting the list and shrink after, the list has to fit either way
+    if size > extra_metas.data_len() {
+        extra_metas.resize(size)?;
+    }
+
+    {
+        let data = &mut extra_metas.try_borrow_mut_data()?;
+        match operation {
+            PermissionlessOperation::Thaw => ExtraAccountMetaList::update::<
+                instruction::CanThawPermissionlessInstruction,
+            >(data, extra_account_metas)?,
+            PermissionlessOperation::Freeze => ExtraAccountMetaList::update::<
+                instruction::CanFreezePermissionlessInstruction,
+            >(data, extra_account_metas)?,
+        }
+    }
+
+    if size < extra_metas.data_len() {
+        extra_metas.resize(size)?;
+
+        let excess = extra_metas
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(size));
+        **extra_metas.try_borrow_mut_lamports()? -= excess;
+        **payer.try_borrow_mut_lamports()? += excess;
+    }
+
+    Ok(())
+}
+
+fn extra_account_metas_seeds(mint: &Pubkey, operation: PermissionlessOperation) -> [&[u8]; 2] {
+    match operation {
+        PermissionlessOperation::Thaw => collect_thaw_extra_account_metas(mint),
+        PermissionlessOperation::Freeze => collect_freeze_extra_account_metas(mint),
+    }
+}
+
+fn top_up_rent<'a>(
+    payer: &AccountInfo<'a>,
+    account: &AccountInfo<'a>,
+    size: usize,
+) -> ProgramResult {
+    let lamports = Rent::get()?.minimum_balance(size);
+    if account.lamports() < lamports {
+        let ix = solana_system_interface::instruction::transfer(
+            payer.key,
+            account.key,
+            lamports - account.lamports(),
+        );
+        invoke(&ix, &[payer.clone(), account.clone()])?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+//! Generates the `forbid-additional-mints` allowlist from `TOKEN_ACL_ALLOWED_MINTS`, a comma
+//! separated list of mint addresses. Invalid addresses fail the build.
+
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=TOKEN_ACL_ALLOWED_MINTS");
+
+    let mints = env::var("TOKEN_ACL_ALLOWED_MINTS").unwrap_or_default();
+    let entries: String = mints
+        .split(',')
+        .map(str::trim)
+        .filter(|mint| !mint.is_empty())
+        .map(|mint| format!("    Pubkey::from_str_const({mint:?}),\n"))
+        .collect();
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(
+        Path::new(&out_dir).join("allowed_mints.rs"),
+        format!("pub const ALLOWED_MINTS: &[Pubkey] = &[\n{entries}];\n"),
+    )
+    .unwrap();
+}
@@ -0,0 +1,14 @@
+//! Mints allowed to create a config when the program is built with the
+//! `forbid-additional-mints` feature, for instances dedicated to a single issuer.
+//! Any other mint is rejected by `CreateConfig`.
+//!
+//! The list is read at build time from `TOKEN_ACL_ALLOWED_MINTS`, a comma separated list of
+//! mint addresses, see `build.rs`.
+
+use solana_program::pubkey::Pubkey;
+
+include!(concat!(env!("OUT_DIR"), "/allowed_mints.rs"));
+
+pub fn is_mint_allowed(mint: &Pubkey) -> bool {
+    ALLOWED_MINTS.contains(mint)
+}
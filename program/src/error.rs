@@ -12,6 +12,23 @@ pub enum TokenAclError {
     PermissionlessFreezeNotEnabled,
     InvalidTokenAccountOwner,
     InvalidFlagAccount,
+    NoPendingAuthority,
+    MintNotAllowed,
+    MintConfigMigrationRequired,
+    ConfigPaused,
+    GatingProgramTimelocked,
+    GatingProgramChangeNotActive,
+    InvalidPendingGatingProgram,
+    InvalidAssociatedTokenProgram,
+    InvalidFreezeReason,
+    TokenAccountStillFrozen,
+    FreezeReasonRequired,
+    TokenAccountStickyFrozen,
+    InvalidFeeVault,
+    InsufficientFeeVaultBalance,
+    InvalidThawExpiry,
+    ThawExpiryStillActive,
+    ExternalTokenMetadata,
 }
 
 impl From<TokenAclError> for ProgramError {
@@ -0,0 +1,357 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::{log::sol_log_data, pubkey::Pubkey};
+use spl_pod::primitives::{PodBool, PodI64, PodU32, PodU64};
+
+/// Events are logged with `sol_log_data` as a single buffer made of the event
+/// discriminator followed by the event struct bytes.
+pub trait Event: Pod {
+    const DISCRIMINATOR: u8;
+
+    fn emit(&self) {
+        let mut data = Vec::with_capacity(1 + core::mem::size_of::<Self>());
+        data.push(Self::DISCRIMINATOR);
+        data.extend_from_slice(bytemuck::bytes_of(self));
+        sol_log_data(&[&data]);
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct ConfigCreated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub gating_program: Pubkey,
+}
+
+impl Event for ConfigCreated {
+    const DISCRIMINATOR: u8 = 0;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct ConfigDeleted {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub new_freeze_authority: Pubkey,
+}
+
+impl Event for ConfigDeleted {
+    const DISCRIMINATOR: u8 = 1;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct AuthorityChanged {
+    pub mint: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+impl Event for AuthorityChanged {
+    const DISCRIMINATOR: u8 = 2;
+}
+
+/// Emitted when a transfer is proposed, or cancelled with a default `pending_authority`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct PendingAuthorityChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+impl Event for PendingAuthorityChanged {
+    const DISCRIMINATOR: u8 = 3;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct FreezeOperatorChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub new_freeze_operator: Pubkey,
+}
+
+impl Event for FreezeOperatorChanged {
+    const DISCRIMINATOR: u8 = 4;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct ThawOperatorChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub new_thaw_operator: Pubkey,
+}
+
+impl Event for ThawOperatorChanged {
+    const DISCRIMINATOR: u8 = 5;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct GatingProgramChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub old_gating_program: Pubkey,
+    pub new_gating_program: Pubkey,
+}
+
+impl Event for GatingProgramChanged {
+    const DISCRIMINATOR: u8 = 6;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct PermissionlessInstructionsToggled {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub freeze_enabled: PodBool,
+    pub thaw_enabled: PodBool,
+}
+
+impl Event for PermissionlessInstructionsToggled {
+    const DISCRIMINATOR: u8 = 7;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct TokenAccountFrozen {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub caller: Pubkey,
+    pub permissionless: PodBool,
+}
+
+impl Event for TokenAccountFrozen {
+    const DISCRIMINATOR: u8 = 8;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct TokenAccountThawed {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub caller: Pubkey,
+    pub permissionless: PodBool,
+}
+
+impl Event for TokenAccountThawed {
+    const DISCRIMINATOR: u8 = 9;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct ConfigMigrated {
+    pub mint: Pubkey,
+    pub old_version: u8,
+    pub new_version: u8,
+}
+
+impl Event for ConfigMigrated {
+    const DISCRIMINATOR: u8 = 10;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct GatingChainChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub additional_gating_programs: [Pubkey; 3],
+    pub thaw_gate_mode: u8,
+    pub freeze_gate_mode: u8,
+}
+
+impl Event for GatingChainChanged {
+    const DISCRIMINATOR: u8 = 11;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct FreezeGatingProgramChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub old_freeze_gating_program: Pubkey,
+    pub new_freeze_gating_program: Pubkey,
+}
+
+impl Event for FreezeGatingProgramChanged {
+    const DISCRIMINATOR: u8 = 12;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct PausedChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub paused: PodBool,
+}
+
+impl Event for PausedChanged {
+    const DISCRIMINATOR: u8 = 13;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct PauseGuardianChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub new_pause_guardian: Pubkey,
+}
+
+impl Event for PauseGuardianChanged {
+    const DISCRIMINATOR: u8 = 14;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct GatingProgramQueued {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub new_gating_program: Pubkey,
+    pub new_gating_program_delay: PodI64,
+    pub activation_timestamp: PodI64,
+}
+
+impl Event for GatingProgramQueued {
+    const DISCRIMINATOR: u8 = 15;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct QueuedGatingProgramCancelled {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub new_gating_program: Pubkey,
+}
+
+impl Event for QueuedGatingProgramCancelled {
+    const DISCRIMINATOR: u8 = 16;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct GatingProgramDelayChanged {
+    pub mint: Pubkey,
+    pub old_gating_program_delay: PodI64,
+    pub new_gating_program_delay: PodI64,
+}
+
+impl Event for GatingProgramDelayChanged {
+    const DISCRIMINATOR: u8 = 17;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct FreezeReasonRecorded {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub authority: Pubkey,
+    pub reason_code: PodU32,
+    pub memo_hash: [u8; 32],
+}
+
+impl Event for FreezeReasonRecorded {
+    const DISCRIMINATOR: u8 = 18;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct StickyFreezeChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub sticky_freeze: PodBool,
+}
+
+impl Event for StickyFreezeChanged {
+    const DISCRIMINATOR: u8 = 19;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct PermissionlessFeeChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub permissionless_fee: PodU64,
+}
+
+impl Event for PermissionlessFeeChanged {
+    const DISCRIMINATOR: u8 = 20;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct FeesWithdrawn {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub receiver: Pubkey,
+    pub amount: PodU64,
+}
+
+impl Event for FeesWithdrawn {
+    const DISCRIMINATOR: u8 = 21;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct ThawTtlChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub thaw_ttl: PodI64,
+}
+
+impl Event for ThawTtlChanged {
+    const DISCRIMINATOR: u8 = 22;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct ThawExpiryRecorded {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub expires_at: PodI64,
+}
+
+impl Event for ThawExpiryRecorded {
+    const DISCRIMINATOR: u8 = 23;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct FreezeReasonClosed {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub receiver: Pubkey,
+}
+
+impl Event for FreezeReasonClosed {
+    const DISCRIMINATOR: u8 = 24;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct ThawExpiryClosed {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub receiver: Pubkey,
+}
+
+impl Event for ThawExpiryClosed {
+    const DISCRIMINATOR: u8 = 25;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct GatingChainQueued {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub new_additional_gating_programs: [Pubkey; 3],
+    pub new_thaw_gate_mode: u8,
+    pub new_freeze_gate_mode: u8,
+    pub new_freeze_gating_program: Pubkey,
+    pub activation_timestamp: PodI64,
+}
+
+impl Event for GatingChainQueued {
+    const DISCRIMINATOR: u8 = 26;
+}
@@ -0,0 +1,61 @@
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+use solana_program_error::{ProgramError, ProgramResult};
+
+use crate::{
+    error::TokenAclError,
+    events::{AuthorityChanged, Event},
+    state::load_mint_config_mut,
+};
+
+pub struct AcceptAuthority<'a> {
+    pub pending_authority: &'a AccountInfo<'a>,
+    pub mint_config: &'a AccountInfo<'a>,
+}
+
+impl AcceptAuthority<'_> {
+    pub const DISCRIMINATOR: u8 = 12;
+
+    pub fn process(&self) -> ProgramResult {
+        let data = &mut self.mint_config.data.borrow_mut();
+        let config = load_mint_config_mut(data)?;
+
+        if !config.has_pending_authority() {
+            return Err(TokenAclError::NoPendingAuthority.into());
+        }
+
+        if config.pending_authority != *self.pending_authority.key {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        AuthorityChanged {
+            mint: config.mint,
+            old_authority: config.freeze_authority,
+            new_authority: config.pending_authority,
+        }
+        .emit();
+
+        config.freeze_authority = config.pending_authority;
+        config.pending_authority = Pubkey::default();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for AcceptAuthority<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [pending_authority, mint_config] = &accounts else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        if !pending_authority.is_signer {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        Ok(Self {
+            pending_authority,
+            mint_config,
+        })
+    }
+}
@@ -0,0 +1,60 @@
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+use solana_program_error::{ProgramError, ProgramResult};
+
+use crate::{
+    error::TokenAclError,
+    events::{Event, PendingAuthorityChanged},
+    state::load_mint_config_mut,
+};
+
+pub struct CancelAuthorityTransfer<'a> {
+    pub authority: &'a AccountInfo<'a>,
+    pub mint_config: &'a AccountInfo<'a>,
+}
+
+impl CancelAuthorityTransfer<'_> {
+    pub const DISCRIMINATOR: u8 = 13;
+
+    pub fn process(&self) -> ProgramResult {
+        let data = &mut self.mint_config.data.borrow_mut();
+        let config = load_mint_config_mut(data)?;
+
+        if config.freeze_authority != *self.authority.key {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        if !config.has_pending_authority() {
+            return Err(TokenAclError::NoPendingAuthority.into());
+        }
+
+        config.pending_authority = Pubkey::default();
+
+        PendingAuthorityChanged {
+            mint: config.mint,
+            authority: *self.authority.key,
+            pending_authority: Pubkey::default(),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for CancelAuthorityTransfer<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [authority, mint_config] = &accounts else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        if !authority.is_signer {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        Ok(Self {
+            authority,
+            mint_config,
+        })
+    }
+}
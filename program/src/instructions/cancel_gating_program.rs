@@ -0,0 +1,80 @@
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+use solana_program_error::{ProgramError, ProgramResult};
+
+use crate::{
+    error::TokenAclError,
+    events::{Event, QueuedGatingProgramCancelled},
+    state::{load_mint_config, load_pending_gating_program_mut},
+};
+
+pub struct CancelGatingProgram<'a> {
+    pub authority: &'a AccountInfo<'a>,
+    pub mint_config: &'a AccountInfo<'a>,
+    pub pending_gating_program: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+}
+
+impl CancelGatingProgram<'_> {
+    pub const DISCRIMINATOR: u8 = 25;
+
+    pub fn process(&self) -> ProgramResult {
+        let data = &self.mint_config.data.borrow();
+        let config = load_mint_config(data)?;
+
+        if config.freeze_authority != *self.authority.key {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        let pending =
+            *load_pending_gating_program_mut(&mut self.pending_gating_program.data.borrow_mut())?;
+
+        if pending.mint != config.mint || pending.payer != *self.payer.key {
+            return Err(TokenAclError::InvalidPendingGatingProgram.into());
+        }
+
+        QueuedGatingProgramCancelled {
+            mint: config.mint,
+            authority: *self.authority.key,
+            new_gating_program: pending.new_gating_program,
+        }
+        .emit();
+
+        // close the pending change
+        self.pending_gating_program.data.borrow_mut().fill(0);
+        self.pending_gating_program.resize(0)?;
+        self.pending_gating_program.assign(&Pubkey::default());
+        **self.payer.try_borrow_mut_lamports()? += self.pending_gating_program.lamports();
+        **self.pending_gating_program.try_borrow_mut_lamports()? = 0;
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for CancelGatingProgram<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [authority, mint_config, pending_gating_program, payer] = &accounts else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        if !authority.is_signer {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        if mint_config.owner != &crate::ID {
+            return Err(TokenAclError::InvalidMintConfig.into());
+        }
+
+        if pending_gating_program.owner != &crate::ID {
+            return Err(TokenAclError::InvalidPendingGatingProgram.into());
+        }
+
+        Ok(Self {
+            authority,
+            mint_config,
+            pending_gating_program,
+            payer,
+        })
+    }
+}
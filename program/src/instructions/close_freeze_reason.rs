@@ -0,0 +1,82 @@
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+use solana_program_error::{ProgramError, ProgramResult};
+use spl_token_2022::{extension::StateWithExtensions, state::AccountState};
+
+use crate::{
+    error::TokenAclError,
+    events::{Event, FreezeReasonClosed},
+    state::load_freeze_reason_mut,
+};
+
+pub struct CloseFreezeReason<'a> {
+    pub token_account: &'a AccountInfo<'a>,
+    pub freeze_reason: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+}
+
+impl CloseFreezeReason<'_> {
+    pub const DISCRIMINATOR: u8 = 29;
+
+    pub fn process(&self) -> ProgramResult {
+        // closed token accounts have no state left to check
+        if crate::is_token_program(self.token_account.owner) {
+            let ta_data = self.token_account.data.borrow();
+            let ta = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&ta_data)?;
+
+            if ta.base.state == AccountState::Frozen {
+                return Err(TokenAclError::TokenAccountStillFrozen.into());
+            }
+        }
+
+        close_freeze_reason(self.freeze_reason, self.payer, self.token_account.key)
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for CloseFreezeReason<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [token_account, freeze_reason, payer] = &accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        Ok(Self {
+            token_account,
+            freeze_reason,
+            payer,
+        })
+    }
+}
+
+/// Closes the token account's freeze reason, returning the rent to the payer that
+/// created it.
+pub(crate) fn close_freeze_reason(
+    freeze_reason: &AccountInfo,
+    payer: &AccountInfo,
+    token_account: &Pubkey,
+) -> ProgramResult {
+    if freeze_reason.owner != &crate::ID {
+        return Err(TokenAclError::InvalidFreezeReason.into());
+    }
+
+    let reason = *load_freeze_reason_mut(&mut freeze_reason.data.borrow_mut())?;
+
+    if reason.token_account != *token_account || reason.payer != *payer.key {
+        return Err(TokenAclError::InvalidFreezeReason.into());
+    }
+
+    freeze_reason.data.borrow_mut().fill(0);
+    freeze_reason.resize(0)?;
+    freeze_reason.assign(&Pubkey::default());
+    **payer.try_borrow_mut_lamports()? += freeze_reason.lamports();
+    **freeze_reason.try_borrow_mut_lamports()? = 0;
+
+    FreezeReasonClosed {
+        mint: reason.mint,
+        token_account: *token_account,
+        receiver: *payer.key,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+use solana_program_error::{ProgramError, ProgramResult};
+use spl_token_2022::{extension::StateWithExtensions, state::AccountState};
+
+use crate::{
+    error::TokenAclError,
+    events::{Event, ThawExpiryClosed},
+    state::load_thaw_expiry_mut,
+};
+
+pub struct CloseThawExpiry<'a> {
+    pub token_account: &'a AccountInfo<'a>,
+    pub thaw_expiry: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+}
+
+impl CloseThawExpiry<'_> {
+    pub const DISCRIMINATOR: u8 = 34;
+
+    pub fn process(&self) -> ProgramResult {
+        if self.thaw_expiry.owner != &crate::ID {
+            return Err(TokenAclError::InvalidThawExpiry.into());
+        }
+
+        let expiry = *load_thaw_expiry_mut(&mut self.thaw_expiry.data.borrow_mut())?;
+
+        if expiry.token_account != *self.token_account.key || expiry.payer != *self.payer.key {
+            return Err(TokenAclError::InvalidThawExpiry.into());
+        }
+
+        // closed token accounts have no state left to check, a thawed account keeps its
+        // expiry until it's frozen again
+        if expiry.expires_at() != 0 && crate::is_token_program(self.token_account.owner) {
+            let ta_data = self.token_account.data.borrow();
+            let ta = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&ta_data)?;
+
+            if ta.base.state != AccountState::Frozen {
+                return Err(TokenAclError::ThawExpiryStillActive.into());
+            }
+        }
+
+        self.thaw_expiry.data.borrow_mut().fill(0);
+        self.thaw_expiry.resize(0)?;
+        self.thaw_expiry.assign(&Pubkey::default());
+        **self.payer.try_borrow_mut_lamports()? += self.thaw_expiry.lamports();
+        **self.thaw_expiry.try_borrow_mut_lamports()? = 0;
+
+        ThawExpiryClosed {
+            mint: expiry.mint,
+            token_account: *self.token_account.key,
+            receiver: *self.payer.key,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for CloseThawExpiry<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [token_account, thaw_expiry, payer] = &accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        Ok(Self {
+            token_account,
+            thaw_expiry,
+            payer,
+        })
+    }
+}
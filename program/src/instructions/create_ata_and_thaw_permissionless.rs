@@ -0,0 +1,62 @@
+use solana_cpi::invoke;
+use solana_program::account_info::AccountInfo;
+use solana_program_error::{ProgramError, ProgramResult};
+use spl_associated_token_account_interface::instruction::create_associated_token_account_idempotent;
+
+use crate::{error::TokenAclError, instructions::ThawPermissionless};
+
+pub struct CreateAtaAndThawPermissionless<'a> {
+    pub associated_token_program: &'a AccountInfo<'a>,
+    pub thaw_permissionless: ThawPermissionless<'a>,
+}
+
+impl CreateAtaAndThawPermissionless<'_> {
+    pub const DISCRIMINATOR: u8 = 26;
+
+    pub fn process(&self) -> ProgramResult {
+        let thaw = &self.thaw_permissionless;
+
+        // the associated token program validates the token account address
+        let ix = create_associated_token_account_idempotent(
+            thaw.authority.key,
+            thaw.token_account_owner.key,
+            thaw.mint.key,
+            thaw.token_program.key,
+        );
+        invoke(
+            &ix,
+            &[
+                thaw.authority.clone(),
+                thaw.token_account.clone(),
+                thaw.token_account_owner.clone(),
+                thaw.mint.clone(),
+                thaw.system_program.clone(),
+                thaw.token_program.clone(),
+                self.associated_token_program.clone(),
+            ],
+        )?;
+
+        // an existing account that isn't frozen is left as is
+        thaw.process(true)
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for CreateAtaAndThawPermissionless<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [associated_token_program, thaw_permissionless_accounts @ ..] = &accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !spl_associated_token_account_interface::program::check_id(associated_token_program.key)
+        {
+            return Err(TokenAclError::InvalidAssociatedTokenProgram.into());
+        }
+
+        Ok(Self {
+            associated_token_program,
+            thaw_permissionless: ThawPermissionless::try_from(thaw_permissionless_accounts)?,
+        })
+    }
+}
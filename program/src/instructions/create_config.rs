@@ -12,7 +12,12 @@ use spl_token_2022::{
     pod::PodMint,
 };
 
-use crate::{error::TokenAclError, state::MintConfig};
+use crate::{
+    error::TokenAclError,
+    events::{ConfigCreated, Event},
+    instructions::set_gating_program::update_token_acl_metadata,
+    state::MintConfig,
+};
 
 pub struct CreateConfig<'a> {
     pub payer: &'a AccountInfo<'a>,
@@ -21,6 +26,8 @@ pub struct CreateConfig<'a> {
     pub mint_config: &'a AccountInfo<'a>,
     pub system_program: &'a AccountInfo<'a>,
     pub token_program: &'a AccountInfo<'a>,
+    /// When present, the `token_acl` metadata key is set to the gating program.
+    pub metadata_update_authority: Option<&'a AccountInfo<'a>>,
     pub config_bump: u8,
 }
 
@@ -34,14 +41,23 @@ impl CreateConfig<'_> {
         let gating_program =
             Pubkey::try_from(remaining_data).map_err(|_| ProgramError::InvalidInstructionData)?;
 
+        #[cfg(feature = "forbid-additional-mints")]
+        if !crate::allowed_mints::is_mint_allowed(self.mint.key) {
+            return Err(TokenAclError::MintNotAllowed.into());
+        }
+
         let mint_data = self.mint.data.borrow_mut();
         let mint = PodStateWithExtensions::<PodMint>::unpack(&mint_data)?;
 
-        // if no freeze authority, or DSA extension is not present,
+        // if no freeze authority, or DSA extension is not present on a Token-2022 mint,
         // this is an invalid mint for this standard
         // these can't also be changed or activated later for existing mints
-        mint.get_extension::<DefaultAccountState>()
-            .map_err(|_| Into::<ProgramError>::into(TokenAclError::InvalidTokenMint))?;
+        // SPL Token mints have no extensions, accounts are created initialized and have to
+        // be frozen by the issuer or a wrapper program instead
+        if spl_token_2022::check_id(self.token_program.key) {
+            mint.get_extension::<DefaultAccountState>()
+                .map_err(|_| Into::<ProgramError>::into(TokenAclError::InvalidTokenMint))?;
+        }
 
         let freeze_authority = mint
             .base
@@ -91,13 +107,33 @@ impl CreateConfig<'_> {
         let config = pod_from_bytes_mut::<MintConfig>(data)?;
 
         config.discriminator = MintConfig::DISCRIMINATOR;
+        config.version = MintConfig::VERSION;
         config.mint = *self.mint.key;
         config.freeze_authority = *self.authority.key;
+        config.freeze_operator = *self.authority.key;
+        config.thaw_operator = *self.authority.key;
         config.gating_program = gating_program;
         config.bump = self.config_bump;
         config.enable_permissionless_freeze = PodBool::from_bool(false);
         config.enable_permissionless_thaw = PodBool::from_bool(false);
 
+        ConfigCreated {
+            mint: *self.mint.key,
+            authority: *self.authority.key,
+            gating_program,
+        }
+        .emit();
+
+        if let Some(metadata_update_authority) = self.metadata_update_authority {
+            update_token_acl_metadata(
+                self.mint,
+                self.token_program,
+                metadata_update_authority,
+                self.payer,
+                &gating_program,
+            )?;
+        }
+
         // finally, set the freeze authority over to the token-acl owned config account
         let ix = spl_token_2022::instruction::set_authority(
             self.token_program.key,
@@ -117,10 +153,18 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for CreateConfig<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
-        let [payer, authority, mint, mint_config, system_program, token_program] = &accounts else {
+        let [payer, authority, mint, mint_config, system_program, token_program, metadata_accounts @ ..] =
+            &accounts
+        else {
             return Err(ProgramError::InvalidInstructionData);
         };
 
+        let metadata_update_authority = match metadata_accounts {
+            [] => None,
+            [metadata_update_authority] => Some(metadata_update_authority),
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+
         if !authority.is_signer {
             return Err(TokenAclError::InvalidAuthority.into());
         }
@@ -136,11 +180,11 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for CreateConfig<'a> {
             return Err(TokenAclError::InvalidSystemProgram.into());
         }
 
-        if !spl_token_2022::check_id(token_program.key) {
+        if !crate::is_token_program(token_program.key) {
             return Err(TokenAclError::InvalidTokenProgram.into());
         }
 
-        if !spl_token_2022::check_id(mint.owner) {
+        if mint.owner != token_program.key {
             return Err(TokenAclError::InvalidTokenProgram.into());
         }
 
@@ -151,6 +195,7 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for CreateConfig<'a> {
             mint_config,
             system_program,
             token_program,
+            metadata_update_authority,
             config_bump,
         })
     }
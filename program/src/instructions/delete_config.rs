@@ -5,7 +5,11 @@ use spl_token_2022::{extension::PodStateWithExtensions, instruction::AuthorityTy
 
 use crate::{
     error::TokenAclError,
-    state::{load_mint_config, MintConfig},
+    events::{ConfigDeleted, Event},
+    state::{
+        load_mint_config, load_pending_gating_program_mut, MintConfig,
+        PENDING_GATING_PROGRAM_SEED_PREFIX,
+    },
 };
 
 pub struct DeleteConfig<'a> {
@@ -14,6 +18,12 @@ pub struct DeleteConfig<'a> {
     pub mint: &'a AccountInfo<'a>,
     pub mint_config: &'a AccountInfo<'a>,
     pub token_program: &'a AccountInfo<'a>,
+    /// Queued gate change of the config, closed with the config so the change can't be
+    /// finalized into a config created later for the mint.
+    pub pending_gating_program: &'a AccountInfo<'a>,
+    /// Account that paid for the queued gate change, refunded when it's closed. Unchecked
+    /// when nothing is queued.
+    pub pending_payer: &'a AccountInfo<'a>,
 }
 
 impl DeleteConfig<'_> {
@@ -31,11 +41,8 @@ impl DeleteConfig<'_> {
         let mint_data = self.mint.data.borrow_mut();
         let mint = PodStateWithExtensions::<PodMint>::unpack(&mint_data);
         let set_freeze_authority = mint
-            .and_then(|mint| {
-                Ok(
-                    mint.base.freeze_authority.unwrap_or(Pubkey::default())
-                        == *self.mint_config.key,
-                )
+            .map(|mint| {
+                mint.base.freeze_authority.unwrap_or(Pubkey::default()) == *self.mint_config.key
             })
             .unwrap_or(false);
         drop(mint_data);
@@ -73,11 +80,43 @@ impl DeleteConfig<'_> {
             )?;
         }
 
+        self.close_pending_gating_program()?;
+
         **self.receiver.try_borrow_mut_lamports()? += self.mint_config.lamports();
         **self.mint_config.try_borrow_mut_lamports()? = 0;
         self.mint_config.resize(0)?;
         self.mint_config.assign(&Pubkey::default());
 
+        ConfigDeleted {
+            mint: *self.mint.key,
+            authority: *self.authority.key,
+            new_freeze_authority,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    /// Closes the pending gate change to its payer, if there is one.
+    fn close_pending_gating_program(&self) -> ProgramResult {
+        // nothing is queued
+        if self.pending_gating_program.owner != &crate::ID {
+            return Ok(());
+        }
+
+        let payer =
+            load_pending_gating_program_mut(&mut self.pending_gating_program.data.borrow_mut())?
+                .payer;
+        if payer != *self.pending_payer.key {
+            return Err(TokenAclError::InvalidPendingGatingProgram.into());
+        }
+
+        self.pending_gating_program.data.borrow_mut().fill(0);
+        self.pending_gating_program.resize(0)?;
+        self.pending_gating_program.assign(&Pubkey::default());
+        **self.pending_payer.try_borrow_mut_lamports()? += self.pending_gating_program.lamports();
+        **self.pending_gating_program.try_borrow_mut_lamports()? = 0;
+
         Ok(())
     }
 }
@@ -86,7 +125,9 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for DeleteConfig<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
-        let [authority, receiver, mint, mint_config, token_program] = &accounts else {
+        let [authority, receiver, mint, mint_config, token_program, pending_gating_program, pending_payer] =
+            &accounts
+        else {
             return Err(ProgramError::InvalidInstructionData);
         };
 
@@ -94,16 +135,26 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for DeleteConfig<'a> {
             return Err(TokenAclError::InvalidAuthority.into());
         }
 
-        if !spl_token_2022::check_id(token_program.key) {
+        if !crate::is_token_program(token_program.key) {
             return Err(TokenAclError::InvalidTokenProgram.into());
         }
 
+        let (pending_key, _) = Pubkey::find_program_address(
+            &[PENDING_GATING_PROGRAM_SEED_PREFIX, mint.key.as_ref()],
+            &crate::ID,
+        );
+        if pending_key != *pending_gating_program.key {
+            return Err(TokenAclError::InvalidPendingGatingProgram.into());
+        }
+
         Ok(Self {
             authority,
             receiver,
             mint,
             mint_config,
             token_program,
+            pending_gating_program,
+            pending_payer,
         })
     }
 }
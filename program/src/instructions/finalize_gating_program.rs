@@ -0,0 +1,148 @@
+use solana_program::{account_info::AccountInfo, clock::Clock, pubkey::Pubkey};
+use solana_program_error::{ProgramError, ProgramResult};
+use solana_sysvar::Sysvar;
+
+use crate::{
+    error::TokenAclError,
+    events::{
+        Event, FreezeGatingProgramChanged, GatingChainChanged, GatingProgramChanged,
+        GatingProgramDelayChanged,
+    },
+    instructions::{update_token_acl_metadata, MetadataAccounts},
+    state::{load_mint_config_mut, load_pending_gating_program_mut},
+};
+
+pub struct FinalizeGatingProgram<'a> {
+    pub mint_config: &'a AccountInfo<'a>,
+    pub pending_gating_program: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    /// Syncs the mint's `token_acl` metadata key like `SetGatingProgram` does, which
+    /// needs the metadata update authority to sign.
+    pub metadata: Option<MetadataAccounts<'a>>,
+}
+
+impl FinalizeGatingProgram<'_> {
+    pub const DISCRIMINATOR: u8 = 24;
+
+    pub fn process(&self) -> ProgramResult {
+        let data = &mut self.mint_config.data.borrow_mut();
+        let config = load_mint_config_mut(data)?;
+
+        let pending =
+            *load_pending_gating_program_mut(&mut self.pending_gating_program.data.borrow_mut())?;
+
+        if pending.mint != config.mint || pending.payer != *self.payer.key {
+            return Err(TokenAclError::InvalidPendingGatingProgram.into());
+        }
+
+        if Clock::get()?.unix_timestamp < pending.activation_timestamp() {
+            return Err(TokenAclError::GatingProgramChangeNotActive.into());
+        }
+
+        if pending.is_gating_program_queued() {
+            GatingProgramChanged {
+                mint: config.mint,
+                authority: config.freeze_authority,
+                old_gating_program: config.gating_program,
+                new_gating_program: pending.new_gating_program,
+            }
+            .emit();
+
+            if config.gating_program_delay != pending.new_gating_program_delay {
+                GatingProgramDelayChanged {
+                    mint: config.mint,
+                    old_gating_program_delay: config.gating_program_delay,
+                    new_gating_program_delay: pending.new_gating_program_delay,
+                }
+                .emit();
+            }
+
+            config.gating_program = pending.new_gating_program;
+            config.gating_program_delay = pending.new_gating_program_delay;
+        }
+
+        if pending.is_gating_chain_queued() {
+            GatingChainChanged {
+                mint: config.mint,
+                authority: config.freeze_authority,
+                additional_gating_programs: pending.new_additional_gating_programs,
+                thaw_gate_mode: pending.new_thaw_gate_mode,
+                freeze_gate_mode: pending.new_freeze_gate_mode,
+            }
+            .emit();
+
+            if config.freeze_gating_program != pending.new_freeze_gating_program {
+                FreezeGatingProgramChanged {
+                    mint: config.mint,
+                    authority: config.freeze_authority,
+                    old_freeze_gating_program: config.freeze_gating_program,
+                    new_freeze_gating_program: pending.new_freeze_gating_program,
+                }
+                .emit();
+            }
+
+            config.additional_gating_programs = pending.new_additional_gating_programs;
+            config.thaw_gate_mode = pending.new_thaw_gate_mode;
+            config.freeze_gate_mode = pending.new_freeze_gate_mode;
+            config.freeze_gating_program = pending.new_freeze_gating_program;
+        }
+
+        if let Some(metadata) = &self.metadata {
+            if config.mint != *metadata.mint.key {
+                return Err(TokenAclError::InvalidTokenMint.into());
+            }
+
+            update_token_acl_metadata(
+                metadata.mint,
+                metadata.token_program,
+                metadata.metadata_update_authority,
+                metadata.metadata_update_authority,
+                &config.gating_program,
+            )?;
+        }
+
+        // close the pending change
+        self.pending_gating_program.data.borrow_mut().fill(0);
+        self.pending_gating_program.resize(0)?;
+        self.pending_gating_program.assign(&Pubkey::default());
+        **self.payer.try_borrow_mut_lamports()? += self.pending_gating_program.lamports();
+        **self.pending_gating_program.try_borrow_mut_lamports()? = 0;
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for FinalizeGatingProgram<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [mint_config, pending_gating_program, payer, metadata_accounts @ ..] = &accounts else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        if mint_config.owner != &crate::ID {
+            return Err(TokenAclError::InvalidMintConfig.into());
+        }
+
+        if pending_gating_program.owner != &crate::ID {
+            return Err(TokenAclError::InvalidPendingGatingProgram.into());
+        }
+
+        let metadata = match metadata_accounts {
+            [] => None,
+            [mint, token_program, metadata_update_authority] => Some(MetadataAccounts {
+                mint,
+                token_program,
+                metadata_update_authority,
+            }),
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+
+        Ok(Self {
+            mint_config,
+            pending_gating_program,
+            payer,
+            metadata,
+        })
+    }
+}
@@ -1,9 +1,11 @@
 use solana_cpi::invoke_signed;
 use solana_program::account_info::AccountInfo;
 use solana_program_error::{ProgramError, ProgramResult};
+use spl_pod::primitives::PodBool;
 
 use crate::{
     error::TokenAclError,
+    events::{Event, TokenAccountFrozen},
     state::{load_mint_config, MintConfig},
 };
 
@@ -22,7 +24,7 @@ impl Freeze<'_> {
         let data = &self.mint_config.data.borrow();
         let config = load_mint_config(data)?;
 
-        if config.freeze_authority != *self.authority.key {
+        if config.freeze_operator != *self.authority.key {
             return Err(TokenAclError::InvalidAuthority.into());
         }
 
@@ -30,6 +32,10 @@ impl Freeze<'_> {
             return Err(TokenAclError::InvalidTokenMint.into());
         }
 
+        if config.is_sticky_freeze() {
+            return Err(TokenAclError::FreezeReasonRequired.into());
+        }
+
         let bump_seed = [config.bump];
         let seeds = [MintConfig::SEED_PREFIX, self.mint.key.as_ref(), &bump_seed];
 
@@ -50,6 +56,14 @@ impl Freeze<'_> {
             &[&seeds],
         )?;
 
+        TokenAccountFrozen {
+            mint: *self.mint.key,
+            token_account: *self.token_account.key,
+            caller: *self.authority.key,
+            permissionless: PodBool::from_bool(false),
+        }
+        .emit();
+
         Ok(())
     }
 }
@@ -66,7 +80,7 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for Freeze<'a> {
             return Err(TokenAclError::InvalidAuthority.into());
         }
 
-        if !spl_token_2022::check_id(token_program.key) {
+        if !crate::is_token_program(token_program.key) {
             return Err(TokenAclError::InvalidTokenProgram.into());
         }
 
@@ -0,0 +1,144 @@
+use solana_cpi::invoke_signed;
+use solana_program::account_info::AccountInfo;
+use solana_program_error::{ProgramError, ProgramResult};
+use spl_pod::primitives::PodBool;
+use spl_token_2022::{extension::StateWithExtensions, state::AccountState};
+
+use crate::{
+    error::TokenAclError,
+    events::{Event, TokenAccountFrozen},
+    instructions::freeze_with_reason::{record_freeze_reason, unpack_freeze_reason},
+    state::{load_mint_config, MintConfig},
+};
+
+pub struct FreezeBatchWithReason<'a> {
+    pub authority: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    pub mint: &'a AccountInfo<'a>,
+    pub mint_config: &'a AccountInfo<'a>,
+    pub token_program: &'a AccountInfo<'a>,
+    pub system_program: &'a AccountInfo<'a>,
+    pub token_accounts: &'a [AccountInfo<'a>],
+}
+
+impl FreezeBatchWithReason<'_> {
+    pub const DISCRIMINATOR: u8 = 28;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let [skip_frozen, reason_data @ ..] = remaining_data else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+        let skip_frozen = *skip_frozen != 0;
+        let (reason_code, memo_hash) = unpack_freeze_reason(reason_data)?;
+
+        let data = &self.mint_config.data.borrow();
+        let config = load_mint_config(data)?;
+
+        if config.freeze_operator != *self.authority.key {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        if config.mint != *self.mint.key {
+            return Err(TokenAclError::InvalidTokenMint.into());
+        }
+
+        let bump_seed = [config.bump];
+        let seeds = [MintConfig::SEED_PREFIX, self.mint.key.as_ref(), &bump_seed];
+
+        for pair in self.token_accounts.chunks(2) {
+            let [token_account, freeze_reason] = pair else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            if skip_frozen {
+                let ta_data = token_account.data.borrow();
+                let ta = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&ta_data)?;
+
+                // freeze CPI enforces the mint, but skipped accounts never reach it
+                if ta.base.mint != *self.mint.key {
+                    return Err(TokenAclError::InvalidTokenMint.into());
+                }
+
+                // already frozen accounts keep the reason they were frozen with
+                if ta.base.state == AccountState::Frozen {
+                    continue;
+                }
+            }
+
+            let ix = spl_token_2022::instruction::freeze_account(
+                self.token_program.key,
+                token_account.key,
+                self.mint.key,
+                self.mint_config.key,
+                &[],
+            )?;
+            invoke_signed(
+                &ix,
+                &[
+                    token_account.clone(),
+                    self.mint.clone(),
+                    self.mint_config.clone(),
+                ],
+                &[&seeds],
+            )?;
+
+            TokenAccountFrozen {
+                mint: *self.mint.key,
+                token_account: *token_account.key,
+                caller: *self.authority.key,
+                permissionless: PodBool::from_bool(false),
+            }
+            .emit();
+
+            record_freeze_reason(
+                self.payer,
+                freeze_reason,
+                self.mint.key,
+                token_account.key,
+                self.authority.key,
+                reason_code,
+                memo_hash,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for FreezeBatchWithReason<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [authority, payer, mint, mint_config, token_program, system_program, token_accounts @ ..] =
+            &accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !authority.is_signer {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        if !crate::is_token_program(token_program.key) {
+            return Err(TokenAclError::InvalidTokenProgram.into());
+        }
+
+        if !solana_system_interface::program::check_id(system_program.key) {
+            return Err(TokenAclError::InvalidSystemProgram.into());
+        }
+
+        if mint_config.owner != &crate::ID {
+            return Err(TokenAclError::InvalidMintConfig.into());
+        }
+
+        Ok(Self {
+            authority,
+            payer,
+            mint,
+            mint_config,
+            token_program,
+            system_program,
+            token_accounts,
+        })
+    }
+}
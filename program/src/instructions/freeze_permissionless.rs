@@ -1,13 +1,24 @@
 use solana_cpi::invoke_signed;
-use solana_program::account_info::AccountInfo;
+use solana_program::{account_info::AccountInfo, clock::Clock};
 use solana_program_error::{ProgramError, ProgramResult};
 use solana_pubkey::Pubkey;
+use solana_sysvar::Sysvar;
+use spl_pod::primitives::{PodBool, PodI64};
 use spl_token_2022::{extension::StateWithExtensions, state::AccountState};
-use token_acl_interface::onchain::invoke_can_freeze_permissionless;
+use token_acl_interface::{
+    onchain::{invoke_can_freeze_permissionless, split_freeze_gate_accounts},
+    PermissionlessOperation,
+};
 
 use crate::{
     error::TokenAclError,
-    state::{load_mint_config, MintConfig, FLAG_ACCOUNT_SEED_PREFIX},
+    events::{Event, TokenAccountFrozen},
+    instructions::set_permissionless_fee::collect_permissionless_fee,
+    permissionless_accounts::PermissionlessAccounts,
+    state::{
+        load_mint_config, load_thaw_expiry_mut, GateMode, MintConfig, FLAG_ACCOUNT_SEED_PREFIX,
+        THAW_EXPIRY_SEED_PREFIX,
+    },
 };
 
 pub struct FreezePermissionless<'a> {
@@ -35,11 +46,33 @@ impl FreezePermissionless<'_> {
             return Err(TokenAclError::InvalidTokenMint.into());
         }
 
-        if !config.is_permissionless_freeze_enabled() {
+        let accounts = PermissionlessAccounts::split(
+            &config,
+            PermissionlessOperation::Freeze,
+            self.remaining_accounts,
+        )?;
+
+        // once the thaw has expired, anyone can freeze the account without the gate's approval
+        let is_thaw_expired = match accounts.thaw_expiry {
+            Some(thaw_expiry) => is_thaw_expired(thaw_expiry, self.token_account.key)?,
+            None => false,
+        };
+
+        if !is_thaw_expired && !config.is_permissionless_freeze_enabled() {
             return Err(TokenAclError::PermissionlessFreezeNotEnabled.into());
         }
 
-        if config.gating_program != *self.gating_program.key {
+        if config.is_paused() {
+            return Err(TokenAclError::ConfigPaused.into());
+        }
+
+        let gating_programs: Vec<Pubkey> = config.freeze_gating_programs().copied().collect();
+        let gate_mode = config.freeze_gate_mode()?;
+        let is_valid_gating_program = match gate_mode {
+            GateMode::All => gating_programs[0] == *self.gating_program.key,
+            GateMode::Any => gating_programs.contains(self.gating_program.key),
+        };
+        if !is_thaw_expired && !is_valid_gating_program {
             return Err(TokenAclError::InvalidGatingProgram.into());
         }
 
@@ -51,51 +84,74 @@ impl FreezePermissionless<'_> {
                 return Err(TokenAclError::InvalidTokenAccountOwner.into());
             }
 
-            if is_idempotent {
-                if ta.base.state != AccountState::Initialized {
-                    // freeze CPI enforces ta.base.mint == self.mint.key, but we're returning early
-                    // so we need to check it to enforce same behaviour regardless of idempotency
-                    if ta.base.mint != *self.mint.key {
-                        return Err(TokenAclError::InvalidTokenMint.into());
-                    }
-                    return Ok(());
+            if is_idempotent && ta.base.state != AccountState::Initialized {
+                // freeze CPI enforces ta.base.mint == self.mint.key, but we're returning early
+                // so we need to check it to enforce same behaviour regardless of idempotency
+                if ta.base.mint != *self.mint.key {
+                    return Err(TokenAclError::InvalidTokenMint.into());
                 }
+                return Ok(());
             }
         }
 
-        let bump_seed = [self.flag_account_bump];
-        let seeds = [
-            FLAG_ACCOUNT_SEED_PREFIX,
-            self.token_account.key.as_ref(),
-            &bump_seed,
-        ];
+        collect_permissionless_fee(&config, self.authority, accounts.fee_vault)?;
 
-        // allocate, assign and initialize flag account
-        let ix = solana_system_interface::instruction::allocate(self.flag_account.key, 1 as u64);
-        invoke_signed(
-            &ix,
-            &[self.authority.clone(), self.flag_account.clone()],
-            &[&seeds],
-        )?;
+        if !is_thaw_expired {
+            let bump_seed = [self.flag_account_bump];
+            let seeds = [
+                FLAG_ACCOUNT_SEED_PREFIX,
+                self.token_account.key.as_ref(),
+                &bump_seed,
+            ];
 
-        let ix = solana_system_interface::instruction::assign(self.flag_account.key, &crate::ID);
-        invoke_signed(
-            &ix,
-            &[self.authority.clone(), self.flag_account.clone()],
-            &[&seeds],
-        )?;
+            // allocate, assign and initialize flag account
+            let ix = solana_system_interface::instruction::allocate(self.flag_account.key, 1_u64);
+            invoke_signed(
+                &ix,
+                &[self.authority.clone(), self.flag_account.clone()],
+                &[&seeds],
+            )?;
 
-        self.flag_account.data.borrow_mut()[0] = 1;
+            let ix =
+                solana_system_interface::instruction::assign(self.flag_account.key, &crate::ID);
+            invoke_signed(
+                &ix,
+                &[self.authority.clone(), self.flag_account.clone()],
+                &[&seeds],
+            )?;
 
-        invoke_can_freeze_permissionless(
-            self.gating_program.key,
-            self.authority.clone(),
-            self.token_account.clone(),
-            self.mint.clone(),
-            self.token_account_owner.clone(),
-            self.flag_account.clone(),
-            self.remaining_accounts,
-        )?;
+            self.flag_account.data.borrow_mut()[0] = PermissionlessOperation::Freeze as u8;
+
+            if gate_mode == GateMode::All && gating_programs.len() > 1 {
+                // every gate in the chain gets its own slice of the remaining accounts
+                let gate_accounts = split_freeze_gate_accounts(
+                    self.mint.key,
+                    &gating_programs,
+                    accounts.gate_accounts,
+                )?;
+                for (gating_program, accounts) in gating_programs.iter().zip(gate_accounts) {
+                    invoke_can_freeze_permissionless(
+                        gating_program,
+                        self.authority.clone(),
+                        self.token_account.clone(),
+                        self.mint.clone(),
+                        self.token_account_owner.clone(),
+                        self.flag_account.clone(),
+                        accounts,
+                    )?;
+                }
+            } else {
+                invoke_can_freeze_permissionless(
+                    self.gating_program.key,
+                    self.authority.clone(),
+                    self.token_account.clone(),
+                    self.mint.clone(),
+                    self.token_account_owner.clone(),
+                    self.flag_account.clone(),
+                    accounts.gate_accounts,
+                )?;
+            }
+        }
 
         let bump_seed = [config.bump];
         let seeds = [MintConfig::SEED_PREFIX, self.mint.key.as_ref(), &bump_seed];
@@ -117,12 +173,26 @@ impl FreezePermissionless<'_> {
             &[&seeds],
         )?;
 
-        // clean up flag account
-        self.flag_account.data.borrow_mut()[0] = 0;
-        self.flag_account.resize(0)?;
-        self.flag_account.assign(&Pubkey::default());
-        **self.authority.try_borrow_mut_lamports()? += self.flag_account.lamports();
-        **self.flag_account.try_borrow_mut_lamports()? = 0;
+        TokenAccountFrozen {
+            mint: *self.mint.key,
+            token_account: *self.token_account.key,
+            caller: *self.authority.key,
+            permissionless: PodBool::from_bool(true),
+        }
+        .emit();
+
+        if let Some(thaw_expiry) = accounts.thaw_expiry {
+            clear_thaw_expiry(thaw_expiry)?;
+        }
+
+        if !is_thaw_expired {
+            // clean up flag account
+            self.flag_account.data.borrow_mut()[0] = 0;
+            self.flag_account.resize(0)?;
+            self.flag_account.assign(&Pubkey::default());
+            **self.authority.try_borrow_mut_lamports()? += self.flag_account.lamports();
+            **self.flag_account.try_borrow_mut_lamports()? = 0;
+        }
 
         Ok(())
     }
@@ -142,7 +212,7 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for FreezePermissionless<'a> {
             return Err(TokenAclError::InvalidAuthority.into());
         }
 
-        if !spl_token_2022::check_id(token_program.key) {
+        if !crate::is_token_program(token_program.key) {
             return Err(TokenAclError::InvalidTokenProgram.into());
         }
 
@@ -178,3 +248,42 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for FreezePermissionless<'a> {
         })
     }
 }
+
+/// Whether the token account's thaw expiry has passed, accounts without one never expire.
+fn is_thaw_expired(
+    thaw_expiry: &AccountInfo,
+    token_account: &Pubkey,
+) -> Result<bool, ProgramError> {
+    check_thaw_expiry(thaw_expiry.key, token_account)?;
+
+    if thaw_expiry.owner != &crate::ID {
+        return Ok(false);
+    }
+
+    let expiry = *load_thaw_expiry_mut(&mut thaw_expiry.data.borrow_mut())?;
+    Ok(expiry.is_expired(Clock::get()?.unix_timestamp))
+}
+
+/// Frozen accounts have nothing left to expire until they are thawed permissionlessly again,
+/// neither do accounts thawed by the thaw operator.
+pub(crate) fn clear_thaw_expiry(thaw_expiry: &AccountInfo) -> ProgramResult {
+    if thaw_expiry.owner == &crate::ID {
+        let data = &mut thaw_expiry.data.borrow_mut();
+        load_thaw_expiry_mut(data)?.expires_at = PodI64::from(0);
+    }
+
+    Ok(())
+}
+
+/// Checks that `thaw_expiry` is the thaw expiry PDA of the token account.
+pub(crate) fn check_thaw_expiry(thaw_expiry: &Pubkey, token_account: &Pubkey) -> ProgramResult {
+    let (derived_thaw_expiry, _) = Pubkey::find_program_address(
+        &[THAW_EXPIRY_SEED_PREFIX, token_account.as_ref()],
+        &crate::ID,
+    );
+    if derived_thaw_expiry != *thaw_expiry {
+        return Err(TokenAclError::InvalidThawExpiry.into());
+    }
+
+    Ok(())
+}
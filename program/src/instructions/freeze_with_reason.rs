@@ -0,0 +1,244 @@
+use solana_cpi::{invoke, invoke_signed};
+use solana_program::{account_info::AccountInfo, clock::Clock, pubkey::Pubkey};
+use solana_program_error::{ProgramError, ProgramResult};
+use solana_rent::Rent;
+use solana_sysvar::Sysvar;
+use spl_pod::{
+    bytemuck::pod_from_bytes_mut,
+    primitives::{PodBool, PodI64, PodU32},
+};
+use spl_token_2022::{extension::StateWithExtensions, state::AccountState};
+
+use crate::{
+    error::TokenAclError,
+    events::{Event, FreezeReasonRecorded, TokenAccountFrozen},
+    state::{
+        load_freeze_reason_mut, load_mint_config, FreezeReason, MintConfig,
+        FREEZE_REASON_SEED_PREFIX,
+    },
+};
+
+pub struct FreezeWithReason<'a> {
+    pub authority: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    pub mint: &'a AccountInfo<'a>,
+    pub token_account: &'a AccountInfo<'a>,
+    pub mint_config: &'a AccountInfo<'a>,
+    pub freeze_reason: &'a AccountInfo<'a>,
+    pub token_program: &'a AccountInfo<'a>,
+    pub system_program: &'a AccountInfo<'a>,
+}
+
+impl FreezeWithReason<'_> {
+    pub const DISCRIMINATOR: u8 = 27;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let (reason_code, memo_hash) = unpack_freeze_reason(remaining_data)?;
+
+        let data = &self.mint_config.data.borrow();
+        let config = load_mint_config(data)?;
+
+        if config.freeze_operator != *self.authority.key {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        if config.mint != *self.mint.key {
+            return Err(TokenAclError::InvalidTokenMint.into());
+        }
+
+        let is_frozen = {
+            let ta_data = self.token_account.data.borrow();
+            let ta = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&ta_data)?;
+
+            // freeze CPI enforces the mint, but frozen accounts never reach it
+            if ta.base.mint != *self.mint.key {
+                return Err(TokenAclError::InvalidTokenMint.into());
+            }
+
+            ta.base.state == AccountState::Frozen
+        };
+
+        // accounts frozen without a reason, e.g. by the default account state, only get
+        // the reason recorded, the token program refuses to freeze them again
+        if !is_frozen {
+            self.freeze(config.bump)?;
+        }
+
+        record_freeze_reason(
+            self.payer,
+            self.freeze_reason,
+            self.mint.key,
+            self.token_account.key,
+            self.authority.key,
+            reason_code,
+            memo_hash,
+        )
+    }
+
+    fn freeze(&self, bump: u8) -> ProgramResult {
+        let bump_seed = [bump];
+        let seeds = [MintConfig::SEED_PREFIX, self.mint.key.as_ref(), &bump_seed];
+
+        let ix = spl_token_2022::instruction::freeze_account(
+            self.token_program.key,
+            self.token_account.key,
+            self.mint.key,
+            self.mint_config.key,
+            &[],
+        )?;
+        invoke_signed(
+            &ix,
+            &[
+                self.token_account.clone(),
+                self.mint.clone(),
+                self.mint_config.clone(),
+            ],
+            &[&seeds],
+        )?;
+
+        TokenAccountFrozen {
+            mint: *self.mint.key,
+            token_account: *self.token_account.key,
+            caller: *self.authority.key,
+            permissionless: PodBool::from_bool(false),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for FreezeWithReason<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [authority, payer, mint, token_account, mint_config, freeze_reason, token_program, system_program] =
+            &accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !authority.is_signer {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        if !crate::is_token_program(token_program.key) {
+            return Err(TokenAclError::InvalidTokenProgram.into());
+        }
+
+        if !solana_system_interface::program::check_id(system_program.key) {
+            return Err(TokenAclError::InvalidSystemProgram.into());
+        }
+
+        if mint_config.owner != &crate::ID {
+            return Err(TokenAclError::InvalidMintConfig.into());
+        }
+
+        Ok(Self {
+            authority,
+            payer,
+            mint,
+            token_account,
+            mint_config,
+            freeze_reason,
+            token_program,
+            system_program,
+        })
+    }
+}
+
+/// Reason code followed by the memo hash.
+pub(crate) fn unpack_freeze_reason(data: &[u8]) -> Result<(u32, [u8; 32]), ProgramError> {
+    if data.len() != 36 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let reason_code = u32::from_le_bytes(
+        data[..4]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidInstructionData)?,
+    );
+    let memo_hash = data[4..]
+        .try_into()
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    Ok((reason_code, memo_hash))
+}
+
+/// Creates the token account's freeze reason, or overwrites a reason left over from
+/// a thaw that didn't clear it. The original payer keeps the rent claim.
+pub(crate) fn record_freeze_reason<'a>(
+    payer: &AccountInfo<'a>,
+    freeze_reason: &AccountInfo<'a>,
+    mint: &Pubkey,
+    token_account: &Pubkey,
+    authority: &Pubkey,
+    reason_code: u32,
+    memo_hash: [u8; 32],
+) -> ProgramResult {
+    let (freeze_reason_key, bump) = Pubkey::find_program_address(
+        &[FREEZE_REASON_SEED_PREFIX, token_account.as_ref()],
+        &crate::ID,
+    );
+    if freeze_reason_key != *freeze_reason.key {
+        return Err(TokenAclError::InvalidFreezeReason.into());
+    }
+
+    let frozen_at = Clock::get()?.unix_timestamp;
+
+    if freeze_reason.owner == &crate::ID {
+        let data = &mut freeze_reason.data.borrow_mut();
+        let reason = load_freeze_reason_mut(data)?;
+        reason.authority = *authority;
+        reason.reason_code = PodU32::from(reason_code);
+        reason.memo_hash = memo_hash;
+        reason.frozen_at = PodI64::from(frozen_at);
+    } else {
+        let lamports = Rent::get()?.minimum_balance(FreezeReason::LEN);
+
+        if freeze_reason.lamports() < lamports {
+            let diff = lamports - freeze_reason.lamports();
+
+            let ix =
+                solana_system_interface::instruction::transfer(payer.key, freeze_reason.key, diff);
+            invoke(&ix, &[payer.clone(), freeze_reason.clone()])?;
+        }
+
+        let bump_seed = [bump];
+        let seeds = [
+            FREEZE_REASON_SEED_PREFIX,
+            token_account.as_ref(),
+            &bump_seed,
+        ];
+
+        let allocate_ix = solana_system_interface::instruction::allocate(
+            freeze_reason.key,
+            FreezeReason::LEN as u64,
+        );
+        invoke_signed(&allocate_ix, std::slice::from_ref(freeze_reason), &[&seeds])?;
+
+        let assign_ix = solana_system_interface::instruction::assign(freeze_reason.key, &crate::ID);
+        invoke_signed(&assign_ix, std::slice::from_ref(freeze_reason), &[&seeds])?;
+
+        let data = &mut freeze_reason.data.borrow_mut();
+        let reason = pod_from_bytes_mut::<FreezeReason>(data)?;
+        reason.discriminator = FreezeReason::DISCRIMINATOR;
+        reason.bump = bump;
+        reason.mint = *mint;
+        reason.token_account = *token_account;
+        reason.authority = *authority;
+        reason.reason_code = PodU32::from(reason_code);
+        reason.memo_hash = memo_hash;
+        reason.frozen_at = PodI64::from(frozen_at);
+        reason.payer = *payer.key;
+    }
+
+    FreezeReasonRecorded {
+        mint: *mint,
+        token_account: *token_account,
+        authority: *authority,
+        reason_code: PodU32::from(reason_code),
+        memo_hash,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use solana_cpi::invoke;
+use solana_program::account_info::AccountInfo;
+use solana_program_error::{ProgramError, ProgramResult};
+use solana_rent::Rent;
+use solana_sysvar::Sysvar;
+
+use crate::{
+    error::TokenAclError,
+    events::{ConfigMigrated, Event},
+    state::{load_mint_config, load_mint_config_v1, MintConfig, MintConfigV1},
+};
+
+pub struct MigrateConfig<'a> {
+    pub payer: &'a AccountInfo<'a>,
+    pub mint_config: &'a AccountInfo<'a>,
+    pub system_program: &'a AccountInfo<'a>,
+}
+
+impl MigrateConfig<'_> {
+    pub const DISCRIMINATOR: u8 = 18;
+
+    pub fn process(&self) -> ProgramResult {
+        let old_config = {
+            let data = self.mint_config.data.borrow();
+            if data.len() != MintConfigV1::LEN {
+                // already on the current version, nothing to migrate
+                load_mint_config(&data)?;
+                return Ok(());
+            }
+            *load_mint_config_v1(&data)?
+        };
+
+        let lamports = Rent::get()?.minimum_balance(MintConfig::LEN);
+
+        if self.mint_config.lamports() < lamports {
+            let diff = lamports - self.mint_config.lamports();
+
+            let ix = solana_system_interface::instruction::transfer(
+                self.payer.key,
+                self.mint_config.key,
+                diff,
+            );
+            invoke(&ix, &[self.payer.clone(), self.mint_config.clone()])?;
+        }
+
+        self.mint_config.resize(MintConfig::LEN)?;
+
+        let config = MintConfig::from(old_config);
+        self.mint_config
+            .data
+            .borrow_mut()
+            .copy_from_slice(bytemuck::bytes_of(&config));
+
+        ConfigMigrated {
+            mint: config.mint,
+            old_version: MintConfigV1::VERSION,
+            new_version: MintConfig::VERSION,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for MigrateConfig<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [payer, mint_config, system_program] = &accounts else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        if mint_config.owner != &crate::ID {
+            return Err(TokenAclError::InvalidMintConfig.into());
+        }
+
+        if !solana_system_interface::program::check_id(system_program.key) {
+            return Err(TokenAclError::InvalidSystemProgram.into());
+        }
+
+        Ok(Self {
+            payer,
+            mint_config,
+            system_program,
+        })
+    }
+}
@@ -1,23 +1,73 @@
+pub mod accept_authority;
+pub mod cancel_authority_transfer;
+pub mod cancel_gating_program;
+pub mod close_freeze_reason;
+pub mod close_thaw_expiry;
+pub mod create_ata_and_thaw_permissionless;
 pub mod create_config;
 pub mod delete_config;
+pub mod finalize_gating_program;
 pub mod freeze;
+pub mod freeze_batch;
+pub mod freeze_batch_with_reason;
 pub mod freeze_permissionless;
 pub mod freeze_permissionless_idempotent;
+pub mod freeze_with_reason;
+pub mod migrate_config;
+pub mod propose_authority;
+pub mod queue_gating_chain;
+pub mod queue_gating_program;
 pub mod set_authority;
+pub mod set_freeze_gating_program;
+pub mod set_freeze_operator;
+pub mod set_gating_chain;
 pub mod set_gating_program;
+pub mod set_pause_guardian;
+pub mod set_paused;
+pub mod set_permissionless_fee;
+pub mod set_sticky_freeze;
+pub mod set_thaw_operator;
+pub mod set_thaw_ttl;
 pub mod thaw;
+pub mod thaw_batch;
 pub mod thaw_permissionless;
 pub mod thaw_permissionless_idempotent;
 pub mod toggle_permissionless_instructions;
+pub mod withdraw_fees;
 
+pub use accept_authority::*;
+pub use cancel_authority_transfer::*;
+pub use cancel_gating_program::*;
+pub use close_freeze_reason::*;
+pub use close_thaw_expiry::*;
+pub use create_ata_and_thaw_permissionless::*;
 pub use create_config::*;
 pub use delete_config::*;
+pub use finalize_gating_program::*;
 pub use freeze::*;
+pub use freeze_batch::*;
+pub use freeze_batch_with_reason::*;
 pub use freeze_permissionless::*;
 pub use freeze_permissionless_idempotent::*;
+pub use freeze_with_reason::*;
+pub use migrate_config::*;
+pub use propose_authority::*;
+pub use queue_gating_chain::*;
+pub use queue_gating_program::*;
 pub use set_authority::*;
+pub use set_freeze_gating_program::*;
+pub use set_freeze_operator::*;
+pub use set_gating_chain::*;
 pub use set_gating_program::*;
+pub use set_pause_guardian::*;
+pub use set_paused::*;
+pub use set_permissionless_fee::*;
+pub use set_sticky_freeze::*;
+pub use set_thaw_operator::*;
+pub use set_thaw_ttl::*;
 pub use thaw::*;
+pub use thaw_batch::*;
 pub use thaw_permissionless::*;
 pub use thaw_permissionless_idempotent::*;
 pub use toggle_permissionless_instructions::*;
+pub use withdraw_fees::*;
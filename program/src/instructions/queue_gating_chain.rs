@@ -0,0 +1,139 @@
+use solana_program::{account_info::AccountInfo, clock::Clock, pubkey::Pubkey};
+use solana_program_error::{ProgramError, ProgramResult};
+use solana_sysvar::Sysvar;
+use spl_pod::{
+    bytemuck::pod_from_bytes_mut,
+    primitives::{PodBool, PodI64},
+};
+
+use crate::{
+    error::TokenAclError,
+    events::{Event, GatingChainQueued},
+    instructions::{
+        create_pending_gating_program, unpack_gating_chain, GatingChain, GATING_CHAIN_LEN,
+    },
+    state::{
+        load_mint_config_mut, load_pending_gating_program_mut, PendingGatingProgram,
+        PENDING_GATING_PROGRAM_SEED_PREFIX,
+    },
+};
+
+/// Queues the changes `SetGatingChain` and `SetFreezeGatingProgram` make, for configs
+/// with a `gating_program_delay`. Both are queued together, the current values have to
+/// be passed for the parts that shouldn't change.
+pub struct QueueGatingChain<'a> {
+    pub authority: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    pub mint_config: &'a AccountInfo<'a>,
+    pub pending_gating_program: &'a AccountInfo<'a>,
+    pub system_program: &'a AccountInfo<'a>,
+}
+
+impl QueueGatingChain<'_> {
+    pub const DISCRIMINATOR: u8 = 35;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        if remaining_data.len() != GATING_CHAIN_LEN + 32 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let GatingChain {
+            additional_gating_programs,
+            thaw_gate_mode,
+            freeze_gate_mode,
+        } = unpack_gating_chain(&remaining_data[..GATING_CHAIN_LEN])?;
+        let new_freeze_gating_program = Pubkey::try_from(&remaining_data[GATING_CHAIN_LEN..])
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+        let data = &mut self.mint_config.data.borrow_mut();
+        let config = load_mint_config_mut(data)?;
+
+        if config.freeze_authority != *self.authority.key {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        let (pending_key, bump) = Pubkey::find_program_address(
+            &[PENDING_GATING_PROGRAM_SEED_PREFIX, config.mint.as_ref()],
+            &crate::ID,
+        );
+        if pending_key != *self.pending_gating_program.key {
+            return Err(TokenAclError::InvalidPendingGatingProgram.into());
+        }
+
+        let activation_timestamp = Clock::get()?
+            .unix_timestamp
+            .saturating_add(config.gating_program_delay());
+
+        if self.pending_gating_program.owner != &crate::ID {
+            create_pending_gating_program(
+                self.payer,
+                self.pending_gating_program,
+                &config.mint,
+                bump,
+            )?;
+
+            let pending_data = &mut self.pending_gating_program.data.borrow_mut();
+            let pending = pod_from_bytes_mut::<PendingGatingProgram>(pending_data)?;
+            pending.discriminator = PendingGatingProgram::DISCRIMINATOR;
+            pending.bump = bump;
+            pending.mint = config.mint;
+            pending.new_gating_program = config.gating_program;
+            pending.new_gating_program_delay = config.gating_program_delay;
+            pending.payer = *self.payer.key;
+        }
+
+        // re-queueing replaces the queued chain and restarts the delay, including for
+        // a gating program change queued alongside it
+        let pending_data = &mut self.pending_gating_program.data.borrow_mut();
+        let pending = load_pending_gating_program_mut(pending_data)?;
+        pending.new_additional_gating_programs = additional_gating_programs;
+        pending.new_thaw_gate_mode = thaw_gate_mode as u8;
+        pending.new_freeze_gate_mode = freeze_gate_mode as u8;
+        pending.new_freeze_gating_program = new_freeze_gating_program;
+        pending.activation_timestamp = PodI64::from(activation_timestamp);
+        pending.gating_chain_queued = PodBool::from(true);
+
+        GatingChainQueued {
+            mint: config.mint,
+            authority: *self.authority.key,
+            new_additional_gating_programs: additional_gating_programs,
+            new_thaw_gate_mode: thaw_gate_mode as u8,
+            new_freeze_gate_mode: freeze_gate_mode as u8,
+            new_freeze_gating_program,
+            activation_timestamp: PodI64::from(activation_timestamp),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for QueueGatingChain<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [authority, payer, mint_config, pending_gating_program, system_program] = &accounts
+        else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        if !authority.is_signer {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        if mint_config.owner != &crate::ID {
+            return Err(TokenAclError::InvalidMintConfig.into());
+        }
+
+        if !solana_system_interface::program::check_id(system_program.key) {
+            return Err(TokenAclError::InvalidSystemProgram.into());
+        }
+
+        Ok(Self {
+            authority,
+            payer,
+            mint_config,
+            pending_gating_program,
+            system_program,
+        })
+    }
+}
@@ -0,0 +1,184 @@
+use solana_cpi::{invoke, invoke_signed};
+use solana_program::{account_info::AccountInfo, clock::Clock, pubkey::Pubkey};
+use solana_program_error::{ProgramError, ProgramResult};
+use solana_rent::Rent;
+use solana_sysvar::Sysvar;
+use spl_pod::{
+    bytemuck::pod_from_bytes_mut,
+    primitives::{PodBool, PodI64},
+};
+
+use crate::{
+    error::TokenAclError,
+    events::{Event, GatingProgramQueued},
+    state::{
+        load_mint_config_mut, load_pending_gating_program_mut, PendingGatingProgram,
+        PENDING_GATING_PROGRAM_SEED_PREFIX,
+    },
+};
+
+pub struct QueueGatingProgram<'a> {
+    pub authority: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    pub mint_config: &'a AccountInfo<'a>,
+    pub pending_gating_program: &'a AccountInfo<'a>,
+    pub system_program: &'a AccountInfo<'a>,
+}
+
+impl QueueGatingProgram<'_> {
+    pub const DISCRIMINATOR: u8 = 23;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        if remaining_data.len() != 40 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let new_gating_program = Pubkey::try_from(&remaining_data[..32])
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        let new_gating_program_delay = i64::from_le_bytes(
+            remaining_data[32..]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+        if new_gating_program_delay < 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let data = &mut self.mint_config.data.borrow_mut();
+        let config = load_mint_config_mut(data)?;
+
+        if config.freeze_authority != *self.authority.key {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        let (pending_key, bump) = Pubkey::find_program_address(
+            &[PENDING_GATING_PROGRAM_SEED_PREFIX, config.mint.as_ref()],
+            &crate::ID,
+        );
+        if pending_key != *self.pending_gating_program.key {
+            return Err(TokenAclError::InvalidPendingGatingProgram.into());
+        }
+
+        // the delay in force when queueing applies, even if the change lowers it
+        let activation_timestamp = Clock::get()?
+            .unix_timestamp
+            .saturating_add(config.gating_program_delay());
+
+        if self.pending_gating_program.owner == &crate::ID {
+            // re-queueing replaces the pending change and restarts the delay
+            let pending_data = &mut self.pending_gating_program.data.borrow_mut();
+            let pending = load_pending_gating_program_mut(pending_data)?;
+            pending.new_gating_program = new_gating_program;
+            pending.new_gating_program_delay = PodI64::from(new_gating_program_delay);
+            pending.activation_timestamp = PodI64::from(activation_timestamp);
+            pending.gating_program_queued = PodBool::from(true);
+        } else {
+            create_pending_gating_program(
+                self.payer,
+                self.pending_gating_program,
+                &config.mint,
+                bump,
+            )?;
+
+            let pending_data = &mut self.pending_gating_program.data.borrow_mut();
+            let pending = pod_from_bytes_mut::<PendingGatingProgram>(pending_data)?;
+            pending.discriminator = PendingGatingProgram::DISCRIMINATOR;
+            pending.bump = bump;
+            pending.mint = config.mint;
+            pending.new_gating_program = new_gating_program;
+            pending.new_gating_program_delay = PodI64::from(new_gating_program_delay);
+            pending.activation_timestamp = PodI64::from(activation_timestamp);
+            pending.payer = *self.payer.key;
+            pending.gating_program_queued = PodBool::from(true);
+        }
+
+        GatingProgramQueued {
+            mint: config.mint,
+            authority: *self.authority.key,
+            new_gating_program,
+            new_gating_program_delay: PodI64::from(new_gating_program_delay),
+            activation_timestamp: PodI64::from(activation_timestamp),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for QueueGatingProgram<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [authority, payer, mint_config, pending_gating_program, system_program] = &accounts
+        else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        if !authority.is_signer {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        if mint_config.owner != &crate::ID {
+            return Err(TokenAclError::InvalidMintConfig.into());
+        }
+
+        if !solana_system_interface::program::check_id(system_program.key) {
+            return Err(TokenAclError::InvalidSystemProgram.into());
+        }
+
+        Ok(Self {
+            authority,
+            payer,
+            mint_config,
+            pending_gating_program,
+            system_program,
+        })
+    }
+}
+
+/// Allocates the `PendingGatingProgram` of `mint`, funded by `payer`.
+pub(crate) fn create_pending_gating_program<'a>(
+    payer: &AccountInfo<'a>,
+    pending_gating_program: &AccountInfo<'a>,
+    mint: &Pubkey,
+    bump: u8,
+) -> ProgramResult {
+    let lamports = Rent::get()?.minimum_balance(PendingGatingProgram::LEN);
+
+    if pending_gating_program.lamports() < lamports {
+        let diff = lamports - pending_gating_program.lamports();
+
+        let ix = solana_system_interface::instruction::transfer(
+            payer.key,
+            pending_gating_program.key,
+            diff,
+        );
+        invoke(&ix, &[payer.clone(), pending_gating_program.clone()])?;
+    }
+
+    let bump_seed = [bump];
+    let seeds = [
+        PENDING_GATING_PROGRAM_SEED_PREFIX,
+        mint.as_ref(),
+        &bump_seed,
+    ];
+
+    let allocate_ix = solana_system_interface::instruction::allocate(
+        pending_gating_program.key,
+        PendingGatingProgram::LEN as u64,
+    );
+    invoke_signed(
+        &allocate_ix,
+        &[payer.clone(), pending_gating_program.clone()],
+        &[&seeds],
+    )?;
+
+    let assign_ix =
+        solana_system_interface::instruction::assign(pending_gating_program.key, &crate::ID);
+    invoke_signed(
+        &assign_ix,
+        &[payer.clone(), pending_gating_program.clone()],
+        &[&seeds],
+    )?;
+
+    Ok(())
+}
@@ -1,23 +1,24 @@
 use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 use solana_program_error::{ProgramError, ProgramResult};
 
-use crate::{error::TokenAclError, state::load_mint_config_mut};
+use crate::{
+    error::TokenAclError,
+    events::{AuthorityChanged, Event},
+    state::load_mint_config_mut,
+};
 
 pub struct SetAuthority<'a> {
     pub authority: &'a AccountInfo<'a>,
     pub mint_config: &'a AccountInfo<'a>,
+    /// Signs as well, so that the config can't be handed to a key nobody controls. Same
+    /// guarantee as `ProposeAuthority` followed by `AcceptAuthority`, in one transaction.
+    pub new_authority: &'a AccountInfo<'a>,
 }
 
 impl SetAuthority<'_> {
     pub const DISCRIMINATOR: u8 = 1;
 
-    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
-        if remaining_data.len() != 32 {
-            return Err(ProgramError::InvalidInstructionData);
-        }
-        let new_authority =
-            Pubkey::try_from(remaining_data).map_err(|_| ProgramError::InvalidInstructionData)?;
-
+    pub fn process(&self) -> ProgramResult {
         let data = &mut self.mint_config.data.borrow_mut();
         let config = load_mint_config_mut(data)?;
 
@@ -25,7 +26,15 @@ impl SetAuthority<'_> {
             return Err(TokenAclError::InvalidAuthority.into());
         }
 
-        config.freeze_authority = new_authority;
+        config.freeze_authority = *self.new_authority.key;
+        config.pending_authority = Pubkey::default();
+
+        AuthorityChanged {
+            mint: config.mint,
+            old_authority: *self.authority.key,
+            new_authority: *self.new_authority.key,
+        }
+        .emit();
 
         Ok(())
     }
@@ -35,17 +44,18 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for SetAuthority<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
-        let [authority, mint_config] = &accounts else {
-            return Err(ProgramError::InvalidInstructionData);
+        let [authority, mint_config, new_authority] = &accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        if !authority.is_signer {
+        if !authority.is_signer || !new_authority.is_signer {
             return Err(TokenAclError::InvalidAuthority.into());
         }
 
         Ok(Self {
             authority,
             mint_config,
+            new_authority,
         })
     }
 }
@@ -0,0 +1,116 @@
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+use solana_program_error::{ProgramError, ProgramResult};
+
+use crate::{
+    error::TokenAclError,
+    events::{Event, GatingChainChanged},
+    state::{load_mint_config_mut, GateMode, MintConfig},
+};
+
+pub struct SetGatingChain<'a> {
+    pub authority: &'a AccountInfo<'a>,
+    pub mint_config: &'a AccountInfo<'a>,
+}
+
+impl SetGatingChain<'_> {
+    pub const DISCRIMINATOR: u8 = 19;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let GatingChain {
+            additional_gating_programs,
+            thaw_gate_mode,
+            freeze_gate_mode,
+        } = unpack_gating_chain(remaining_data)?;
+
+        let data = &mut self.mint_config.data.borrow_mut();
+        let config = load_mint_config_mut(data)?;
+
+        if config.freeze_authority != *self.authority.key {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        // timelocked configs have to go through `QueueGatingChain`
+        if config.gating_program_delay() != 0 {
+            return Err(TokenAclError::GatingProgramTimelocked.into());
+        }
+
+        config.additional_gating_programs = additional_gating_programs;
+        config.thaw_gate_mode = thaw_gate_mode as u8;
+        config.freeze_gate_mode = freeze_gate_mode as u8;
+
+        GatingChainChanged {
+            mint: config.mint,
+            authority: *self.authority.key,
+            additional_gating_programs,
+            thaw_gate_mode: thaw_gate_mode as u8,
+            freeze_gate_mode: freeze_gate_mode as u8,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for SetGatingChain<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [authority, mint_config] = &accounts else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        if !authority.is_signer {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        Ok(Self {
+            authority,
+            mint_config,
+        })
+    }
+}
+
+/// Length of a gating chain in instruction data: the additional gating programs
+/// followed by the thaw and freeze gate modes.
+pub(crate) const GATING_CHAIN_LEN: usize = 32 * MintConfig::MAX_ADDITIONAL_GATING_PROGRAMS + 2;
+
+pub(crate) struct GatingChain {
+    pub additional_gating_programs: [Pubkey; MintConfig::MAX_ADDITIONAL_GATING_PROGRAMS],
+    pub thaw_gate_mode: GateMode,
+    pub freeze_gate_mode: GateMode,
+}
+
+pub(crate) fn unpack_gating_chain(data: &[u8]) -> Result<GatingChain, ProgramError> {
+    if data.len() != GATING_CHAIN_LEN {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let programs_len = GATING_CHAIN_LEN - 2;
+
+    let mut additional_gating_programs =
+        [Pubkey::default(); MintConfig::MAX_ADDITIONAL_GATING_PROGRAMS];
+    for (program, chunk) in additional_gating_programs
+        .iter_mut()
+        .zip(data[..programs_len].chunks_exact(32))
+    {
+        *program = Pubkey::try_from(chunk).map_err(|_| ProgramError::InvalidInstructionData)?;
+    }
+
+    // unused slots have to be at the end so the chain order is unambiguous
+    if additional_gating_programs
+        .windows(2)
+        .any(|pair| pair[0] == Pubkey::default() && pair[1] != Pubkey::default())
+    {
+        return Err(TokenAclError::InvalidGatingProgram.into());
+    }
+
+    let thaw_gate_mode =
+        GateMode::try_from(data[programs_len]).map_err(|_| ProgramError::InvalidInstructionData)?;
+    let freeze_gate_mode = GateMode::try_from(data[programs_len + 1])
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    Ok(GatingChain {
+        additional_gating_programs,
+        thaw_gate_mode,
+        freeze_gate_mode,
+    })
+}
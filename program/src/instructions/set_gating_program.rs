@@ -1,11 +1,36 @@
+use solana_cpi::invoke;
 use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 use solana_program_error::{ProgramError, ProgramResult};
+use solana_rent::Rent;
+use solana_sysvar::Sysvar;
+use spl_token_2022::{
+    extension::{
+        metadata_pointer::MetadataPointer, BaseStateWithExtensions, PodStateWithExtensions,
+    },
+    pod::PodMint,
+};
+use spl_token_metadata_interface::state::{Field, TokenMetadata};
 
-use crate::{error::TokenAclError, state::load_mint_config_mut};
+use crate::{
+    error::TokenAclError,
+    events::{Event, GatingProgramChanged},
+    state::load_mint_config_mut,
+};
+
+/// Additional metadata key naming the gating program of a Token-2022 mint.
+pub const TOKEN_ACL_METADATA_KEY: &str = "token_acl";
 
 pub struct SetGatingProgram<'a> {
     pub authority: &'a AccountInfo<'a>,
     pub mint_config: &'a AccountInfo<'a>,
+    pub metadata: Option<MetadataAccounts<'a>>,
+}
+
+/// Optional accounts to keep the mint's `token_acl` metadata key in sync with the config.
+pub struct MetadataAccounts<'a> {
+    pub mint: &'a AccountInfo<'a>,
+    pub token_program: &'a AccountInfo<'a>,
+    pub metadata_update_authority: &'a AccountInfo<'a>,
 }
 
 impl SetGatingProgram<'_> {
@@ -25,8 +50,35 @@ impl SetGatingProgram<'_> {
             return Err(TokenAclError::InvalidAuthority.into());
         }
 
+        if config.gating_program_delay() != 0 {
+            return Err(TokenAclError::GatingProgramTimelocked.into());
+        }
+
+        GatingProgramChanged {
+            mint: config.mint,
+            authority: *self.authority.key,
+            old_gating_program: config.gating_program,
+            new_gating_program,
+        }
+        .emit();
+
         config.gating_program = new_gating_program;
 
+        if let Some(metadata) = &self.metadata {
+            if config.mint != *metadata.mint.key {
+                return Err(TokenAclError::InvalidTokenMint.into());
+            }
+
+            // the update authority also pays for the larger metadata
+            update_token_acl_metadata(
+                metadata.mint,
+                metadata.token_program,
+                metadata.metadata_update_authority,
+                metadata.metadata_update_authority,
+                &new_gating_program,
+            )?;
+        }
+
         Ok(())
     }
 }
@@ -35,7 +87,7 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for SetGatingProgram<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
-        let [authority, mint_config] = &accounts else {
+        let [authority, mint_config, metadata_accounts @ ..] = &accounts else {
             return Err(ProgramError::InvalidInstructionData);
         };
 
@@ -43,9 +95,75 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for SetGatingProgram<'a> {
             return Err(TokenAclError::InvalidAuthority.into());
         }
 
+        let metadata = match metadata_accounts {
+            [] => None,
+            [mint, token_program, metadata_update_authority] => Some(MetadataAccounts {
+                mint,
+                token_program,
+                metadata_update_authority,
+            }),
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+
         Ok(Self {
             authority,
             mint_config,
+            metadata,
         })
     }
 }
+
+/// Sets the `token_acl` metadata key of a Token-2022 mint to `gating_program`, with `payer`
+/// topping up the mint's rent when the metadata grows. Mints whose metadata pointer points to
+/// another account are refused, that metadata belongs to its own program.
+pub(crate) fn update_token_acl_metadata<'a>(
+    mint: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    metadata_update_authority: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    gating_program: &Pubkey,
+) -> ProgramResult {
+    // SPL Token mints have no metadata extension
+    if !spl_token_2022::check_id(token_program.key) || mint.owner != token_program.key {
+        return Err(TokenAclError::InvalidTokenProgram.into());
+    }
+
+    let value = gating_program.to_string();
+
+    let new_len = {
+        let mint_data = mint.data.borrow();
+        let mint_state = PodStateWithExtensions::<PodMint>::unpack(&mint_data)?;
+        if let Ok(pointer) = mint_state.get_extension::<MetadataPointer>() {
+            if Option::<Pubkey>::from(pointer.metadata_address)
+                .is_some_and(|address| address != *mint.key)
+            {
+                return Err(TokenAclError::ExternalTokenMetadata.into());
+            }
+        }
+
+        let mut metadata = mint_state.get_variable_len_extension::<TokenMetadata>()?;
+
+        let initial_tlv_size = metadata.tlv_size_of()?;
+        metadata.set_key_value(TOKEN_ACL_METADATA_KEY.to_string(), value.clone());
+        let new_tlv_size = metadata.tlv_size_of()?;
+
+        (mint_data.len() + new_tlv_size).saturating_sub(initial_tlv_size)
+    };
+
+    let lamports = Rent::get()?.minimum_balance(new_len);
+    if mint.lamports() < lamports {
+        let diff = lamports - mint.lamports();
+
+        let ix = solana_system_interface::instruction::transfer(payer.key, mint.key, diff);
+        invoke(&ix, &[payer.clone(), mint.clone()])?;
+    }
+
+    let ix = spl_token_metadata_interface::instruction::update_field(
+        token_program.key,
+        mint.key,
+        metadata_update_authority.key,
+        Field::Key(TOKEN_ACL_METADATA_KEY.to_string()),
+        value,
+    );
+    invoke(&ix, &[mint.clone(), metadata_update_authority.clone()])
+}
@@ -0,0 +1,66 @@
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+use solana_program_error::{ProgramError, ProgramResult};
+use spl_pod::primitives::PodBool;
+
+use crate::{
+    error::TokenAclError,
+    events::{Event, PausedChanged},
+    state::load_mint_config_mut,
+};
+
+pub struct SetPaused<'a> {
+    pub authority: &'a AccountInfo<'a>,
+    pub mint_config: &'a AccountInfo<'a>,
+}
+
+impl SetPaused<'_> {
+    pub const DISCRIMINATOR: u8 = 21;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let [paused] = remaining_data else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+        let paused = *paused != 0;
+
+        let data = &mut self.mint_config.data.borrow_mut();
+        let config = load_mint_config_mut(data)?;
+
+        // the pause guardian can only pause, unpausing is left to the admin
+        let is_admin = config.freeze_authority == *self.authority.key;
+        let is_guardian = config.pause_guardian != Pubkey::default()
+            && config.pause_guardian == *self.authority.key;
+        if !(is_admin || paused && is_guardian) {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        config.paused = PodBool::from_bool(paused);
+
+        PausedChanged {
+            mint: config.mint,
+            authority: *self.authority.key,
+            paused: config.paused,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for SetPaused<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [authority, mint_config] = &accounts else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        if !authority.is_signer {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        Ok(Self {
+            authority,
+            mint_config,
+        })
+    }
+}
@@ -0,0 +1,129 @@
+use solana_cpi::invoke;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+use solana_program_error::{ProgramError, ProgramResult};
+use solana_rent::Rent;
+use solana_sysvar::Sysvar;
+use spl_pod::primitives::PodU64;
+
+use crate::{
+    error::TokenAclError,
+    events::{Event, PermissionlessFeeChanged},
+    state::{load_mint_config_mut, MintConfig, FEE_VAULT_SEED_PREFIX},
+};
+
+pub struct SetPermissionlessFee<'a> {
+    pub authority: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    pub mint_config: &'a AccountInfo<'a>,
+    pub fee_vault: &'a AccountInfo<'a>,
+    pub system_program: &'a AccountInfo<'a>,
+}
+
+impl SetPermissionlessFee<'_> {
+    pub const DISCRIMINATOR: u8 = 31;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let permissionless_fee = u64::from_le_bytes(
+            remaining_data
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+
+        let data = &mut self.mint_config.data.borrow_mut();
+        let config = load_mint_config_mut(data)?;
+
+        if config.freeze_authority != *self.authority.key {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        check_fee_vault(self.fee_vault.key, &config.mint)?;
+
+        // fees below the rent exempt minimum couldn't fund an empty vault on their own
+        if permissionless_fee > 0 {
+            let minimum_balance = Rent::get()?.minimum_balance(0);
+            let lamports = minimum_balance.saturating_sub(self.fee_vault.lamports());
+            if lamports > 0 {
+                let ix = solana_system_interface::instruction::transfer(
+                    self.payer.key,
+                    self.fee_vault.key,
+                    lamports,
+                );
+                invoke(&ix, &[self.payer.clone(), self.fee_vault.clone()])?;
+            }
+        }
+
+        config.permissionless_fee = PodU64::from(permissionless_fee);
+
+        PermissionlessFeeChanged {
+            mint: config.mint,
+            authority: *self.authority.key,
+            permissionless_fee: config.permissionless_fee,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for SetPermissionlessFee<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [authority, payer, mint_config, fee_vault, system_program] = &accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !authority.is_signer {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        if !solana_system_interface::program::check_id(system_program.key) {
+            return Err(TokenAclError::InvalidSystemProgram.into());
+        }
+
+        if mint_config.owner != &crate::ID {
+            return Err(TokenAclError::InvalidMintConfig.into());
+        }
+
+        Ok(Self {
+            authority,
+            payer,
+            mint_config,
+            fee_vault,
+            system_program,
+        })
+    }
+}
+
+/// Checks that `fee_vault` is the fee vault PDA of the mint and returns its bump.
+pub(crate) fn check_fee_vault(fee_vault: &Pubkey, mint: &Pubkey) -> Result<u8, ProgramError> {
+    let (derived_fee_vault, bump) =
+        Pubkey::find_program_address(&[FEE_VAULT_SEED_PREFIX, mint.as_ref()], &crate::ID);
+
+    if derived_fee_vault != *fee_vault {
+        return Err(TokenAclError::InvalidFeeVault.into());
+    }
+
+    Ok(bump)
+}
+
+/// Charges the config's permissionless fee to `authority`, paid into `fee_vault`, which is
+/// only passed when the config charges a fee.
+pub(crate) fn collect_permissionless_fee<'a>(
+    config: &MintConfig,
+    authority: &AccountInfo<'a>,
+    fee_vault: Option<&AccountInfo<'a>>,
+) -> ProgramResult {
+    let Some(fee_vault) = fee_vault else {
+        return Ok(());
+    };
+
+    check_fee_vault(fee_vault.key, &config.mint)?;
+
+    let ix = solana_system_interface::instruction::transfer(
+        authority.key,
+        fee_vault.key,
+        config.permissionless_fee(),
+    );
+    invoke(&ix, &[authority.clone(), fee_vault.clone()])
+}
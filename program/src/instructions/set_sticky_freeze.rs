@@ -0,0 +1,62 @@
+use solana_program::account_info::AccountInfo;
+use solana_program_error::{ProgramError, ProgramResult};
+use spl_pod::primitives::PodBool;
+
+use crate::{
+    error::TokenAclError,
+    events::{Event, StickyFreezeChanged},
+    state::load_mint_config_mut,
+};
+
+pub struct SetStickyFreeze<'a> {
+    pub authority: &'a AccountInfo<'a>,
+    pub mint_config: &'a AccountInfo<'a>,
+}
+
+impl SetStickyFreeze<'_> {
+    pub const DISCRIMINATOR: u8 = 30;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let [sticky_freeze] = remaining_data else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+        let sticky_freeze = *sticky_freeze != 0;
+
+        let data = &mut self.mint_config.data.borrow_mut();
+        let config = load_mint_config_mut(data)?;
+
+        if config.freeze_authority != *self.authority.key {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        config.sticky_freeze = PodBool::from_bool(sticky_freeze);
+
+        StickyFreezeChanged {
+            mint: config.mint,
+            authority: *self.authority.key,
+            sticky_freeze: config.sticky_freeze,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for SetStickyFreeze<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [authority, mint_config] = &accounts else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        if !authority.is_signer {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        Ok(Self {
+            authority,
+            mint_config,
+        })
+    }
+}
@@ -0,0 +1,67 @@
+use solana_program::account_info::AccountInfo;
+use solana_program_error::{ProgramError, ProgramResult};
+use spl_pod::primitives::PodI64;
+
+use crate::{
+    error::TokenAclError,
+    events::{Event, ThawTtlChanged},
+    state::load_mint_config_mut,
+};
+
+pub struct SetThawTtl<'a> {
+    pub authority: &'a AccountInfo<'a>,
+    pub mint_config: &'a AccountInfo<'a>,
+}
+
+impl SetThawTtl<'_> {
+    pub const DISCRIMINATOR: u8 = 33;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let thaw_ttl = i64::from_le_bytes(
+            remaining_data
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+        if thaw_ttl < 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let data = &mut self.mint_config.data.borrow_mut();
+        let config = load_mint_config_mut(data)?;
+
+        if config.freeze_authority != *self.authority.key {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        // only applies to thaws from now on. With 0, freezes stop checking recorded expiries
+        config.thaw_ttl = PodI64::from(thaw_ttl);
+
+        ThawTtlChanged {
+            mint: config.mint,
+            authority: *self.authority.key,
+            thaw_ttl: config.thaw_ttl,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for SetThawTtl<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [authority, mint_config] = &accounts else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        if !authority.is_signer {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        Ok(Self {
+            authority,
+            mint_config,
+        })
+    }
+}
@@ -1,10 +1,17 @@
 use solana_cpi::invoke_signed;
 use solana_program::account_info::AccountInfo;
 use solana_program_error::{ProgramError, ProgramResult};
+use solana_pubkey::Pubkey;
+use spl_pod::primitives::PodBool;
 
 use crate::{
     error::TokenAclError,
-    state::{load_mint_config, MintConfig},
+    events::{Event, TokenAccountThawed},
+    instructions::{
+        close_freeze_reason::close_freeze_reason,
+        freeze_permissionless::{check_thaw_expiry, clear_thaw_expiry},
+    },
+    state::{load_mint_config, MintConfig, FREEZE_REASON_SEED_PREFIX},
 };
 
 pub struct Thaw<'a> {
@@ -13,6 +20,10 @@ pub struct Thaw<'a> {
     pub token_account: &'a AccountInfo<'a>,
     pub mint_config: &'a AccountInfo<'a>,
     pub token_program: &'a AccountInfo<'a>,
+    /// The token account's `ThawExpiry` when the config has a thaw ttl, followed by its
+    /// `FreezeReason` and the receiver of its rent, which are optional unless the config has
+    /// sticky freeze. Same order as in [`crate::permissionless_accounts`].
+    pub remaining_accounts: &'a [AccountInfo<'a>],
 }
 
 impl Thaw<'_> {
@@ -22,7 +33,7 @@ impl Thaw<'_> {
         let data = &self.mint_config.data.borrow();
         let config = load_mint_config(data)?;
 
-        if config.freeze_authority != *self.authority.key {
+        if config.thaw_operator != *self.authority.key {
             return Err(TokenAclError::InvalidAuthority.into());
         }
 
@@ -30,6 +41,28 @@ impl Thaw<'_> {
             return Err(TokenAclError::InvalidTokenMint.into());
         }
 
+        let (thaw_expiry, rest) = if config.thaw_ttl() > 0 {
+            let [thaw_expiry, rest @ ..] = self.remaining_accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            check_thaw_expiry(thaw_expiry.key, self.token_account.key)?;
+            (Some(thaw_expiry), rest)
+        } else {
+            (None, self.remaining_accounts)
+        };
+
+        let freeze_reason = match rest {
+            [] => None,
+            [freeze_reason, receiver] => Some((freeze_reason, receiver)),
+            _ => return Err(ProgramError::NotEnoughAccountKeys),
+        };
+
+        // with sticky freeze a stale reason would keep blocking permissionless thaws, so the
+        // thaw has to clear it
+        if config.is_sticky_freeze() && freeze_reason.is_none() {
+            return Err(TokenAclError::FreezeReasonRequired.into());
+        }
+
         let bump_seed = [config.bump];
         let seeds = [MintConfig::SEED_PREFIX, self.mint.key.as_ref(), &bump_seed];
 
@@ -50,15 +83,58 @@ impl Thaw<'_> {
             &[&seeds],
         )?;
 
+        TokenAccountThawed {
+            mint: *self.mint.key,
+            token_account: *self.token_account.key,
+            caller: *self.authority.key,
+            permissionless: PodBool::from_bool(false),
+        }
+        .emit();
+
+        // an expiry left from an earlier permissionless thaw would let anyone freeze the
+        // account without the gate once it passes
+        if let Some(thaw_expiry) = thaw_expiry {
+            clear_thaw_expiry(thaw_expiry)?;
+        }
+
+        if let Some((freeze_reason, receiver)) = freeze_reason {
+            clear_freeze_reason(freeze_reason, receiver, self.token_account.key)?;
+        }
+
         Ok(())
     }
 }
 
+/// Closes the token account's freeze reason after a thaw by the thaw operator, if it has one.
+pub(crate) fn clear_freeze_reason(
+    freeze_reason: &AccountInfo,
+    receiver: &AccountInfo,
+    token_account: &Pubkey,
+) -> ProgramResult {
+    let (derived_freeze_reason, _) = Pubkey::find_program_address(
+        &[FREEZE_REASON_SEED_PREFIX, token_account.as_ref()],
+        &crate::ID,
+    );
+    if derived_freeze_reason != *freeze_reason.key {
+        return Err(TokenAclError::InvalidFreezeReason.into());
+    }
+
+    // accounts frozen without a reason, e.g. by the default account state, have nothing
+    // to close
+    if freeze_reason.owner == &crate::ID {
+        close_freeze_reason(freeze_reason, receiver, token_account)?;
+    }
+
+    Ok(())
+}
+
 impl<'a> TryFrom<&'a [AccountInfo<'a>]> for Thaw<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
-        let [authority, mint, token_account, mint_config, token_program] = &accounts else {
+        let [authority, mint, token_account, mint_config, token_program, remaining_accounts @ ..] =
+            &accounts
+        else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
@@ -66,7 +142,7 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for Thaw<'a> {
             return Err(TokenAclError::InvalidAuthority.into());
         }
 
-        if !spl_token_2022::check_id(token_program.key) {
+        if !crate::is_token_program(token_program.key) {
             return Err(TokenAclError::InvalidTokenProgram.into());
         }
 
@@ -80,6 +156,7 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for Thaw<'a> {
             token_account,
             mint_config,
             token_program,
+            remaining_accounts,
         })
     }
 }
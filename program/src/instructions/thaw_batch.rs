@@ -0,0 +1,168 @@
+use solana_cpi::invoke_signed;
+use solana_program::account_info::AccountInfo;
+use solana_program_error::{ProgramError, ProgramResult};
+use spl_pod::primitives::PodBool;
+use spl_token_2022::{extension::StateWithExtensions, state::AccountState};
+
+use crate::{
+    error::TokenAclError,
+    events::{Event, TokenAccountThawed},
+    instructions::{
+        freeze_permissionless::{check_thaw_expiry, clear_thaw_expiry},
+        thaw::clear_freeze_reason,
+    },
+    state::{load_mint_config, MintConfig},
+};
+
+pub struct ThawBatch<'a> {
+    pub authority: &'a AccountInfo<'a>,
+    pub mint: &'a AccountInfo<'a>,
+    pub mint_config: &'a AccountInfo<'a>,
+    pub token_program: &'a AccountInfo<'a>,
+    /// Each token account is followed by its `ThawExpiry` when the config has a thaw ttl, then
+    /// by its `FreezeReason` and the receiver of its rent, which are optional unless the config
+    /// has sticky freeze, same as in [`crate::instructions::Thaw`].
+    pub token_accounts: &'a [AccountInfo<'a>],
+}
+
+impl ThawBatch<'_> {
+    pub const DISCRIMINATOR: u8 = 17;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let [skip_thawed] = remaining_data else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+        let skip_thawed = *skip_thawed != 0;
+
+        let data = &self.mint_config.data.borrow();
+        let config = load_mint_config(data)?;
+
+        if config.thaw_operator != *self.authority.key {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        if config.mint != *self.mint.key {
+            return Err(TokenAclError::InvalidTokenMint.into());
+        }
+
+        let bump_seed = [config.bump];
+        let seeds = [MintConfig::SEED_PREFIX, self.mint.key.as_ref(), &bump_seed];
+
+        let has_thaw_expiry = config.thaw_ttl() > 0;
+
+        let mut remaining = self.token_accounts;
+        while let [token_account, rest @ ..] = remaining {
+            let (thaw_expiry, rest) = if has_thaw_expiry {
+                let [thaw_expiry, rest @ ..] = rest else {
+                    return Err(ProgramError::NotEnoughAccountKeys);
+                };
+                check_thaw_expiry(thaw_expiry.key, token_account.key)?;
+                (Some(thaw_expiry), rest)
+            } else {
+                (None, rest)
+            };
+
+            // the next token account is owned by the token program, a freeze reason by Token
+            // ACL or, when the account was frozen without one, by the system program
+            let (freeze_reason, rest) = match rest {
+                [freeze_reason, receiver, rest @ ..]
+                    if !crate::is_token_program(freeze_reason.owner) =>
+                {
+                    (Some((freeze_reason, receiver)), rest)
+                }
+                [freeze_reason, ..] if !crate::is_token_program(freeze_reason.owner) => {
+                    return Err(ProgramError::NotEnoughAccountKeys)
+                }
+                _ => (None, rest),
+            };
+            remaining = rest;
+
+            // same as `Thaw`, a stale reason would keep blocking permissionless thaws
+            if config.is_sticky_freeze() && freeze_reason.is_none() {
+                return Err(TokenAclError::FreezeReasonRequired.into());
+            }
+
+            if skip_thawed {
+                let ta_data = token_account.data.borrow();
+                let ta = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&ta_data)?;
+
+                // thaw CPI enforces the mint, but skipped accounts never reach it
+                if ta.base.mint != *self.mint.key {
+                    return Err(TokenAclError::InvalidTokenMint.into());
+                }
+
+                if ta.base.state != AccountState::Frozen {
+                    if let Some((freeze_reason, receiver)) = freeze_reason {
+                        clear_freeze_reason(freeze_reason, receiver, token_account.key)?;
+                    }
+                    continue;
+                }
+            }
+
+            let ix = spl_token_2022::instruction::thaw_account(
+                self.token_program.key,
+                token_account.key,
+                self.mint.key,
+                self.mint_config.key,
+                &[],
+            )?;
+            invoke_signed(
+                &ix,
+                &[
+                    token_account.clone(),
+                    self.mint.clone(),
+                    self.mint_config.clone(),
+                ],
+                &[&seeds],
+            )?;
+
+            TokenAccountThawed {
+                mint: *self.mint.key,
+                token_account: *token_account.key,
+                caller: *self.authority.key,
+                permissionless: PodBool::from_bool(false),
+            }
+            .emit();
+
+            if let Some(thaw_expiry) = thaw_expiry {
+                clear_thaw_expiry(thaw_expiry)?;
+            }
+
+            if let Some((freeze_reason, receiver)) = freeze_reason {
+                clear_freeze_reason(freeze_reason, receiver, token_account.key)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for ThawBatch<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [authority, mint, mint_config, token_program, token_accounts @ ..] = &accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !authority.is_signer {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        if !crate::is_token_program(token_program.key) {
+            return Err(TokenAclError::InvalidTokenProgram.into());
+        }
+
+        if mint_config.owner != &crate::ID {
+            return Err(TokenAclError::InvalidMintConfig.into());
+        }
+
+        Ok(Self {
+            authority,
+            mint,
+            mint_config,
+            token_program,
+            token_accounts,
+        })
+    }
+}
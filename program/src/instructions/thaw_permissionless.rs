@@ -1,13 +1,28 @@
-use solana_cpi::invoke_signed;
-use solana_program::account_info::AccountInfo;
+use solana_cpi::{invoke, invoke_signed};
+use solana_program::{account_info::AccountInfo, clock::Clock};
 use solana_program_error::{ProgramError, ProgramResult};
 use solana_pubkey::Pubkey;
+use solana_rent::Rent;
+use solana_sysvar::Sysvar;
+use spl_pod::{
+    bytemuck::pod_from_bytes_mut,
+    primitives::{PodBool, PodI64},
+};
 use spl_token_2022::{extension::StateWithExtensions, state::AccountState};
-use token_acl_interface::onchain::invoke_can_thaw_permissionless;
+use token_acl_interface::{
+    onchain::{invoke_can_thaw_permissionless, split_thaw_gate_accounts},
+    PermissionlessOperation,
+};
 
 use crate::{
     error::TokenAclError,
-    state::{load_mint_config, MintConfig, FLAG_ACCOUNT_SEED_PREFIX},
+    events::{Event, ThawExpiryRecorded, TokenAccountThawed},
+    instructions::set_permissionless_fee::collect_permissionless_fee,
+    permissionless_accounts::PermissionlessAccounts,
+    state::{
+        load_mint_config, load_thaw_expiry_mut, GateMode, MintConfig, ThawExpiry,
+        FLAG_ACCOUNT_SEED_PREFIX, FREEZE_REASON_SEED_PREFIX, THAW_EXPIRY_SEED_PREFIX,
+    },
 };
 
 pub struct ThawPermissionless<'a> {
@@ -39,7 +54,17 @@ impl ThawPermissionless<'_> {
             return Err(TokenAclError::PermissionlessThawNotEnabled.into());
         }
 
-        if config.gating_program != *self.gating_program.key {
+        if config.is_paused() {
+            return Err(TokenAclError::ConfigPaused.into());
+        }
+
+        let gating_programs: Vec<Pubkey> = config.gating_programs().copied().collect();
+        let gate_mode = config.thaw_gate_mode()?;
+        let is_valid_gating_program = match gate_mode {
+            GateMode::All => gating_programs[0] == *self.gating_program.key,
+            GateMode::Any => gating_programs.contains(self.gating_program.key),
+        };
+        if !is_valid_gating_program {
             return Err(TokenAclError::InvalidGatingProgram.into());
         }
 
@@ -51,18 +76,39 @@ impl ThawPermissionless<'_> {
                 return Err(TokenAclError::InvalidTokenAccountOwner.into());
             }
 
-            if is_idempotent {
-                if ta.base.state != AccountState::Frozen {
-                    // thaw CPI enforces ta.base.mint == self.mint.key, but we're returning early
-                    // so we need to check it to enforce same behaviour regardless of idempotency
-                    if ta.base.mint != *self.mint.key {
-                        return Err(TokenAclError::InvalidTokenMint.into());
-                    }
-                    return Ok(());
+            if is_idempotent && ta.base.state != AccountState::Frozen {
+                // thaw CPI enforces ta.base.mint == self.mint.key, but we're returning early
+                // so we need to check it to enforce same behaviour regardless of idempotency
+                if ta.base.mint != *self.mint.key {
+                    return Err(TokenAclError::InvalidTokenMint.into());
                 }
+                return Ok(());
+            }
+        }
+
+        let accounts = PermissionlessAccounts::split(
+            &config,
+            PermissionlessOperation::Thaw,
+            self.remaining_accounts,
+        )?;
+
+        // with sticky freeze, the freeze reason marks accounts frozen by the freeze operator
+        if let Some(freeze_reason) = accounts.freeze_reason {
+            let (derived_freeze_reason, _) = Pubkey::find_program_address(
+                &[FREEZE_REASON_SEED_PREFIX, self.token_account.key.as_ref()],
+                &crate::ID,
+            );
+            if derived_freeze_reason != *freeze_reason.key {
+                return Err(TokenAclError::InvalidFreezeReason.into());
+            }
+
+            if freeze_reason.owner == &crate::ID && !freeze_reason.data_is_empty() {
+                return Err(TokenAclError::TokenAccountStickyFrozen.into());
             }
         }
 
+        collect_permissionless_fee(&config, self.authority, accounts.fee_vault)?;
+
         let bump_seed = [self.flag_account_bump];
         let seeds = [
             FLAG_ACCOUNT_SEED_PREFIX,
@@ -71,7 +117,7 @@ impl ThawPermissionless<'_> {
         ];
 
         // allocate, assign and initialize flag account
-        let ix = solana_system_interface::instruction::allocate(self.flag_account.key, 1 as u64);
+        let ix = solana_system_interface::instruction::allocate(self.flag_account.key, 1_u64);
         invoke_signed(
             &ix,
             &[self.authority.clone(), self.flag_account.clone()],
@@ -85,17 +131,34 @@ impl ThawPermissionless<'_> {
             &[&seeds],
         )?;
 
-        self.flag_account.data.borrow_mut()[0] = 1;
+        self.flag_account.data.borrow_mut()[0] = PermissionlessOperation::Thaw as u8;
 
-        invoke_can_thaw_permissionless(
-            self.gating_program.key,
-            self.authority.clone(),
-            self.token_account.clone(),
-            self.mint.clone(),
-            self.token_account_owner.clone(),
-            self.flag_account.clone(),
-            self.remaining_accounts,
-        )?;
+        if gate_mode == GateMode::All && gating_programs.len() > 1 {
+            // every gate in the chain gets its own slice of the remaining accounts
+            let gate_accounts =
+                split_thaw_gate_accounts(self.mint.key, &gating_programs, accounts.gate_accounts)?;
+            for (gating_program, accounts) in gating_programs.iter().zip(gate_accounts) {
+                invoke_can_thaw_permissionless(
+                    gating_program,
+                    self.authority.clone(),
+                    self.token_account.clone(),
+                    self.mint.clone(),
+                    self.token_account_owner.clone(),
+                    self.flag_account.clone(),
+                    accounts,
+                )?;
+            }
+        } else {
+            invoke_can_thaw_permissionless(
+                self.gating_program.key,
+                self.authority.clone(),
+                self.token_account.clone(),
+                self.mint.clone(),
+                self.token_account_owner.clone(),
+                self.flag_account.clone(),
+                accounts.gate_accounts,
+            )?;
+        }
 
         let bump_seed = [config.bump];
         let seeds = [MintConfig::SEED_PREFIX, self.mint.key.as_ref(), &bump_seed];
@@ -117,6 +180,27 @@ impl ThawPermissionless<'_> {
             &[&seeds],
         )?;
 
+        TokenAccountThawed {
+            mint: *self.mint.key,
+            token_account: *self.token_account.key,
+            caller: *self.authority.key,
+            permissionless: PodBool::from_bool(true),
+        }
+        .emit();
+
+        if let Some(thaw_expiry) = accounts.thaw_expiry {
+            let expires_at = Clock::get()?
+                .unix_timestamp
+                .saturating_add(config.thaw_ttl());
+            record_thaw_expiry(
+                self.authority,
+                thaw_expiry,
+                self.mint.key,
+                self.token_account.key,
+                expires_at,
+            )?;
+        }
+
         // clean up flag account
         self.flag_account.data.borrow_mut()[0] = 0;
         self.flag_account.resize(0)?;
@@ -142,7 +226,7 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for ThawPermissionless<'a> {
             return Err(TokenAclError::InvalidAuthority.into());
         }
 
-        if !spl_token_2022::check_id(token_program.key) {
+        if !crate::is_token_program(token_program.key) {
             return Err(TokenAclError::InvalidTokenProgram.into());
         }
 
@@ -178,3 +262,65 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for ThawPermissionless<'a> {
         })
     }
 }
+
+/// Creates the token account's thaw expiry, or moves the expiry of an existing one.
+/// The original payer keeps the rent claim.
+pub(crate) fn record_thaw_expiry<'a>(
+    payer: &AccountInfo<'a>,
+    thaw_expiry: &AccountInfo<'a>,
+    mint: &Pubkey,
+    token_account: &Pubkey,
+    expires_at: i64,
+) -> ProgramResult {
+    let (thaw_expiry_key, bump) = Pubkey::find_program_address(
+        &[THAW_EXPIRY_SEED_PREFIX, token_account.as_ref()],
+        &crate::ID,
+    );
+    if thaw_expiry_key != *thaw_expiry.key {
+        return Err(TokenAclError::InvalidThawExpiry.into());
+    }
+
+    if thaw_expiry.owner == &crate::ID {
+        let data = &mut thaw_expiry.data.borrow_mut();
+        let expiry = load_thaw_expiry_mut(data)?;
+        expiry.expires_at = PodI64::from(expires_at);
+    } else {
+        let lamports = Rent::get()?.minimum_balance(ThawExpiry::LEN);
+
+        if thaw_expiry.lamports() < lamports {
+            let diff = lamports - thaw_expiry.lamports();
+
+            let ix =
+                solana_system_interface::instruction::transfer(payer.key, thaw_expiry.key, diff);
+            invoke(&ix, &[payer.clone(), thaw_expiry.clone()])?;
+        }
+
+        let bump_seed = [bump];
+        let seeds = [THAW_EXPIRY_SEED_PREFIX, token_account.as_ref(), &bump_seed];
+
+        let allocate_ix =
+            solana_system_interface::instruction::allocate(thaw_expiry.key, ThawExpiry::LEN as u64);
+        invoke_signed(&allocate_ix, std::slice::from_ref(thaw_expiry), &[&seeds])?;
+
+        let assign_ix = solana_system_interface::instruction::assign(thaw_expiry.key, &crate::ID);
+        invoke_signed(&assign_ix, std::slice::from_ref(thaw_expiry), &[&seeds])?;
+
+        let data = &mut thaw_expiry.data.borrow_mut();
+        let expiry = pod_from_bytes_mut::<ThawExpiry>(data)?;
+        expiry.discriminator = ThawExpiry::DISCRIMINATOR;
+        expiry.bump = bump;
+        expiry.mint = *mint;
+        expiry.token_account = *token_account;
+        expiry.expires_at = PodI64::from(expires_at);
+        expiry.payer = *payer.key;
+    }
+
+    ThawExpiryRecorded {
+        mint: *mint,
+        token_account: *token_account,
+        expires_at: PodI64::from(expires_at),
+    }
+    .emit();
+
+    Ok(())
+}
@@ -2,7 +2,11 @@ use solana_program::account_info::AccountInfo;
 use solana_program_error::{ProgramError, ProgramResult};
 use spl_pod::primitives::PodBool;
 
-use crate::{error::TokenAclError, state::load_mint_config_mut};
+use crate::{
+    error::TokenAclError,
+    events::{Event, PermissionlessInstructionsToggled},
+    state::load_mint_config_mut,
+};
 
 pub struct TogglePermissionlessInstructions<'a> {
     pub authority: &'a AccountInfo<'a>,
@@ -27,6 +31,14 @@ impl TogglePermissionlessInstructions<'_> {
         config.enable_permissionless_freeze = PodBool::from_bool(*freeze_enabled != 0);
         config.enable_permissionless_thaw = PodBool::from_bool(*thaw_enabled != 0);
 
+        PermissionlessInstructionsToggled {
+            mint: config.mint,
+            authority: *self.authority.key,
+            freeze_enabled: config.enable_permissionless_freeze,
+            thaw_enabled: config.enable_permissionless_thaw,
+        }
+        .emit();
+
         Ok(())
     }
 }
@@ -0,0 +1,102 @@
+use solana_cpi::invoke_signed;
+use solana_program::account_info::AccountInfo;
+use solana_program_error::{ProgramError, ProgramResult};
+use solana_rent::Rent;
+use solana_sysvar::Sysvar;
+use spl_pod::primitives::PodU64;
+
+use crate::{
+    error::TokenAclError,
+    events::{Event, FeesWithdrawn},
+    instructions::set_permissionless_fee::check_fee_vault,
+    state::{load_mint_config, FEE_VAULT_SEED_PREFIX},
+};
+
+pub struct WithdrawFees<'a> {
+    pub authority: &'a AccountInfo<'a>,
+    pub mint_config: &'a AccountInfo<'a>,
+    pub fee_vault: &'a AccountInfo<'a>,
+    pub receiver: &'a AccountInfo<'a>,
+    pub system_program: &'a AccountInfo<'a>,
+}
+
+impl WithdrawFees<'_> {
+    pub const DISCRIMINATOR: u8 = 32;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let amount = u64::from_le_bytes(
+            remaining_data
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+
+        let data = &self.mint_config.data.borrow();
+        let config = load_mint_config(data)?;
+
+        if config.freeze_authority != *self.authority.key {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        let bump = check_fee_vault(self.fee_vault.key, &config.mint)?;
+
+        // the vault keeps its rent exempt minimum so that small fees can still be paid in
+        let minimum_balance = Rent::get()?.minimum_balance(0);
+        if self.fee_vault.lamports().saturating_sub(minimum_balance) < amount {
+            return Err(TokenAclError::InsufficientFeeVaultBalance.into());
+        }
+
+        let bump_seed = [bump];
+        let seeds = [FEE_VAULT_SEED_PREFIX, config.mint.as_ref(), &bump_seed];
+
+        let ix = solana_system_interface::instruction::transfer(
+            self.fee_vault.key,
+            self.receiver.key,
+            amount,
+        );
+        invoke_signed(
+            &ix,
+            &[self.fee_vault.clone(), self.receiver.clone()],
+            &[&seeds],
+        )?;
+
+        FeesWithdrawn {
+            mint: config.mint,
+            authority: *self.authority.key,
+            receiver: *self.receiver.key,
+            amount: PodU64::from(amount),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for WithdrawFees<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error> {
+        let [authority, mint_config, fee_vault, receiver, system_program] = &accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !authority.is_signer {
+            return Err(TokenAclError::InvalidAuthority.into());
+        }
+
+        if !solana_system_interface::program::check_id(system_program.key) {
+            return Err(TokenAclError::InvalidSystemProgram.into());
+        }
+
+        if mint_config.owner != &crate::ID {
+            return Err(TokenAclError::InvalidMintConfig.into());
+        }
+
+        Ok(Self {
+            authority,
+            mint_config,
+            fee_vault,
+            receiver,
+            system_program,
+        })
+    }
+}
@@ -4,17 +4,31 @@ use solana_program::{
 use solana_program_error::ProgramError;
 
 use crate::instructions::{
-    CreateConfig, DeleteConfig, Freeze, FreezePermissionless, FreezePermissionlessIdempotent,
-    SetAuthority, SetGatingProgram, Thaw, ThawPermissionless, ThawPermissionlessIdempotent,
-    TogglePermissionlessInstructions,
+    AcceptAuthority, CancelAuthorityTransfer, CancelGatingProgram, CloseFreezeReason,
+    CloseThawExpiry, CreateAtaAndThawPermissionless, CreateConfig, DeleteConfig,
+    FinalizeGatingProgram, Freeze, FreezeBatch, FreezeBatchWithReason, FreezePermissionless,
+    FreezePermissionlessIdempotent, FreezeWithReason, MigrateConfig, ProposeAuthority,
+    QueueGatingChain, QueueGatingProgram, SetAuthority, SetFreezeGatingProgram, SetFreezeOperator,
+    SetGatingChain, SetGatingProgram, SetPauseGuardian, SetPaused, SetPermissionlessFee,
+    SetStickyFreeze, SetThawOperator, SetThawTtl, Thaw, ThawBatch, ThawPermissionless,
+    ThawPermissionlessIdempotent, TogglePermissionlessInstructions, WithdrawFees,
 };
 
+#[cfg(feature = "forbid-additional-mints")]
+pub mod allowed_mints;
 pub mod error;
+pub mod events;
 pub mod instructions;
+pub mod permissionless_accounts;
 pub mod state;
 
 declare_id!("TACLkU6CiCdkQN2MjoyDkVg2yAH9zkxiHDsiztQ52TP");
 
+/// Mints owned by either SPL Token or Token-2022 can be managed by Token ACL.
+pub fn is_token_program(program_id: &Pubkey) -> bool {
+    spl_token_2022::check_id(program_id) || spl_token_interface::check_id(program_id)
+}
+
 #[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 fn process_instruction<'a>(
@@ -30,6 +44,16 @@ fn process_instruction<'a>(
         CreateConfig::DISCRIMINATOR => CreateConfig::try_from(accounts)?.process(remaining_data),
         Freeze::DISCRIMINATOR => Freeze::try_from(accounts)?.process(),
         Thaw::DISCRIMINATOR => Thaw::try_from(accounts)?.process(),
+        FreezeBatch::DISCRIMINATOR => FreezeBatch::try_from(accounts)?.process(remaining_data),
+        FreezeWithReason::DISCRIMINATOR => {
+            FreezeWithReason::try_from(accounts)?.process(remaining_data)
+        }
+        FreezeBatchWithReason::DISCRIMINATOR => {
+            FreezeBatchWithReason::try_from(accounts)?.process(remaining_data)
+        }
+        CloseFreezeReason::DISCRIMINATOR => CloseFreezeReason::try_from(accounts)?.process(),
+        CloseThawExpiry::DISCRIMINATOR => CloseThawExpiry::try_from(accounts)?.process(),
+        ThawBatch::DISCRIMINATOR => ThawBatch::try_from(accounts)?.process(remaining_data),
         ThawPermissionless::DISCRIMINATOR => ThawPermissionless::try_from(accounts)?.process(false),
         ThawPermissionlessIdempotent::DISCRIMINATOR => {
             ThawPermissionless::try_from(accounts)?.process(true)
@@ -40,17 +64,59 @@ fn process_instruction<'a>(
         FreezePermissionlessIdempotent::DISCRIMINATOR => {
             FreezePermissionless::try_from(accounts)?.process(true)
         }
-        SetAuthority::DISCRIMINATOR => SetAuthority::try_from(accounts)?.process(remaining_data),
+        CreateAtaAndThawPermissionless::DISCRIMINATOR => {
+            CreateAtaAndThawPermissionless::try_from(accounts)?.process()
+        }
+        SetAuthority::DISCRIMINATOR => SetAuthority::try_from(accounts)?.process(),
+        ProposeAuthority::DISCRIMINATOR => {
+            ProposeAuthority::try_from(accounts)?.process(remaining_data)
+        }
+        AcceptAuthority::DISCRIMINATOR => AcceptAuthority::try_from(accounts)?.process(),
+        CancelAuthorityTransfer::DISCRIMINATOR => {
+            CancelAuthorityTransfer::try_from(accounts)?.process()
+        }
+        SetFreezeOperator::DISCRIMINATOR => {
+            SetFreezeOperator::try_from(accounts)?.process(remaining_data)
+        }
+        SetThawOperator::DISCRIMINATOR => {
+            SetThawOperator::try_from(accounts)?.process(remaining_data)
+        }
         SetGatingProgram::DISCRIMINATOR => {
             SetGatingProgram::try_from(accounts)?.process(remaining_data)
         }
+        SetGatingChain::DISCRIMINATOR => {
+            SetGatingChain::try_from(accounts)?.process(remaining_data)
+        }
+        SetFreezeGatingProgram::DISCRIMINATOR => {
+            SetFreezeGatingProgram::try_from(accounts)?.process(remaining_data)
+        }
+        QueueGatingProgram::DISCRIMINATOR => {
+            QueueGatingProgram::try_from(accounts)?.process(remaining_data)
+        }
+        QueueGatingChain::DISCRIMINATOR => {
+            QueueGatingChain::try_from(accounts)?.process(remaining_data)
+        }
+        FinalizeGatingProgram::DISCRIMINATOR => {
+            FinalizeGatingProgram::try_from(accounts)?.process()
+        }
+        CancelGatingProgram::DISCRIMINATOR => CancelGatingProgram::try_from(accounts)?.process(),
+        SetPaused::DISCRIMINATOR => SetPaused::try_from(accounts)?.process(remaining_data),
+        SetPauseGuardian::DISCRIMINATOR => {
+            SetPauseGuardian::try_from(accounts)?.process(remaining_data)
+        }
+        SetPermissionlessFee::DISCRIMINATOR => {
+            SetPermissionlessFee::try_from(accounts)?.process(remaining_data)
+        }
+        SetThawTtl::DISCRIMINATOR => SetThawTtl::try_from(accounts)?.process(remaining_data),
+        WithdrawFees::DISCRIMINATOR => WithdrawFees::try_from(accounts)?.process(remaining_data),
+        SetStickyFreeze::DISCRIMINATOR => {
+            SetStickyFreeze::try_from(accounts)?.process(remaining_data)
+        }
         DeleteConfig::DISCRIMINATOR => DeleteConfig::try_from(accounts)?.process(remaining_data),
         TogglePermissionlessInstructions::DISCRIMINATOR => {
             TogglePermissionlessInstructions::try_from(accounts)?.process(remaining_data)
         }
-        _ => {
-            println!("Invalid instruction discriminator: {:?}", discriminator);
-            Err(ProgramError::InvalidInstructionData)
-        }
+        MigrateConfig::DISCRIMINATOR => MigrateConfig::try_from(accounts)?.process(),
+        _ => Err(ProgramError::InvalidInstructionData),
     }
 }
@@ -0,0 +1,64 @@
+//! Layout of the remaining accounts of the permissionless instructions.
+//!
+//! `ThawPermissionless`, `FreezePermissionless`, their idempotent variants and
+//! `CreateAtaAndThawPermissionless` take the gate accounts right after the gating program,
+//! where sRFC-37 resolvers put them. The accounts Token ACL needs for its own features are
+//! appended after the gate accounts, in this order and each only when the config calls for it:
+//!
+//! 1. the fee vault, when the config charges a permissionless fee
+//! 2. the token account's `ThawExpiry`, when the config has a thaw ttl
+//! 3. the token account's `FreezeReason`, on thaws when the config has sticky freeze
+//!
+//! The permissioned `Thaw` takes the `ThawExpiry` and `FreezeReason` in the same order, see
+//! [`crate::instructions::Thaw`].
+
+use solana_program::account_info::AccountInfo;
+use solana_program_error::ProgramError;
+use token_acl_interface::PermissionlessOperation;
+
+use crate::state::MintConfig;
+
+pub struct PermissionlessAccounts<'a> {
+    pub gate_accounts: &'a [AccountInfo<'a>],
+    pub fee_vault: Option<&'a AccountInfo<'a>>,
+    pub thaw_expiry: Option<&'a AccountInfo<'a>>,
+    pub freeze_reason: Option<&'a AccountInfo<'a>>,
+}
+
+impl<'a> PermissionlessAccounts<'a> {
+    /// Splits the Token ACL accounts that `config` requires for `operation` off the end of
+    /// `remaining_accounts`, the rest are the gate accounts.
+    pub fn split(
+        config: &MintConfig,
+        operation: PermissionlessOperation,
+        remaining_accounts: &'a [AccountInfo<'a>],
+    ) -> Result<Self, ProgramError> {
+        let has_freeze_reason =
+            operation == PermissionlessOperation::Thaw && config.is_sticky_freeze();
+        let mut rest = remaining_accounts;
+
+        // taken from the back, so in the reverse of the documented order
+        let freeze_reason = has_freeze_reason.then(|| pop(&mut rest)).transpose()?;
+        let thaw_expiry = (config.thaw_ttl() > 0)
+            .then(|| pop(&mut rest))
+            .transpose()?;
+        let fee_vault = (config.permissionless_fee() > 0)
+            .then(|| pop(&mut rest))
+            .transpose()?;
+
+        Ok(Self {
+            gate_accounts: rest,
+            fee_vault,
+            thaw_expiry,
+            freeze_reason,
+        })
+    }
+}
+
+fn pop<'a>(accounts: &mut &'a [AccountInfo<'a>]) -> Result<&'a AccountInfo<'a>, ProgramError> {
+    let [rest @ .., last] = *accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    *accounts = rest;
+    Ok(last)
+}
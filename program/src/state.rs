@@ -1,28 +1,95 @@
 use bytemuck::{Pod, Zeroable};
 use solana_program_error::ProgramError;
-use spl_pod::primitives::PodBool;
+use spl_pod::primitives::{PodBool, PodI64, PodU32, PodU64};
 
 use crate::error::TokenAclError;
 use solana_program::pubkey::Pubkey;
 
-pub const FLAG_ACCOUNT_SEED_PREFIX: &'static [u8] = b"FLAG_ACCOUNT";
+pub const FLAG_ACCOUNT_SEED_PREFIX: &[u8] = b"FLAG_ACCOUNT";
+pub const PENDING_GATING_PROGRAM_SEED_PREFIX: &[u8] = b"PENDING_GATING_PROGRAM";
+pub const FREEZE_REASON_SEED_PREFIX: &[u8] = b"FREEZE_REASON";
+pub const FEE_VAULT_SEED_PREFIX: &[u8] = b"FEE_VAULT";
+pub const THAW_EXPIRY_SEED_PREFIX: &[u8] = b"THAW_EXPIRY";
 
+/// Current mint config layout.
+///
+/// The first 100 bytes match the original, unversioned layout (version 1).
+/// Newer fields are appended after the `version` byte and new fields should
+/// be carved out of `reserved` so that the account size stays the same.
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
 pub struct MintConfig {
     pub discriminator: u8,
     pub bump: u8,
     pub enable_permissionless_thaw: PodBool,
     pub enable_permissionless_freeze: PodBool,
     pub mint: Pubkey,
+    /// Config admin, allowed to change settings and roles and to delete the config.
     pub freeze_authority: Pubkey,
     pub gating_program: Pubkey,
+    pub version: u8,
+    pub pending_authority: Pubkey,
+    /// Allowed to use the permissioned `Freeze` instruction.
+    pub freeze_operator: Pubkey,
+    /// Allowed to use the permissioned `Thaw` instruction.
+    pub thaw_operator: Pubkey,
+    /// Gating programs chained after `gating_program`, unused slots are left as default.
+    pub additional_gating_programs: [Pubkey; MintConfig::MAX_ADDITIONAL_GATING_PROGRAMS],
+    /// `GateMode` used by `ThawPermissionless`.
+    pub thaw_gate_mode: u8,
+    /// `GateMode` used by `FreezePermissionless`.
+    pub freeze_gate_mode: u8,
+    /// Replaces `gating_program` for `FreezePermissionless` when set.
+    pub freeze_gating_program: Pubkey,
+    /// Blocks the permissionless instructions without touching their enable flags.
+    pub paused: PodBool,
+    /// Allowed to pause the config besides the admin, unset if default.
+    pub pause_guardian: Pubkey,
+    /// Seconds a queued gating program change waits before it can be finalized.
+    /// Immediate gate changes are rejected while this is non-zero. Only the gates are
+    /// delayed: the admin can still change the operators and the authority, thaw through
+    /// the thaw operator or delete the config right away.
+    pub gating_program_delay: PodI64,
+    /// Accounts frozen by the freeze operator can't be thawed permissionlessly, the
+    /// permissioned freezes have to record a `FreezeReason` that marks them.
+    pub sticky_freeze: PodBool,
+    /// Lamports charged to the `authority` of the permissionless instructions and paid
+    /// into the config's fee vault, zero if there is no fee.
+    pub permissionless_fee: PodU64,
+    /// Seconds a permissionless thaw lasts before anyone can freeze the account again
+    /// without the gate's approval, zero if thaws don't expire.
+    pub thaw_ttl: PodI64,
+    pub reserved: [u8; MintConfig::RESERVED_LEN],
+}
+
+impl Default for MintConfig {
+    fn default() -> Self {
+        Self::zeroed()
+    }
 }
 
 impl MintConfig {
     pub const SEED_PREFIX: &'static [u8] = b"MINT_CONFIG";
     pub const DISCRIMINATOR: u8 = 1;
-    pub const LEN: usize = 1 + 32 + 32 + 32 + 1 + 1 + 1;
+    pub const VERSION: u8 = 2;
+    pub const MAX_ADDITIONAL_GATING_PROGRAMS: usize = 3;
+    pub const RESERVED_LEN: usize = 68;
+    pub const LEN: usize = MintConfigV1::LEN
+        + 1
+        + 32
+        + 32
+        + 32
+        + 32 * Self::MAX_ADDITIONAL_GATING_PROGRAMS
+        + 1
+        + 1
+        + 32
+        + 1
+        + 32
+        + 8
+        + 1
+        + 8
+        + 8
+        + Self::RESERVED_LEN;
 
     pub fn is_permissionless_thaw_enabled(&self) -> bool {
         Into::<bool>::into(self.enable_permissionless_thaw)
@@ -31,26 +98,288 @@ impl MintConfig {
     pub fn is_permissionless_freeze_enabled(&self) -> bool {
         Into::<bool>::into(self.enable_permissionless_freeze)
     }
+
+    pub fn is_paused(&self) -> bool {
+        Into::<bool>::into(self.paused)
+    }
+
+    pub fn is_sticky_freeze(&self) -> bool {
+        Into::<bool>::into(self.sticky_freeze)
+    }
+
+    pub fn permissionless_fee(&self) -> u64 {
+        u64::from(self.permissionless_fee)
+    }
+
+    pub fn thaw_ttl(&self) -> i64 {
+        i64::from(self.thaw_ttl)
+    }
+
+    pub fn gating_program_delay(&self) -> i64 {
+        i64::from(self.gating_program_delay)
+    }
+
+    pub fn has_pending_authority(&self) -> bool {
+        self.pending_authority != Pubkey::default()
+    }
+
+    /// Configured gating programs in invocation order, starting with `gating_program`.
+    pub fn gating_programs(&self) -> impl Iterator<Item = &Pubkey> {
+        core::iter::once(&self.gating_program).chain(self.additional_gating_programs())
+    }
+
+    /// Gating programs used by `FreezePermissionless`, starting with
+    /// `freeze_gating_program` if set and `gating_program` otherwise.
+    pub fn freeze_gating_programs(&self) -> impl Iterator<Item = &Pubkey> {
+        let primary = if self.freeze_gating_program != Pubkey::default() {
+            &self.freeze_gating_program
+        } else {
+            &self.gating_program
+        };
+        core::iter::once(primary).chain(self.additional_gating_programs())
+    }
+
+    fn additional_gating_programs(&self) -> impl Iterator<Item = &Pubkey> {
+        self.additional_gating_programs
+            .iter()
+            .take_while(|program| **program != Pubkey::default())
+    }
+
+    pub fn thaw_gate_mode(&self) -> Result<GateMode, ProgramError> {
+        GateMode::try_from(self.thaw_gate_mode)
+    }
+
+    pub fn freeze_gate_mode(&self) -> Result<GateMode, ProgramError> {
+        GateMode::try_from(self.freeze_gate_mode)
+    }
+}
+
+/// How the chained gating programs of a config are combined.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GateMode {
+    /// Every gating program is invoked and has to approve the operation.
+    All = 0,
+    /// Only the gating program passed by the caller is invoked, it can be any of
+    /// the configured ones. Callers pick the most permissive gate, so the chain
+    /// approves whatever any single gate approves.
+    Any = 1,
+}
+
+impl TryFrom<u8> for GateMode {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::All),
+            1 => Ok(Self::Any),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Gate changes queued while the config has a `gating_program_delay`.
+///
+/// `QueueGatingProgram` queues the gating program and delay, `QueueGatingChain` the
+/// chain and the freeze gating program. Both can be queued in the same pending change,
+/// only the queued parts are applied by `FinalizeGatingProgram`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct PendingGatingProgram {
+    pub discriminator: u8,
+    pub bump: u8,
+    pub mint: Pubkey,
+    pub new_gating_program: Pubkey,
+    pub new_gating_program_delay: PodI64,
+    /// Unix timestamp from which the change can be finalized.
+    pub activation_timestamp: PodI64,
+    /// Receives the rent back once the change is finalized or cancelled.
+    pub payer: Pubkey,
+    /// Whether `new_gating_program` and `new_gating_program_delay` are queued.
+    pub gating_program_queued: PodBool,
+    /// Whether the gating chain and freeze gating program below are queued.
+    pub gating_chain_queued: PodBool,
+    pub new_additional_gating_programs: [Pubkey; MintConfig::MAX_ADDITIONAL_GATING_PROGRAMS],
+    pub new_thaw_gate_mode: u8,
+    pub new_freeze_gate_mode: u8,
+    pub new_freeze_gating_program: Pubkey,
+}
+
+impl PendingGatingProgram {
+    pub const DISCRIMINATOR: u8 = 2;
+    pub const LEN: usize = 1
+        + 1
+        + 32
+        + 32
+        + 8
+        + 8
+        + 32
+        + 1
+        + 1
+        + 32 * MintConfig::MAX_ADDITIONAL_GATING_PROGRAMS
+        + 1
+        + 1
+        + 32;
+
+    pub fn activation_timestamp(&self) -> i64 {
+        i64::from(self.activation_timestamp)
+    }
+
+    pub fn is_gating_program_queued(&self) -> bool {
+        Into::<bool>::into(self.gating_program_queued)
+    }
+
+    pub fn is_gating_chain_queued(&self) -> bool {
+        Into::<bool>::into(self.gating_chain_queued)
+    }
+}
+
+/// Why a token account was frozen, recorded by the permissioned freeze instructions
+/// and cleared once the account is thawed.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct FreezeReason {
+    pub discriminator: u8,
+    pub bump: u8,
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    /// Freeze operator that froze the account.
+    pub authority: Pubkey,
+    /// Issuer-defined reason code.
+    pub reason_code: PodU32,
+    /// Hash of an off-chain memo, zeroed when there is none.
+    pub memo_hash: [u8; 32],
+    pub frozen_at: PodI64,
+    /// Receives the rent back once the reason is cleared.
+    pub payer: Pubkey,
+}
+
+impl FreezeReason {
+    pub const DISCRIMINATOR: u8 = 3;
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 32 + 4 + 32 + 8 + 32;
+}
+
+/// When a permissionless thaw of a token account expires, recorded while the config
+/// has a `thaw_ttl`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct ThawExpiry {
+    pub discriminator: u8,
+    pub bump: u8,
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    /// Unix timestamp from which the account can be frozen without the gate's approval,
+    /// zero once the account has been frozen again.
+    pub expires_at: PodI64,
+    /// Receives the rent back once the expiry is closed.
+    pub payer: Pubkey,
+}
+
+impl ThawExpiry {
+    pub const DISCRIMINATOR: u8 = 4;
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 8 + 32;
+
+    pub fn expires_at(&self) -> i64 {
+        i64::from(self.expires_at)
+    }
+
+    pub fn is_expired(&self, unix_timestamp: i64) -> bool {
+        self.expires_at() != 0 && unix_timestamp >= self.expires_at()
+    }
+}
+
+/// Original config layout, created before the version byte was introduced.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct MintConfigV1 {
+    pub discriminator: u8,
+    pub bump: u8,
+    pub enable_permissionless_thaw: PodBool,
+    pub enable_permissionless_freeze: PodBool,
+    pub mint: Pubkey,
+    pub freeze_authority: Pubkey,
+    pub gating_program: Pubkey,
+}
+
+impl MintConfigV1 {
+    pub const VERSION: u8 = 1;
+    pub const LEN: usize = 1 + 32 + 32 + 32 + 1 + 1 + 1;
+}
+
+impl From<MintConfigV1> for MintConfig {
+    /// Both operator roles default to the admin, matching the behavior of
+    /// configs created before the roles were split.
+    fn from(v1: MintConfigV1) -> Self {
+        Self {
+            discriminator: v1.discriminator,
+            bump: v1.bump,
+            enable_permissionless_thaw: v1.enable_permissionless_thaw,
+            enable_permissionless_freeze: v1.enable_permissionless_freeze,
+            mint: v1.mint,
+            freeze_authority: v1.freeze_authority,
+            gating_program: v1.gating_program,
+            version: Self::VERSION,
+            pending_authority: Pubkey::default(),
+            freeze_operator: v1.freeze_authority,
+            thaw_operator: v1.freeze_authority,
+            additional_gating_programs: [Pubkey::default(); Self::MAX_ADDITIONAL_GATING_PROGRAMS],
+            thaw_gate_mode: GateMode::All as u8,
+            freeze_gate_mode: GateMode::All as u8,
+            freeze_gating_program: Pubkey::default(),
+            paused: PodBool::from_bool(false),
+            pause_guardian: Pubkey::default(),
+            gating_program_delay: PodI64::from(0),
+            sticky_freeze: PodBool::from_bool(false),
+            permissionless_fee: PodU64::from(0),
+            thaw_ttl: PodI64::from(0),
+            reserved: [0; Self::RESERVED_LEN],
+        }
+    }
 }
 
+/// Loads a config of any supported version, upgrading older layouts in memory.
 #[inline(always)]
-pub fn load_mint_config(data: &[u8]) -> Result<&MintConfig, ProgramError> {
+pub fn load_mint_config(data: &[u8]) -> Result<MintConfig, ProgramError> {
+    if data.len() == MintConfigV1::LEN {
+        return load_mint_config_v1(data).map(|cfg| MintConfig::from(*cfg));
+    }
+
     bytemuck::try_from_bytes::<MintConfig>(data)
         .map_err(|_| TokenAclError::InvalidMintConfig.into())
         .and_then(|cfg: &MintConfig| {
-            if cfg.discriminator == MintConfig::DISCRIMINATOR {
-                Ok(cfg)
+            if cfg.discriminator == MintConfig::DISCRIMINATOR && cfg.version == MintConfig::VERSION
+            {
+                Ok(*cfg)
             } else {
                 Err(TokenAclError::InvalidMintConfig.into())
             }
         })
 }
 
+/// Loads a config for modification, older layouts have to be migrated first.
 #[inline(always)]
 pub fn load_mint_config_mut(data: &mut [u8]) -> Result<&mut MintConfig, ProgramError> {
+    if data.len() == MintConfigV1::LEN {
+        return Err(TokenAclError::MintConfigMigrationRequired.into());
+    }
+
     bytemuck::try_from_bytes_mut::<MintConfig>(data)
         .map_err(|_| TokenAclError::InvalidMintConfig.into())
         .and_then(|cfg: &mut MintConfig| {
+            if cfg.discriminator == MintConfig::DISCRIMINATOR && cfg.version == MintConfig::VERSION
+            {
+                Ok(cfg)
+            } else {
+                Err(TokenAclError::InvalidMintConfig.into())
+            }
+        })
+}
+
+#[inline(always)]
+pub fn load_mint_config_v1(data: &[u8]) -> Result<&MintConfigV1, ProgramError> {
+    bytemuck::try_from_bytes::<MintConfigV1>(data)
+        .map_err(|_| TokenAclError::InvalidMintConfig.into())
+        .and_then(|cfg: &MintConfigV1| {
             if cfg.discriminator == MintConfig::DISCRIMINATOR {
                 Ok(cfg)
             } else {
@@ -58,3 +387,44 @@ pub fn load_mint_config_mut(data: &mut [u8]) -> Result<&mut MintConfig, ProgramE
             }
         })
 }
+
+#[inline(always)]
+pub fn load_pending_gating_program_mut(
+    data: &mut [u8],
+) -> Result<&mut PendingGatingProgram, ProgramError> {
+    bytemuck::try_from_bytes_mut::<PendingGatingProgram>(data)
+        .map_err(|_| TokenAclError::InvalidPendingGatingProgram.into())
+        .and_then(|pending: &mut PendingGatingProgram| {
+            if pending.discriminator == PendingGatingProgram::DISCRIMINATOR {
+                Ok(pending)
+            } else {
+                Err(TokenAclError::InvalidPendingGatingProgram.into())
+            }
+        })
+}
+
+#[inline(always)]
+pub fn load_freeze_reason_mut(data: &mut [u8]) -> Result<&mut FreezeReason, ProgramError> {
+    bytemuck::try_from_bytes_mut::<FreezeReason>(data)
+        .map_err(|_| TokenAclError::InvalidFreezeReason.into())
+        .and_then(|reason: &mut FreezeReason| {
+            if reason.discriminator == FreezeReason::DISCRIMINATOR {
+                Ok(reason)
+            } else {
+                Err(TokenAclError::InvalidFreezeReason.into())
+            }
+        })
+}
+
+#[inline(always)]
+pub fn load_thaw_expiry_mut(data: &mut [u8]) -> Result<&mut ThawExpiry, ProgramError> {
+    bytemuck::try_from_bytes_mut::<ThawExpiry>(data)
+        .map_err(|_| TokenAclError::InvalidThawExpiry.into())
+        .and_then(|expiry: &mut ThawExpiry| {
+            if expiry.discriminator == ThawExpiry::DISCRIMINATOR {
+                Ok(expiry)
+            } else {
+                Err(TokenAclError::InvalidThawExpiry.into())
+            }
+        })
+}